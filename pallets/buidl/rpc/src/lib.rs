@@ -0,0 +1,98 @@
+//! RPC interface for the BUIDL pallet.
+//!
+//! The pallet's types are generic over the runtime, so results are returned SCALE-encoded and
+//! decoded by the caller with the runtime's metadata.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::{Codec, Encode};
+use jsonrpsee::{
+	core::{async_trait, RpcResult},
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+
+pub use buidl_pallet_runtime_api::BuidlApi as BuidlRuntimeApi;
+
+#[rpc(client, server)]
+pub trait BuidlApi<BlockHash> {
+	/// The encoded `Option<(Challenge, Vec<ListedSolution>)>` for a challenge of a hackathon.
+	#[method(name = "buidl_challengeWithSolutions")]
+	fn challenge_with_solutions(
+		&self,
+		hackathon_id: u32,
+		challenge_id: u16,
+		at: Option<BlockHash>,
+	) -> RpcResult<Bytes>;
+
+	/// The encoded `Vec<(ChallengeId, Challenge)>` of every challenge of a hackathon.
+	#[method(name = "buidl_listChallenges")]
+	fn list_challenges(&self, hackathon_id: u32, at: Option<BlockHash>) -> RpcResult<Bytes>;
+}
+
+/// Provides the BUIDL RPC methods. `P` is `(Block, Challenge, Solution)`.
+pub struct Buidl<C, P> {
+	client: Arc<C>,
+	_marker: PhantomData<P>,
+}
+
+impl<C, P> Buidl<C, P> {
+	/// Create a new instance of the BUIDL RPC handler.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+/// Error code returned when the runtime API call fails.
+const RUNTIME_ERROR: i32 = 1;
+
+fn runtime_error_into_rpc_err(err: impl std::fmt::Debug) -> jsonrpsee::core::Error {
+	CallError::Custom(ErrorObject::owned(
+		RUNTIME_ERROR,
+		"Unable to query BUIDL challenges.",
+		Some(format!("{:?}", err)),
+	))
+	.into()
+}
+
+#[async_trait]
+impl<C, Block, Challenge, Solution> BuidlApiServer<<Block as BlockT>::Hash>
+	for Buidl<C, (Block, Challenge, Solution)>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: BuidlRuntimeApi<Block, Challenge, Solution>,
+	Challenge: Codec + Send + Sync + 'static,
+	Solution: Codec + Send + Sync + 'static,
+{
+	fn challenge_with_solutions(
+		&self,
+		hackathon_id: u32,
+		challenge_id: u16,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Bytes> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.challenge_with_solutions(&at, hackathon_id, challenge_id)
+			.map(|result| result.encode().into())
+			.map_err(runtime_error_into_rpc_err)
+	}
+
+	fn list_challenges(
+		&self,
+		hackathon_id: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Bytes> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.list_challenges(&at, hackathon_id)
+			.map(|result| result.encode().into())
+			.map_err(runtime_error_into_rpc_err)
+	}
+}
@@ -0,0 +1,23 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! Runtime API for the BUIDL pallet, so front-ends can render a challenge board in one call
+//! instead of reading and decoding `Challenges` and `ChallengeSolutions` themselves.
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	/// `Challenge` and `Solution` are the runtime's `Challenge<Runtime>` and
+	/// `ListedSolution<Runtime>`, which keeps this crate independent of the pallet's `Config`.
+	pub trait BuidlApi<Challenge, Solution>
+	where
+		Challenge: Codec,
+		Solution: Codec,
+	{
+		/// A challenge of a hackathon with every solution submitted to it, in solution id order.
+		fn challenge_with_solutions(hackathon_id: u32, challenge_id: u16) -> Option<(Challenge, Vec<Solution>)>;
+
+		/// Every challenge of a hackathon with its id, in challenge id order.
+		fn list_challenges(hackathon_id: u32) -> Vec<(u16, Challenge)>;
+	}
+}
@@ -0,0 +1,1061 @@
+//! Benchmarking setup for the BUIDL pallet
+
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as Buidl;
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_support::{
+	assert_ok,
+	sp_runtime::{
+		traits::{Saturating, Zero},
+		Permill,
+	},
+	traits::{fungible::MutateHold, Currency, EnsureOrigin, Get, Hooks},
+	BoundedVec,
+};
+use frame_system::RawOrigin;
+use sp_core::H256;
+use sp_std::prelude::*;
+
+const SEED: u32 = 0;
+/// Length of both the submission and the voting window set by `open_periods`.
+const PHASE_BLOCKS: u32 = 10;
+
+fn fund<T: Config>(who: &T::AccountId) {
+	let amount = T::Deposit::minimum_balance().saturating_mul(1_000_000u32.into());
+	let bonds = T::HackathonBond::get()
+		.saturating_add(T::TeamBond::get())
+		.saturating_add(T::SubmissionBond::get());
+	T::Deposit::make_free_balance_be(who, amount.saturating_add(bonds));
+}
+
+fn funded_account<T: Config>(name: &'static str, index: u32) -> T::AccountId {
+	let who = account(name, index, SEED);
+	fund::<T>(&who);
+	who
+}
+
+fn reward<T: Config>() -> BalanceOf<T> {
+	T::Deposit::minimum_balance().saturating_mul(100u32.into())
+}
+
+fn block_after<T: Config>(blocks: u32) -> T::BlockNumber {
+	frame_system::Pallet::<T>::block_number().saturating_add(blocks.into())
+}
+
+fn judge_panel<T: Config>(size: u32) -> BoundedVec<T::AccountId, T::MaxMembers> {
+	let judges: Vec<T::AccountId> = (0..size).map(|i| funded_account::<T>("judge", i)).collect();
+	judges.try_into().expect("size is at most MaxMembers")
+}
+
+/// Register a hackathon, whose default periods open submissions once its creation window closes.
+fn hackathon<T: Config>() -> (T::AccountId, u32) {
+	let organizer = funded_account::<T>("organizer", 0);
+	let hackathon_id = NextHackathonId::<T>::get();
+	assert_ok!(Buidl::<T>::register(RawOrigin::Signed(organizer.clone()).into(), H256::zero()));
+	(organizer, hackathon_id)
+}
+
+/// Open the hackathon's submission window at the current block, followed by the voting window.
+fn open_periods<T: Config>(organizer: T::AccountId, hackathon_id: u32) -> EventPeriods<T::BlockNumber> {
+	let periods = EventPeriods {
+		submission_start: block_after::<T>(0),
+		submission_end: block_after::<T>(PHASE_BLOCKS),
+		vote_start: block_after::<T>(PHASE_BLOCKS),
+		vote_end: block_after::<T>(2 * PHASE_BLOCKS),
+	};
+	assert_ok!(Buidl::<T>::update_period(
+		RawOrigin::Signed(organizer).into(),
+		hackathon_id,
+		periods.submission_start,
+		periods.submission_end,
+		periods.vote_start,
+		periods.vote_end,
+	));
+	periods
+}
+
+fn challenge<T: Config>(hackathon_id: u32, judges: Vec<T::AccountId>) -> (T::AccountId, u16) {
+	let author = funded_account::<T>("author", 0);
+	let challenge_id = NextChallengeId::<T>::get();
+	let judges = if judges.is_empty() {
+		None
+	} else {
+		Some(judges.try_into().expect("at most MaxMembers judges"))
+	};
+	assert_ok!(Buidl::<T>::create_challenge(
+		RawOrigin::Signed(author.clone()).into(),
+		hackathon_id,
+		H256::zero(),
+		reward::<T>(),
+		judges,
+		None,
+	));
+	// hold whatever `DepositFraction` left outstanding, so the challenge accepts solutions
+	let outstanding = Challenges::<T>::get(hackathon_id, challenge_id)
+		.expect("challenge was just created")
+		.outstanding;
+	if !outstanding.is_zero() {
+		assert_ok!(Buidl::<T>::fund_challenge(
+			RawOrigin::Signed(author.clone()).into(),
+			hackathon_id,
+			challenge_id,
+			outstanding,
+		));
+	}
+	(author, challenge_id)
+}
+
+/// Create a team of `size` members, each invitee accepting. The founder comes first and is funded.
+fn team<T: Config>(size: u32) -> (u32, Vec<T::AccountId>) {
+	let founder = funded_account::<T>("member", 0);
+	let others: Vec<T::AccountId> = (1..size).map(|i| account("member", i, SEED)).collect();
+	let team_id = NextTeamId::<T>::get();
+	assert_ok!(Buidl::<T>::create_team(RawOrigin::Signed(founder).into(), others.clone()));
+	for member in others {
+		assert_ok!(Buidl::<T>::accept_team_invite(RawOrigin::Signed(member).into(), team_id));
+	}
+	(team_id, Teams::<T>::get(team_id).expect("team was just created").members.into_inner())
+}
+
+fn submit<T: Config>(hackathon_id: u32, challenge_id: u16, size: u32) {
+	let (team_id, members) = team::<T>(size);
+	assert_ok!(Buidl::<T>::submit_solution(
+		RawOrigin::Signed(members[0].clone()).into(),
+		hackathon_id,
+		challenge_id,
+		team_id,
+		H256::zero(),
+		false,
+	));
+}
+
+/// Post a bounty from a one-member team, returning the bounty id and its poster.
+fn bounty<T: Config>() -> (u32, T::AccountId) {
+	let (team_id, members) = team::<T>(1);
+	let (_, hackathon_id) = hackathon::<T>();
+	let bounty_id = NextBountyId::<T>::get();
+	assert_ok!(Buidl::<T>::post_bounty(
+		RawOrigin::Signed(members[0].clone()).into(),
+		team_id,
+		hackathon_id,
+		H256::zero(),
+		reward::<T>(),
+		block_after::<T>(PHASE_BLOCKS),
+		// a multiple of any `PercentageStep`
+		0,
+	));
+	(bounty_id, members[0].clone())
+}
+
+/// Record `c` distinct contributors to a challenge's reward, each owed the minimum balance out of
+/// the funder's hold.
+fn contributors<T: Config>(challenge_id: u16, c: u32) {
+	for i in 0..c {
+		let contributor: T::AccountId = account("contributor", i, SEED);
+		ChallengeContributions::<T>::insert(challenge_id, contributor, T::Deposit::minimum_balance());
+	}
+}
+
+/// Add a prize judged by `judges` to a challenge, funded by the challenge author.
+fn prize<T: Config>(
+	hackathon_id: u32,
+	challenge_id: u16,
+	author: &T::AccountId,
+	judges: Vec<T::AccountId>,
+) {
+	assert_ok!(Buidl::<T>::add_prize(
+		RawOrigin::Signed(author.clone()).into(),
+		hackathon_id,
+		challenge_id,
+		H256::zero(),
+		reward::<T>(),
+		judges.try_into().expect("at most MaxMembers judges"),
+	));
+}
+
+fn claim<T: Config>(bounty_id: u32) {
+	let claimant: T::AccountId = account("claimant", 0, SEED);
+	assert_ok!(Buidl::<T>::claim_bounty(RawOrigin::Signed(claimant).into(), bounty_id, H256::zero()));
+}
+
+benchmarks! {
+	on_initialize {
+		let h in 0 .. T::MaxHackathonsPerBlock::get();
+		let now = block_after::<T>(PHASE_BLOCKS);
+		let ended: BoundedVec<u32, T::MaxHackathonsPerBlock> =
+			(0 .. h).collect::<Vec<u32>>().try_into().expect("at most MaxHackathonsPerBlock");
+		SubmissionEnds::<T>::insert(now, ended.clone());
+		VoteEnds::<T>::insert(now, ended);
+		let active: BoundedVec<u32, T::MaxActiveHackathons> = (0 .. T::MaxActiveHackathons::get())
+			.collect::<Vec<u32>>()
+			.try_into()
+			.expect("exactly MaxActiveHackathons");
+		ActiveHackathons::<T>::put(active);
+	}: {
+		Buidl::<T>::on_initialize(now);
+	}
+	verify {
+		assert!(!SubmissionEnds::<T>::contains_key(now));
+		assert!(!VoteEnds::<T>::contains_key(now));
+	}
+
+	register {
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let hackathon_id = NextHackathonId::<T>::get();
+	}: _(RawOrigin::Signed(caller.clone()), H256::zero())
+	verify {
+		assert_eq!(Hackathons::<T>::get(hackathon_id).map(|h| h.organizer), Some(caller));
+	}
+
+	create_challenge {
+		let j in 1 .. T::MaxMembers::get();
+		let (_, hackathon_id) = hackathon::<T>();
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let judges = judge_panel::<T>(j);
+		let challenge_id = NextChallengeId::<T>::get();
+	}: _(RawOrigin::Signed(caller), hackathon_id, H256::zero(), reward::<T>(), Some(judges), Some(H256::zero()))
+	verify {
+		assert!(Challenges::<T>::contains_key(hackathon_id, challenge_id));
+	}
+
+	create_treasury_challenge {
+		let j in 1 .. T::MaxMembers::get();
+		let (_, hackathon_id) = hackathon::<T>();
+		fund::<T>(&T::TreasuryAccount::get());
+		let author: T::AccountId = account("author", 0, SEED);
+		let judges = judge_panel::<T>(j);
+		let challenge_id = NextChallengeId::<T>::get();
+		let origin = T::AdminOrigin::successful_origin();
+	}: _<T::RuntimeOrigin>(origin, hackathon_id, author, H256::zero(), reward::<T>(), Some(judges), None)
+	verify {
+		assert!(Challenges::<T>::contains_key(hackathon_id, challenge_id));
+	}
+
+	edit_challenge {
+		let (_, hackathon_id) = hackathon::<T>();
+		let (author, challenge_id) = challenge::<T>(hackathon_id, Vec::new());
+	}: _(RawOrigin::Signed(author), hackathon_id, challenge_id, H256::repeat_byte(1), Some(H256::repeat_byte(2)))
+	verify {
+		assert_eq!(Challenges::<T>::get(hackathon_id, challenge_id).map(|c| c.edits), Some(1));
+	}
+
+	set_early_bonus {
+		let (_, hackathon_id) = hackathon::<T>();
+		let (author, challenge_id) = challenge::<T>(hackathon_id, Vec::new());
+		let bonus = EarlyBonus { cutoff: block_after::<T>(PHASE_BLOCKS), votes: 1 };
+	}: _(RawOrigin::Signed(author), hackathon_id, challenge_id, Some(bonus.clone()))
+	verify {
+		assert_eq!(Challenges::<T>::get(hackathon_id, challenge_id).and_then(|c| c.early_bonus), Some(bonus));
+	}
+
+	set_required_category {
+		let (_, hackathon_id) = hackathon::<T>();
+		let (author, challenge_id) = challenge::<T>(hackathon_id, Vec::new());
+	}: _(RawOrigin::Signed(author), hackathon_id, challenge_id, Some(1))
+	verify {
+		assert_eq!(Challenges::<T>::get(hackathon_id, challenge_id).and_then(|c| c.required_category), Some(1));
+	}
+
+	set_required_agreement {
+		let (_, hackathon_id) = hackathon::<T>();
+		let (author, challenge_id) = challenge::<T>(hackathon_id, Vec::new());
+		let agreement = Permill::from_percent(60);
+	}: _(RawOrigin::Signed(author), hackathon_id, challenge_id, agreement)
+	verify {
+		assert_eq!(Challenges::<T>::get(hackathon_id, challenge_id).map(|c| c.required_agreement), Some(agreement));
+	}
+
+	fund_challenge {
+		let (_, hackathon_id) = hackathon::<T>();
+		let (author, challenge_id) = challenge::<T>(hackathon_id, Vec::new());
+		// whatever the runtime's `DepositFraction`, leave the whole reward to be held
+		Challenges::<T>::mutate(hackathon_id, challenge_id, |challenge| {
+			if let Some(challenge) = challenge {
+				challenge.outstanding = challenge.reward;
+			}
+		});
+	}: _(RawOrigin::Signed(author), hackathon_id, challenge_id, reward::<T>())
+	verify {
+		assert_eq!(Challenges::<T>::get(hackathon_id, challenge_id).map(|c| c.outstanding.is_zero()), Some(true));
+	}
+
+	add_prize {
+		let j in 1 .. T::MaxMembers::get();
+		let (_, hackathon_id) = hackathon::<T>();
+		let (author, challenge_id) = challenge::<T>(hackathon_id, Vec::new());
+		let judges = judge_panel::<T>(j);
+	}: _(RawOrigin::Signed(author), hackathon_id, challenge_id, H256::zero(), reward::<T>(), judges)
+	verify {
+		assert!(ChallengePrizes::<T>::contains_key(challenge_id, 0));
+	}
+
+	cancel_challenge {
+		let c in 0 .. T::MaxContributors::get();
+		let p in 0 .. T::MaxPrizes::get();
+		let (_, hackathon_id) = hackathon::<T>();
+		let (author, challenge_id) = challenge::<T>(hackathon_id, Vec::new());
+		contributors::<T>(challenge_id, c);
+		for _ in 0 .. p {
+			prize::<T>(hackathon_id, challenge_id, &author, judge_panel::<T>(1).into_inner());
+		}
+	}: _(RawOrigin::Signed(author), hackathon_id, challenge_id)
+	verify {
+		assert!(!Challenges::<T>::contains_key(hackathon_id, challenge_id));
+		assert_eq!(ChallengeContributions::<T>::iter_prefix(challenge_id).count(), 0);
+		assert_eq!(ChallengePrizes::<T>::iter_prefix(challenge_id).count(), 0);
+	}
+
+	submit_solution {
+		let m in 1 .. T::MaxMembers::get();
+		let s in 0 .. T::MaxSolutions::get() - 1;
+		let (organizer, hackathon_id) = hackathon::<T>();
+		let (_, challenge_id) = challenge::<T>(hackathon_id, Vec::new());
+		open_periods::<T>(organizer, hackathon_id);
+		let (team_id, members) = team::<T>(m);
+		// every earlier solution is scanned for this team's, so placeholders from another team
+		// cost as much as real submissions; they sit after the id this submission takes
+		let other = SubmittedSolution::<T> {
+			solution: H256::zero(),
+			team_id: team_id.saturating_add(1),
+			submitter: account("submitter", 0, SEED),
+			members: BoundedVec::default(),
+			anonymous: false,
+			submitted_at: frame_system::Pallet::<T>::block_number(),
+			draft: false,
+			bond: Zero::zero(),
+			disqualified: false,
+		};
+		for solution_id in 1 ..= s {
+			ChallengeSolutions::<T>::insert(challenge_id, solution_id as u16, other.clone());
+		}
+	}: _(RawOrigin::Signed(members[0].clone()), hackathon_id, challenge_id, team_id, H256::zero(), false)
+	verify {
+		assert!(ChallengeSolutions::<T>::contains_key(challenge_id, 0));
+	}
+
+	draft_solution {
+		let m in 1 .. T::MaxMembers::get();
+		let s in 0 .. T::MaxSolutions::get() - 1;
+		let (organizer, hackathon_id) = hackathon::<T>();
+		let (_, challenge_id) = challenge::<T>(hackathon_id, Vec::new());
+		open_periods::<T>(organizer, hackathon_id);
+		let (team_id, members) = team::<T>(m);
+		// placeholders from another team, as for `submit_solution`
+		let other = SubmittedSolution::<T> {
+			solution: H256::zero(),
+			team_id: team_id.saturating_add(1),
+			submitter: account("submitter", 0, SEED),
+			members: BoundedVec::default(),
+			anonymous: false,
+			submitted_at: frame_system::Pallet::<T>::block_number(),
+			draft: false,
+			bond: Zero::zero(),
+			disqualified: false,
+		};
+		for solution_id in 1 ..= s {
+			ChallengeSolutions::<T>::insert(challenge_id, solution_id as u16, other.clone());
+		}
+	}: _(RawOrigin::Signed(members[0].clone()), hackathon_id, challenge_id, team_id, H256::zero(), false)
+	verify {
+		assert!(ChallengeSolutions::<T>::get(challenge_id, 0).map_or(false, |s| s.draft));
+	}
+
+	set_official_solution {
+		let (organizer, hackathon_id) = hackathon::<T>();
+		let (_, challenge_id) = challenge::<T>(hackathon_id, Vec::new());
+		open_periods::<T>(organizer, hackathon_id);
+		let (team_id, members) = team::<T>(1);
+		assert_ok!(Buidl::<T>::draft_solution(
+			RawOrigin::Signed(members[0].clone()).into(),
+			hackathon_id,
+			challenge_id,
+			team_id,
+			H256::zero(),
+			false,
+		));
+	}: _(RawOrigin::Signed(members[0].clone()), hackathon_id, challenge_id, 0)
+	verify {
+		assert!(ChallengeSolutions::<T>::get(challenge_id, 0).map_or(false, |s| !s.draft));
+	}
+
+	disqualify_solution {
+		let v in 0 .. T::MaxMembers::get();
+		let (organizer, hackathon_id) = hackathon::<T>();
+		// every judge who voted for the solution gets a strike
+		let panel = judge_panel::<T>(T::MaxMembers::get()).into_inner();
+		let (_, challenge_id) = challenge::<T>(hackathon_id, panel.clone());
+		let periods = open_periods::<T>(organizer.clone(), hackathon_id);
+		submit::<T>(hackathon_id, challenge_id, 1);
+		frame_system::Pallet::<T>::set_block_number(periods.vote_start);
+		for judge in panel.iter().take(v as usize) {
+			assert_ok!(Buidl::<T>::vote(RawOrigin::Signed(judge.clone()).into(), hackathon_id, challenge_id, 0));
+		}
+	}: _(RawOrigin::Signed(organizer), hackathon_id, challenge_id, 0)
+	verify {
+		assert!(ChallengeSolutions::<T>::get(challenge_id, 0).map_or(false, |s| s.disqualified));
+	}
+
+	withdraw_solution {
+		let m in 1 .. T::MaxMembers::get();
+		let (organizer, hackathon_id) = hackathon::<T>();
+		let (_, challenge_id) = challenge::<T>(hackathon_id, Vec::new());
+		open_periods::<T>(organizer, hackathon_id);
+		let (team_id, members) = team::<T>(m);
+		assert_ok!(Buidl::<T>::submit_solution(
+			RawOrigin::Signed(members[0].clone()).into(),
+			hackathon_id,
+			challenge_id,
+			team_id,
+			H256::zero(),
+			false,
+		));
+	}: _(RawOrigin::Signed(members[0].clone()), hackathon_id, challenge_id, 0)
+	verify {
+		assert!(!ChallengeSolutions::<T>::contains_key(challenge_id, 0));
+	}
+
+	add_judges {
+		let j in 1 .. T::MaxMembers::get();
+		let (_, hackathon_id) = hackathon::<T>();
+		let (author, challenge_id) = challenge::<T>(hackathon_id, Vec::new());
+		let judges = judge_panel::<T>(j).into_inner();
+	}: _(RawOrigin::Signed(author), hackathon_id, challenge_id, judges)
+	verify {
+		assert_eq!(JudgeInvites::<T>::iter_prefix(challenge_id).count() as u32, j);
+	}
+
+	accept_judge_role {
+		let (_, hackathon_id) = hackathon::<T>();
+		let (author, challenge_id) = challenge::<T>(hackathon_id, Vec::new());
+		let judge = funded_account::<T>("judge", 0);
+		assert_ok!(Buidl::<T>::add_judges(
+			RawOrigin::Signed(author).into(),
+			hackathon_id,
+			challenge_id,
+			vec![judge.clone()],
+		));
+	}: _(RawOrigin::Signed(judge.clone()), hackathon_id, challenge_id)
+	verify {
+		assert!(Challenges::<T>::get(hackathon_id, challenge_id)
+			.and_then(|c| c.judges)
+			.map_or(false, |judges| judges.contains(&judge)));
+	}
+
+	request_info {
+		let (organizer, hackathon_id) = hackathon::<T>();
+		let judge = funded_account::<T>("judge", 0);
+		let (_, challenge_id) = challenge::<T>(hackathon_id, vec![judge.clone()]);
+		open_periods::<T>(organizer, hackathon_id);
+		submit::<T>(hackathon_id, challenge_id, 1);
+	}: _(RawOrigin::Signed(judge), hackathon_id, challenge_id, 0, H256::zero())
+
+	// the caller's backing completes the majority, so every judge's backing is counted and cleared
+	judges_request_extension {
+		let j in 1 .. T::MaxEventJudges::get();
+		let (_, hackathon_id) = hackathon::<T>();
+		let judges: Vec<T::AccountId> = (0..j).map(|i| account("judge", i, SEED)).collect();
+		let blocks: T::BlockNumber = PHASE_BLOCKS.into();
+		for judge in judges.iter().skip(1).take((j / 2) as usize) {
+			ExtensionSupport::<T>::insert(hackathon_id, judge, blocks);
+		}
+		let caller = judges[0].clone();
+		let judges: BoundedVec<T::AccountId, T::MaxEventJudges> =
+			judges.try_into().expect("j is at most MaxEventJudges");
+		EventJudges::<T>::insert(hackathon_id, judges);
+	}: _(RawOrigin::Signed(caller), hackathon_id, blocks)
+	verify {
+		assert_eq!(ExtensionSupport::<T>::iter_prefix(hackathon_id).count(), 0);
+	}
+
+	update_period {
+		let (organizer, hackathon_id) = hackathon::<T>();
+		// worst case: earlier periods are unscheduled and the new ends share a block with as many
+		// other hackathons as fit
+		open_periods::<T>(organizer.clone(), hackathon_id);
+		let start = block_after::<T>(0);
+		let middle = block_after::<T>(PHASE_BLOCKS + 1);
+		let end = block_after::<T>(2 * PHASE_BLOCKS + 1);
+		let others: Vec<u32> = (1 .. T::MaxHackathonsPerBlock::get()).map(|i| hackathon_id + i).collect();
+		let others: BoundedVec<u32, T::MaxHackathonsPerBlock> =
+			others.try_into().expect("fewer than MaxHackathonsPerBlock");
+		SubmissionEnds::<T>::insert(middle, others.clone());
+		VoteEnds::<T>::insert(end, others);
+	}: _(RawOrigin::Signed(organizer), hackathon_id, start, middle, middle, end)
+	verify {
+		assert!(VoteEnds::<T>::get(end).contains(&hackathon_id));
+	}
+
+	close_submissions_early {
+		let (organizer, hackathon_id) = hackathon::<T>();
+		open_periods::<T>(organizer.clone(), hackathon_id);
+	}: _(RawOrigin::Signed(organizer), hackathon_id)
+	verify {
+		assert!(Buidl::<T>::is_voting_open(hackathon_id));
+	}
+
+	set_allow_late_edits {
+		let (organizer, hackathon_id) = hackathon::<T>();
+	}: _(RawOrigin::Signed(organizer), hackathon_id, true)
+	verify {
+		assert_eq!(Hackathons::<T>::get(hackathon_id).map(|h| h.allow_late_edits), Some(true));
+	}
+
+	set_allow_ownership_bounties {
+		let (organizer, hackathon_id) = hackathon::<T>();
+	}: _(RawOrigin::Signed(organizer), hackathon_id, false)
+	verify {
+		assert_eq!(Hackathons::<T>::get(hackathon_id).map(|h| h.allow_ownership_bounties), Some(false));
+	}
+
+	set_hackathon_metadata {
+		let (organizer, hackathon_id) = hackathon::<T>();
+	}: _(RawOrigin::Signed(organizer), hackathon_id, H256::repeat_byte(1))
+	verify {
+		assert_eq!(Hackathons::<T>::get(hackathon_id).and_then(|h| h.metadata), Some(H256::repeat_byte(1)));
+	}
+
+	set_hackathon_visibility {
+		let (organizer, hackathon_id) = hackathon::<T>();
+	}: _(RawOrigin::Signed(organizer), hackathon_id, false)
+	verify {
+		assert_eq!(Hackathons::<T>::get(hackathon_id).map(|h| h.public), Some(false));
+	}
+
+	update_team_size {
+		let (_, hackathon_id) = hackathon::<T>();
+		let origin = T::AdminOrigin::successful_origin();
+	}: _<T::RuntimeOrigin>(origin, hackathon_id, 1)
+	verify {
+		assert_eq!(Hackathons::<T>::get(hackathon_id).map(|h| h.max_team_size), Some(1));
+	}
+
+	extend_challenge_window {
+		let (organizer, hackathon_id) = hackathon::<T>();
+		let deadline = Hackathons::<T>::get(hackathon_id).expect("hackathon was just registered").challenge_deadline;
+		// leave a block between the creation window and submissions to extend into
+		let submission_start = deadline.saturating_add(2u32.into());
+		let submission_end = submission_start.saturating_add(1u32.into());
+		assert_ok!(Buidl::<T>::update_period(
+			RawOrigin::Signed(organizer.clone()).into(),
+			hackathon_id,
+			submission_start,
+			submission_end,
+			submission_end,
+			submission_end,
+		));
+		let new_deadline = deadline.saturating_add(1u32.into());
+	}: _(RawOrigin::Signed(organizer), hackathon_id, new_deadline)
+	verify {
+		assert_eq!(Hackathons::<T>::get(hackathon_id).map(|h| h.challenge_deadline), Some(new_deadline));
+	}
+
+	set_event_judges {
+		let j in 1 .. T::MaxEventJudges::get();
+		let (organizer, hackathon_id) = hackathon::<T>();
+		let judges: Vec<T::AccountId> = (0..j).map(|i| account("judge", i, SEED)).collect();
+		let judges: BoundedVec<T::AccountId, T::MaxEventJudges> =
+			judges.try_into().expect("j is at most MaxEventJudges");
+	}: _(RawOrigin::Signed(organizer), hackathon_id, judges)
+	verify {
+		assert_eq!(EventJudges::<T>::get(hackathon_id).len() as u32, j);
+	}
+
+	auto_assign_judges {
+		// challenges per hackathon are not bounded, so this samples a busy event
+		let c in 1 .. 20;
+		let (organizer, hackathon_id) = hackathon::<T>();
+		for _ in 0 .. c {
+			challenge::<T>(hackathon_id, Vec::new());
+		}
+		let judges: Vec<T::AccountId> =
+			(0..T::MaxEventJudges::get()).map(|i| account("judge", i, SEED)).collect();
+		let judges: BoundedVec<T::AccountId, T::MaxEventJudges> =
+			judges.try_into().expect("MaxEventJudges judges");
+		assert_ok!(Buidl::<T>::set_event_judges(RawOrigin::Signed(organizer).into(), hackathon_id, judges));
+		let origin = T::AdminOrigin::successful_origin();
+	}: _<T::RuntimeOrigin>(origin, hackathon_id, T::MaxMembers::get())
+	verify {
+		assert!(Challenges::<T>::iter_prefix_values(hackathon_id).all(|c| c.judges.is_some()));
+	}
+
+	vote {
+		let s in 1 .. T::MaxSolutions::get();
+		let (organizer, hackathon_id) = hackathon::<T>();
+		let judge = funded_account::<T>("judge", 0);
+		let (_, challenge_id) = challenge::<T>(hackathon_id, vec![judge.clone()]);
+		let periods = open_periods::<T>(organizer, hackathon_id);
+		submit::<T>(hackathon_id, challenge_id, 1);
+		// every solution's members are checked against the judge, so copies of the first are as
+		// costly as submissions from distinct teams
+		let solution = ChallengeSolutions::<T>::get(challenge_id, 0).expect("solution was just submitted");
+		for solution_id in 1 .. s {
+			ChallengeSolutions::<T>::insert(challenge_id, solution_id as u16, solution.clone());
+		}
+		frame_system::Pallet::<T>::set_block_number(periods.vote_start);
+	}: _(RawOrigin::Signed(judge.clone()), hackathon_id, challenge_id, 0)
+	verify {
+		assert_eq!(Votes::<T>::get(challenge_id, judge), Some(0));
+	}
+
+	delegate_vote {
+		let s in 1 .. T::MaxSolutions::get();
+		let (organizer, hackathon_id) = hackathon::<T>();
+		let panel = judge_panel::<T>(2).into_inner();
+		let (_, challenge_id) = challenge::<T>(hackathon_id, panel.clone());
+		let periods = open_periods::<T>(organizer, hackathon_id);
+		submit::<T>(hackathon_id, challenge_id, 1);
+		// both judges are checked against every solution's members
+		let solution = ChallengeSolutions::<T>::get(challenge_id, 0).expect("solution was just submitted");
+		for solution_id in 1 .. s {
+			ChallengeSolutions::<T>::insert(challenge_id, solution_id as u16, solution.clone());
+		}
+		frame_system::Pallet::<T>::set_block_number(periods.vote_start);
+	}: _(RawOrigin::Signed(panel[0].clone()), hackathon_id, challenge_id, panel[1].clone())
+	verify {
+		assert_eq!(VoteDelegations::<T>::get(challenge_id, &panel[0]), Some(panel[1].clone()));
+	}
+
+	revoke_delegation {
+		let (organizer, hackathon_id) = hackathon::<T>();
+		let panel = judge_panel::<T>(2).into_inner();
+		let (_, challenge_id) = challenge::<T>(hackathon_id, panel.clone());
+		let periods = open_periods::<T>(organizer, hackathon_id);
+		frame_system::Pallet::<T>::set_block_number(periods.vote_start);
+		assert_ok!(Buidl::<T>::delegate_vote(
+			RawOrigin::Signed(panel[0].clone()).into(),
+			hackathon_id,
+			challenge_id,
+			panel[1].clone(),
+		));
+	}: _(RawOrigin::Signed(panel[0].clone()), hackathon_id, challenge_id)
+	verify {
+		assert!(!VoteDelegations::<T>::contains_key(challenge_id, &panel[0]));
+	}
+
+	finalize_challenge {
+		let m in 1 .. T::MaxMembers::get();
+		let c in 0 .. T::MaxContributors::get();
+		let s in 1 .. T::MaxSolutions::get();
+		let (organizer, hackathon_id) = hackathon::<T>();
+		let judge = funded_account::<T>("judge", 0);
+		let (_, challenge_id) = challenge::<T>(hackathon_id, vec![judge.clone()]);
+		contributors::<T>(challenge_id, c);
+		let periods = open_periods::<T>(organizer, hackathon_id);
+		submit::<T>(hackathon_id, challenge_id, m);
+		frame_system::Pallet::<T>::set_block_number(periods.vote_start);
+		assert_ok!(Buidl::<T>::vote(RawOrigin::Signed(judge).into(), hackathon_id, challenge_id, 0));
+		// every solution's submission bond is settled, not just the winner's
+		let solution = ChallengeSolutions::<T>::get(challenge_id, 0).expect("solution was just submitted");
+		for solution_id in 1 .. s {
+			ChallengeSolutions::<T>::insert(challenge_id, solution_id as u16, solution.clone());
+		}
+		frame_system::Pallet::<T>::set_block_number(periods.vote_end);
+		let caller: T::AccountId = whitelisted_caller();
+	}: _(RawOrigin::Signed(caller), hackathon_id, challenge_id)
+	verify {
+		assert_eq!(ChallengeWinners::<T>::get(challenge_id), Some(0));
+		assert_eq!(ChallengeContributions::<T>::iter_prefix(challenge_id).count(), 0);
+	}
+
+	vote_prize {
+		let s in 1 .. T::MaxSolutions::get();
+		let (organizer, hackathon_id) = hackathon::<T>();
+		let judge = funded_account::<T>("judge", 0);
+		let (author, challenge_id) = challenge::<T>(hackathon_id, Vec::new());
+		prize::<T>(hackathon_id, challenge_id, &author, vec![judge.clone()]);
+		let periods = open_periods::<T>(organizer, hackathon_id);
+		submit::<T>(hackathon_id, challenge_id, 1);
+		// every solution's members are checked against the judge
+		let solution = ChallengeSolutions::<T>::get(challenge_id, 0).expect("solution was just submitted");
+		for solution_id in 1 .. s {
+			ChallengeSolutions::<T>::insert(challenge_id, solution_id as u16, solution.clone());
+		}
+		frame_system::Pallet::<T>::set_block_number(periods.vote_start);
+	}: _(RawOrigin::Signed(judge.clone()), hackathon_id, challenge_id, 0, 0)
+	verify {
+		assert_eq!(PrizeVotes::<T>::get((challenge_id, 0), judge), Some(0));
+	}
+
+	finalize_prize {
+		let m in 1 .. T::MaxMembers::get();
+		let (organizer, hackathon_id) = hackathon::<T>();
+		let judge = funded_account::<T>("judge", 0);
+		let (author, challenge_id) = challenge::<T>(hackathon_id, Vec::new());
+		prize::<T>(hackathon_id, challenge_id, &author, vec![judge.clone()]);
+		let periods = open_periods::<T>(organizer, hackathon_id);
+		submit::<T>(hackathon_id, challenge_id, m);
+		frame_system::Pallet::<T>::set_block_number(periods.vote_start);
+		assert_ok!(Buidl::<T>::vote_prize(RawOrigin::Signed(judge).into(), hackathon_id, challenge_id, 0, 0));
+		frame_system::Pallet::<T>::set_block_number(periods.vote_end);
+		let caller: T::AccountId = whitelisted_caller();
+	}: _(RawOrigin::Signed(caller), hackathon_id, challenge_id, 0)
+	verify {
+		assert_eq!(ChallengePrizes::<T>::get(challenge_id, 0).and_then(|p| p.winner), Some(0));
+	}
+
+	claim_vested {
+		let winner = funded_account::<T>("winner", 0);
+		let total = reward::<T>();
+		assert_ok!(T::RewardCurrency::hold(&winner, total));
+		let schedule = VestingSchedule {
+			total,
+			released: Zero::zero(),
+			start: Zero::zero(),
+			duration: 2u32.into(),
+		};
+		let reward_id: (u16, Option<u16>) = (0, None);
+		VestingSchedules::<T>::insert(&winner, reward_id, schedule);
+		// half of the share has vested, so the schedule is kept rather than removed
+		frame_system::Pallet::<T>::set_block_number(1u32.into());
+	}: _(RawOrigin::Signed(winner.clone()), 0, None)
+	verify {
+		assert_eq!(
+			VestingSchedules::<T>::get(&winner, reward_id).map(|schedule| schedule.released),
+			Some(Permill::from_percent(50).mul_floor(total))
+		);
+	}
+
+	escalate_dispute {
+		let s in 1 .. T::MaxSolutions::get();
+		let (organizer, hackathon_id) = hackathon::<T>();
+		let (_, challenge_id) = challenge::<T>(hackathon_id, Vec::new());
+		open_periods::<T>(organizer, hackathon_id);
+		submit::<T>(hackathon_id, challenge_id, 1);
+		let solution = ChallengeSolutions::<T>::get(challenge_id, 0).expect("solution was just submitted");
+		let caller = solution.members[0].clone();
+		// every other solution is searched for the caller's team
+		let other = SubmittedSolution::<T> { members: BoundedVec::default(), ..solution };
+		for solution_id in 1 .. s {
+			ChallengeSolutions::<T>::insert(challenge_id, solution_id as u16, other.clone());
+		}
+	}: _(RawOrigin::Signed(caller), challenge_id)
+	verify {
+		assert!(EscalatedDisputes::<T>::contains_key(challenge_id));
+	}
+
+	resolve_dispute {
+		let (organizer, hackathon_id) = hackathon::<T>();
+		let (_, challenge_id) = challenge::<T>(hackathon_id, Vec::new());
+		open_periods::<T>(organizer.clone(), hackathon_id);
+		submit::<T>(hackathon_id, challenge_id, 1);
+		assert_ok!(Buidl::<T>::disqualify_solution(
+			RawOrigin::Signed(organizer).into(),
+			hackathon_id,
+			challenge_id,
+			0,
+		));
+		let solution = ChallengeSolutions::<T>::get(challenge_id, 0).expect("solution was just submitted");
+		assert_ok!(Buidl::<T>::escalate_dispute(
+			RawOrigin::Signed(solution.members[0].clone()).into(),
+			challenge_id,
+		));
+	}: _(RawOrigin::Root, challenge_id, true)
+	verify {
+		assert!(ChallengeSolutions::<T>::get(challenge_id, 0).map_or(false, |s| !s.disqualified));
+	}
+
+	archive_challenge {
+		let s in 1 .. T::MaxSolutions::get();
+		let (organizer, hackathon_id) = hackathon::<T>();
+		let judge = funded_account::<T>("judge", 0);
+		let (_, challenge_id) = challenge::<T>(hackathon_id, vec![judge.clone()]);
+		let periods = open_periods::<T>(organizer, hackathon_id);
+		submit::<T>(hackathon_id, challenge_id, 1);
+		// every solution and the voters recorded for it are removed
+		let solution = ChallengeSolutions::<T>::get(challenge_id, 0).expect("solution was just submitted");
+		for solution_id in 1 .. s {
+			ChallengeSolutions::<T>::insert(challenge_id, solution_id as u16, solution.clone());
+		}
+		frame_system::Pallet::<T>::set_block_number(periods.vote_start);
+		assert_ok!(Buidl::<T>::vote(RawOrigin::Signed(judge.clone()).into(), hackathon_id, challenge_id, 0));
+		frame_system::Pallet::<T>::set_block_number(periods.vote_end);
+		assert_ok!(Buidl::<T>::finalize_challenge(RawOrigin::Signed(judge).into(), hackathon_id, challenge_id));
+		let origin = T::AdminOrigin::successful_origin();
+	}: _<T::RuntimeOrigin>(origin, hackathon_id, challenge_id)
+	verify {
+		assert!(!Challenges::<T>::contains_key(hackathon_id, challenge_id));
+		assert!(ChallengeArchives::<T>::contains_key(challenge_id));
+	}
+
+	acknowledge_contributors {
+		let c in 0 .. T::MaxMembers::get();
+		let (organizer, hackathon_id) = hackathon::<T>();
+		let judge = funded_account::<T>("judge", 0);
+		let (_, challenge_id) = challenge::<T>(hackathon_id, vec![judge.clone()]);
+		let periods = open_periods::<T>(organizer, hackathon_id);
+		submit::<T>(hackathon_id, challenge_id, 1);
+		frame_system::Pallet::<T>::set_block_number(periods.vote_start);
+		assert_ok!(Buidl::<T>::vote(RawOrigin::Signed(judge.clone()).into(), hackathon_id, challenge_id, 0));
+		frame_system::Pallet::<T>::set_block_number(periods.vote_end);
+		assert_ok!(Buidl::<T>::finalize_challenge(RawOrigin::Signed(judge).into(), hackathon_id, challenge_id));
+		let member: T::AccountId = account("member", 0, SEED);
+		let contributors: BoundedVec<(H256, u8), T::MaxMembers> = (0 .. c)
+			.map(|i| (H256::repeat_byte(i as u8), 1))
+			.collect::<Vec<_>>()
+			.try_into()
+			.expect("at most MaxMembers");
+	}: _(RawOrigin::Signed(member), challenge_id, contributors)
+	verify {
+		assert!(ChallengeWinners::<T>::contains_key(challenge_id));
+	}
+
+	create_team {
+		let m in 0 .. T::MaxMembers::get() - 1;
+		let caller: T::AccountId = whitelisted_caller();
+		fund::<T>(&caller);
+		let members: Vec<T::AccountId> = (0..m).map(|i| account("member", i, SEED)).collect();
+		let team_id = NextTeamId::<T>::get();
+	}: _(RawOrigin::Signed(caller), members)
+	verify {
+		assert_eq!(TeamInvites::<T>::iter_prefix(team_id).count() as u32, m);
+	}
+
+	add_member {
+		let (team_id, members) = team::<T>(T::MaxMembers::get() - 1);
+		let new_member: T::AccountId = account("new_member", 0, SEED);
+	}: _(RawOrigin::Signed(members[0].clone()), team_id, new_member.clone())
+	verify {
+		assert!(TeamInvites::<T>::contains_key(team_id, &new_member));
+	}
+
+	accept_team_invite {
+		let (team_id, members) = team::<T>(T::MaxMembers::get() - 1);
+		let new_member: T::AccountId = account("new_member", 0, SEED);
+		assert_ok!(Buidl::<T>::add_member(RawOrigin::Signed(members[0].clone()).into(), team_id, new_member.clone()));
+	}: _(RawOrigin::Signed(new_member.clone()), team_id)
+	verify {
+		assert!(Teams::<T>::get(team_id).map_or(false, |t| t.members.contains(&new_member)));
+	}
+
+	set_team_category {
+		let (team_id, members) = team::<T>(1);
+	}: _(RawOrigin::Signed(members[0].clone()), team_id, Some(1))
+	verify {
+		assert_eq!(Teams::<T>::get(team_id).and_then(|t| t.category), Some(1));
+	}
+
+	set_team_captain {
+		let (team_id, members) = team::<T>(2);
+	}: _(RawOrigin::Signed(members[0].clone()), team_id, Some(members[1].clone()))
+	verify {
+		assert_eq!(Teams::<T>::get(team_id).and_then(|t| t.captain), Some(members[1].clone()));
+	}
+
+	set_private_roster {
+		let (team_id, members) = team::<T>(1);
+	}: _(RawOrigin::Signed(members[0].clone()), team_id, true)
+	verify {
+		assert!(Teams::<T>::get(team_id).map_or(false, |t| t.private_roster));
+	}
+
+	set_required_bounty_approvals {
+		let (team_id, members) = team::<T>(1);
+	}: _(RawOrigin::Signed(members[0].clone()), team_id, 2)
+	verify {
+		assert_eq!(Teams::<T>::get(team_id).map(|t| t.required_bounty_approvals), Some(2));
+	}
+
+	disband_team {
+		let (team_id, members) = team::<T>(1);
+		let invited: Vec<T::AccountId> =
+			(1..T::MaxMembers::get()).map(|i| account("invited", i, SEED)).collect();
+		for member in invited {
+			let founder = RawOrigin::Signed(members[0].clone());
+			assert_ok!(Buidl::<T>::add_member(founder.into(), team_id, member));
+		}
+	}: _(RawOrigin::Signed(members[0].clone()), team_id)
+	verify {
+		assert!(Teams::<T>::get(team_id).is_none());
+		assert_eq!(TeamInvites::<T>::iter_prefix(team_id).count(), 0);
+	}
+
+	claim_founder {
+		let (team_id, _) = team::<T>(2);
+		let claimant = funded_account::<T>("member", 1);
+		let timeout = T::FounderInactivityTimeout::get();
+		frame_system::Pallet::<T>::set_block_number(
+			frame_system::Pallet::<T>::block_number().saturating_add(timeout),
+		);
+	}: _(RawOrigin::Signed(claimant.clone()), team_id)
+	verify {
+		assert_eq!(Teams::<T>::get(team_id).map(|t| t.team_founder), Some(claimant));
+	}
+
+	slash_team_bond {
+		let (team_id, _) = team::<T>(1);
+		let origin = T::AdminOrigin::successful_origin();
+	}: _<T::RuntimeOrigin>(origin, team_id)
+	verify {
+		assert!(Teams::<T>::get(team_id).map_or(false, |t| t.bond.is_zero()));
+	}
+
+	declare_targets {
+		let (_, hackathon_id) = hackathon::<T>();
+		let mut targets = Vec::new();
+		for _ in 0 .. T::MaxTargets::get() {
+			targets.push(challenge::<T>(hackathon_id, Vec::new()).1);
+		}
+		let targets: BoundedVec<u16, T::MaxTargets> = targets.try_into().expect("MaxTargets challenges");
+		let (team_id, members) = team::<T>(1);
+	}: _(RawOrigin::Signed(members[0].clone()), team_id, hackathon_id, targets)
+	verify {
+		assert_eq!(TeamTargets::<T>::get(team_id).len() as u32, T::MaxTargets::get());
+	}
+
+	enter_hackathon {
+		let (_, hackathon_id) = hackathon::<T>();
+		let (team_id, members) = team::<T>(T::MaxMembers::get());
+	}: _(RawOrigin::Signed(members[0].clone()), team_id, hackathon_id)
+	verify {
+		assert!(TeamHackathons::<T>::contains_key(team_id, hackathon_id));
+	}
+
+	post_bounty {
+		let (team_id, members) = team::<T>(1);
+		let (_, hackathon_id) = hackathon::<T>();
+		let expiry = block_after::<T>(PHASE_BLOCKS);
+		let bounty_id = NextBountyId::<T>::get();
+	}: _(RawOrigin::Signed(members[0].clone()), team_id, hackathon_id, H256::zero(), reward::<T>(), expiry, 10)
+	verify {
+		assert!(Bounties::<T>::contains_key(bounty_id));
+	}
+
+	claim_bounty {
+		let (bounty_id, _) = bounty::<T>();
+		let claimant: T::AccountId = account("claimant", 0, SEED);
+	}: _(RawOrigin::Signed(claimant), bounty_id, H256::zero())
+	verify {
+		assert_eq!(Bounties::<T>::get(bounty_id).map(|b| b.status), Some(BountyStatus::Claimed));
+	}
+
+	update_claim_solution {
+		let (bounty_id, _) = bounty::<T>();
+		claim::<T>(bounty_id);
+		let claimant: T::AccountId = account("claimant", 0, SEED);
+	}: _(RawOrigin::Signed(claimant), bounty_id, H256::repeat_byte(1))
+	verify {
+		assert_eq!(Bounties::<T>::get(bounty_id).and_then(|b| b.solution), Some(H256::repeat_byte(1)));
+	}
+
+	approve_bounty {
+		let (bounty_id, poster) = bounty::<T>();
+		claim::<T>(bounty_id);
+	}: _(RawOrigin::Signed(poster), bounty_id)
+	verify {
+		assert_eq!(Bounties::<T>::get(bounty_id).map(|b| b.status), Some(BountyStatus::Approved));
+	}
+
+	set_bounty_milestones {
+		let m in 1 .. T::MaxMilestones::get();
+		let (bounty_id, poster) = bounty::<T>();
+		// every milestone but the first is empty, so the amounts always add up
+		let milestones: Vec<(H256, BalanceOf<T>)> = (0..m)
+			.map(|i| {
+				let amount = if i == 0 { reward::<T>() } else { Zero::zero() };
+				(H256::repeat_byte(i as u8), amount)
+			})
+			.collect();
+		let milestones: BoundedVec<_, T::MaxMilestones> = milestones.try_into().unwrap();
+	}: _(RawOrigin::Signed(poster), bounty_id, milestones)
+	verify {
+		assert_eq!(Bounties::<T>::get(bounty_id).map(|b| b.milestones.len() as u32), Some(m));
+	}
+
+	// the last milestone approves the whole bounty, which is the heavier path
+	approve_milestone {
+		let (bounty_id, poster) = bounty::<T>();
+		let milestones: BoundedVec<_, T::MaxMilestones> =
+			vec![(H256::zero(), reward::<T>())].try_into().unwrap();
+		assert_ok!(Buidl::<T>::set_bounty_milestones(
+			RawOrigin::Signed(poster.clone()).into(),
+			bounty_id,
+			milestones,
+		));
+		claim::<T>(bounty_id);
+	}: _(RawOrigin::Signed(poster), bounty_id, 0)
+	verify {
+		assert_eq!(Bounties::<T>::get(bounty_id).map(|b| b.status), Some(BountyStatus::Approved));
+	}
+
+	extend_bounty_expiry {
+		let (bounty_id, poster) = bounty::<T>();
+		let new_expiry = block_after::<T>(2 * PHASE_BLOCKS);
+	}: _(RawOrigin::Signed(poster), bounty_id, new_expiry)
+	verify {
+		assert_eq!(Bounties::<T>::get(bounty_id).map(|b| b.expiry), Some(new_expiry));
+	}
+
+	// a one-member team resets the bounty on the first rejection, which is the heavier path
+	reject_bounty {
+		let (bounty_id, poster) = bounty::<T>();
+		claim::<T>(bounty_id);
+	}: _(RawOrigin::Signed(poster), bounty_id)
+	verify {
+		assert_eq!(Bounties::<T>::get(bounty_id).map(|b| b.status), Some(BountyStatus::Open));
+	}
+
+	redirect_bounty_to_challenge {
+		let c in 0 .. T::MaxContributors::get() - 1;
+		let (_, hackathon_id) = hackathon::<T>();
+		let (_, challenge_id) = challenge::<T>(hackathon_id, Vec::new());
+		contributors::<T>(challenge_id, c);
+		let (bounty_id, poster) = bounty::<T>();
+		frame_system::Pallet::<T>::set_block_number(block_after::<T>(PHASE_BLOCKS));
+	}: _(RawOrigin::Signed(poster), bounty_id, hackathon_id, challenge_id)
+	verify {
+		assert!(!Bounties::<T>::contains_key(bounty_id));
+	}
+
+	cancel_bounty {
+		let (bounty_id, poster) = bounty::<T>();
+	}: _(RawOrigin::Signed(poster), bounty_id)
+	verify {
+		assert!(!Bounties::<T>::contains_key(bounty_id));
+	}
+
+	endorse_team {
+		let (team_id, _) = team::<T>(1);
+		let caller: T::AccountId = whitelisted_caller();
+	}: _(RawOrigin::Signed(caller), team_id)
+	verify {
+		assert_eq!(Buidl::<T>::endorsement_count(team_id), 1);
+	}
+
+	unendorse_team {
+		let (team_id, _) = team::<T>(1);
+		let caller: T::AccountId = whitelisted_caller();
+		assert_ok!(Buidl::<T>::endorse_team(RawOrigin::Signed(caller.clone()).into(), team_id));
+	}: _(RawOrigin::Signed(caller), team_id)
+	verify {
+		assert_eq!(Buidl::<T>::endorsement_count(team_id), 0);
+	}
+
+	register_buidler {
+		let caller: T::AccountId = whitelisted_caller();
+	}: _(RawOrigin::Signed(caller.clone()), H256::zero())
+	verify {
+		assert!(Buidl::<T>::buidler_profile(caller).is_some());
+	}
+
+	update_buidler_profile {
+		let caller: T::AccountId = whitelisted_caller();
+		assert_ok!(Buidl::<T>::register_buidler(RawOrigin::Signed(caller.clone()).into(), H256::zero()));
+	}: _(RawOrigin::Signed(caller.clone()), H256::repeat_byte(1))
+	verify {
+		assert_eq!(Buidl::<T>::buidler_profile(caller), Some(H256::repeat_byte(1)));
+	}
+
+	deregister_buidler {
+		let caller: T::AccountId = whitelisted_caller();
+		assert_ok!(Buidl::<T>::register_buidler(RawOrigin::Signed(caller.clone()).into(), H256::zero()));
+	}: _(RawOrigin::Signed(caller.clone()))
+	verify {
+		assert!(Buidl::<T>::buidler_profile(caller).is_none());
+	}
+
+	impl_benchmark_test_suite!(Buidl, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -32,35 +32,195 @@
 //! Sponsor actions:
 //! 
 //! - `create_challenge` - Admin just check that the funds are available.
-//! - `add_judges` - Admin can add the addresses of initial judges. Note: this should be available but it's the 
+//! - `create_treasury_challenge` - The admin origin can post a challenge whose reward comes from the treasury.
+//! - `add_judges` - Admin can invite judges, who join once they accept. Note: this should be available but it's the 
 //!                  challenge submitter's responsibility to tag judges in the challenge description.
-//! - `edit_challenge` - Challenge authors may need to update challenges.
+//! - `edit_challenge` - Challenge authors may need to update challenges, up until submissions open
+//!                      unless the organizer allows late edits.
+//! - `set_early_bonus` - Challenge authors can give solutions submitted before a cutoff block extra
+//!                       votes when ranking, up until submissions open.
+//! - `set_required_category` - Challenge authors can accept solutions only from teams in one category
+//!                             (track), up until submissions open.
+//! - `set_required_agreement` - Challenge authors can require the winning solution to have a share
+//!                              of the votes cast, such as a supermajority for a large reward, up
+//!                              until submissions open. Otherwise the reward is refunded.
+//! - `fund_challenge` - Challenge funders hold the rest of the reward. Only `DepositFraction` of it
+//!                      is held when a challenge is created, and solutions are refused until all is.
+//! - `add_prize` - Challenge authors can add up to `MaxPrizes` extra prizes, each with its own
+//!                 reward and judge panel, up until submissions open.
+//! - `cancel_challenge` - Challenge authors can withdraw a challenge nobody has submitted to, up until
+//!                        voting starts. Contributors are refunded what they added and the funder the rest.
 //! 
 //! Admin actions:
 //! 
-//! - `register` - Anyone can register their event by depositing a bond. This should contain admin accounts. 
+//! - `register` - Anyone can register their event by depositing a bond. The registering account is the
+//!                event's organizer. Challenges can be posted and edited for `DefaultCreationDuration`
+//!                blocks, then submissions open for `DefaultSubmissionDuration` blocks, followed by
+//!                `DefaultVotingDuration` blocks of voting. At most `MaxActiveHackathons` events can
+//!                be running at once.
 //! - `update_challenge_list` - Admins can approve new challenges. 
-//! - `update_period` - Can update the start and end periods for all event periods (submissions and vote)
+//! - `update_period` - The organizer can update the start and end periods of their event (submissions and vote)
+//!                     until submissions end.
+//! - `close_submissions_early` - The organizer can end submissions at the current block, which opens
+//!                               voting straight away.
+//! - `set_allow_late_edits` - The organizer can let challenge authors keep editing after submissions open.
+//! - `set_allow_ownership_bounties` - The organizer can forbid bounties posted for the event from
+//!                                    offering a percentage of a team's prize, keeping prizes to
+//!                                    registered members. Funds can still be offered.
+//! - `set_hackathon_metadata` - The organizer can attach details such as the timezone and venue (ipfs hash).
+//! - `set_hackathon_visibility` - The organizer can unlist an invite-only event, or list it again.
+//! - `extend_challenge_window` - The organizer can keep accepting challenges for longer, as long as
+//!                               the window still closes before submissions open.
+//! - `update_team_size` - The admin origin can change the largest team an event accepts, up to
+//!                       `MaxMembers`. Teams enter an event with their first submission to it and
+//!                       keep competing at their size if the limit is lowered afterwards.
+//! - `set_event_judges` - The organizer can set the event's judge pool.
+//! - `disqualify_solution` - The organizer can disqualify a solution until its challenge is settled.
+//!                           It can no longer win and its `SubmissionBond` is slashed. Every judge
+//!                           whose vote counted for it gets a strike; at `JudgeStrikeLimit` strikes a
+//!                           judge is barred from panels and their `JudgeDeposit` on it is slashed.
+//! - `auto_assign_judges` - The admin origin can spread the judge pool across every challenge of an
+//!                          event in turn, up to a number of judges per challenge.
+//! - `slash_team_bond` - The admin origin can slash the bond of a team created for abuse.
+//! - `archive_challenge` - The admin origin can replace a settled challenge, its solutions and its
+//!                         prizes with a compact `ChallengeArchive` of its result.
+//! - `acknowledge_contributors` - Members of a challenge's winning team can publish, as an event, the
+//!                                off-chain contributors they share the prize with. No funds move.
 //! 
 //! Team creation actions:
 //!
-//! - `create_team` - A participant can create a team of maximum 5 people.
-//! - `add_member` - Team members can add up to n members, according to the admin rules.
+//! - `create_team` - A participant can create a team of up to `MaxMembers` people, founder included,
+//!                   reserving a `TeamBond`. Everyone but the founder is invited and only joins once
+//!                   they accept. An account can create one team per `TeamCreationCooldown` blocks.
+//! - `add_member` - Team members can invite members up to `MaxMembers`, pending invites included,
+//!                  and up to the team size limit of any event the team still competes in.
+//! - `accept_team_invite` - Invited buidlers join the team's roster. Nobody is listed on a team,
+//!                          or counted against `MaxSolutionsPerAccount`, without accepting.
+//! - `set_team_category` - The team founder can set the category (track) the team competes in.
+//! - `set_team_captain` - The team founder can name a member captain, who manages the team's
+//!                        bounties alongside the founder.
+//! - `set_private_roster` - The team founder can keep the roster out of `team_members` until a
+//!                          hackathon's voting ends.
+//! - `disband_team` - The team founder can disband a team with no open or claimed bounties,
+//!                    getting the `TeamBond` reserved at creation back.
+//! - `claim_founder` - A member can take over as founder once the founder has gone
+//!                     `FounderInactivityTimeout` blocks without managing, submitting for or posting
+//!                     bounties for the team. The claimant reserves the team bond instead.
+//! - `declare_targets` - Team members can declare which challenges the team is aiming for.
+//! - `enter_hackathon` - The team founder can enter an existing team into another hackathon, within
+//!                       its size limit and with nobody from its judge pool, keeping the same roster.
+//! - `submit_solution` - Team members can submit one solution per challenge for their team while
+//!                       submissions are open, optionally keeping the roster anonymous until voting ends.
+//!                       The submitter's `SubmissionBond` is returned once the challenge is settled.
+//! - `draft_solution` - Team members can stage their team's solution while submissions are open.
+//!                      Drafts cannot be voted on and are left out of listings.
+//! - `set_official_solution` - Team members can make their team's draft official while submissions
+//!                             are open.
+//! - `withdraw_solution` - Team members can retract their team's solution until voting starts.
 //!
 //! Bounty protocol (for Team):
 //!
-//! - `post_bounty` - Team members can post a new bounty. Bounty has expiry. Can only be claimed once at time and has a percentage attached.
+//! - `post_bounty` - The team founder or captain can post a new bounty. Bounty has expiry. Can only be claimed once at time and has a percentage attached.
+//!                   A bounty must offer an amount, a percentage or both, and the percentage must be
+//!                   a multiple of `PercentageStep`. Bounties are posted for a hackathon, which
+//!                   must allow ownership bounties for the percentage to be above zero.
 //! - `extend_bounty_expiry` - Extend the expiry block number of the bounty and stay active.
-//! - `approve_bounty` - Close and pay out the specified amount for the completed work.
+//! - `reject_bounty` - Reject a claim. Once a majority of the team rejects it, the claimant is cleared
+//!                     and the expiry is reset.
+//! - `redirect_bounty_to_challenge` - Donate an expired bounty's funds to a challenge's reward.
+//! - `cancel_bounty` - The poster can withdraw an unclaimed bounty, getting its funds back.
+//! - `approve_bounty` - The founder or captain closes and pays out the specified amount for the
+//!                      completed work, unless a member has rejected the claim. Approved bounties can grant at most 100% of
+//!                      the team's prize between them. Whatever milestones remain are paid at once.
+//!                      Teams requiring two approvals pay once both the founder and captain approve.
+//! - `set_bounty_milestones` - The poster can split an unclaimed bounty's amount into milestones.
+//! - `approve_milestone` - The founder or captain pays a claimed bounty's next milestone. The last
+//!                         one approves the bounty.
+//! - `set_required_bounty_approvals` - The team founder can require both the founder and the captain
+//!                                     to approve a bounty or milestone before it is paid.
 //!
 //! Bounty protocol (for Individual buidl):
 //! 
 //! - `claim_bounty` - Individual buidlers who worked on a bounty can claim it with their solution.
+//!                    The posting team's own members cannot claim it, and the solution must pass
+//!                    the runtime's `ProofVerifier`.
+//! - `update_claim_solution` - Claimants can point their claim at a new solution until it is approved.
+//!                             The new solution is verified the same way.
+//! 
+//! Community actions:
+//! 
+//! - `endorse_team` - Anyone can publicly endorse a team. This is a non-binding reputation signal.
+//! - `unendorse_team` - Endorsers can withdraw their endorsement.
+//! 
+//! Buidler profile actions:
+//! 
+//! - `register_buidler` - Anyone can publish a profile pointer, independent of any team.
+//! - `update_buidler_profile` - Buidlers can point their profile somewhere new.
+//! - `deregister_buidler` - Buidlers can remove their profile.
 //! 
 //! Judge actions
 //! 
-//! - `vote` - Judges submit their votes on challenges submission. Once the voting period ends the prizes are
-//! 		   automatically distributed according to the challenge description.
+//! - `accept_judge_role` - Invited judges accept to join a challenge's panel.
+//! - `request_info` - Judges can ask a team for clarification (ipfs hash) on a solution.
+//! - `judges_request_extension` - Judges in an event's pool can back giving the event more
+//!                                blocks. Once a majority of the pool backs the same number, an
+//!                                `ExtensionRequested` event asks the organizer or admin to honor
+//!                                it with `update_period`, which is possible until submissions end.
+//! - `vote` - Judges submit their votes on challenges submission, one per challenge. Judges who
+//!          are members of a submitting team cannot vote on that challenge. Only the first
+//!          `MaxCountedVotes` votes cast, delegated ones included, count towards the tally.
+//! - `delegate_vote` - Once submissions close, a judge who has not voted can have another judge's
+//!                     vote count for them too. Delegations are one hop: delegates vote themselves.
+//! - `revoke_delegation` - Judges can take back a delegated vote until voting ends.
+//! - `finalize_challenge` - Once the voting period ends, anyone can settle a challenge. The solution
+//!                          with the most votes wins and the reward is split between its members,
+//!                          provided at least `JudgeQuorum` of the judges voted for it. What the
+//!                          split leaves over goes where `Rounding` says. Otherwise the reward is
+//!                          refunded to the funder and contributors.
+//! - `vote_prize` - Judges on a prize's panel vote for the solution that should win that prize.
+//! - `finalize_prize` - Once the voting period ends, anyone can settle a prize on its panel's votes,
+//!                      independently of the challenge's main reward and its other prizes.
+//! - `claim_vested` - Winners release what has vested of their share of a challenge's reward or of
+//!                    a prize. Shares stay held and vest linearly over `VestingDuration` blocks
+//!                    from settlement.
+//! - `escalate_dispute` - A team that submitted to a challenge can contest how it is being judged.
+//!                        The challenge and its prizes cannot be settled, nor its solutions
+//!                        disqualified, until root rules on it.
+//! - `resolve_dispute` - Root rules on an escalated dispute, optionally reinstating the escalating
+//!                       team's disqualified solution, which lets the challenge be settled again.
+//!
+//! ### Queries
+//!
+//! - `challenge_with_solutions` - A challenge and its solutions. Teams that submitted anonymously
+//!                                are listed without their team, submitter and members until
+//!                                voting ends.
+//! - `hackathon_schedule` - A hackathon's submission and voting blocks together with the current
+//!                          block and the phase it falls in.
+//! - `hackathons` - A page of public hackathons, in id order. Unlisted ones are skipped.
+//! - `active_hackathons` - Every hackathon whose voting has not ended yet.
+//! - `hackathon` - A hackathon by id, listed or not.
+//! - `hackathon_metadata` - The details (ipfs hash) attached to a hackathon.
+//! - `current_leader` - The solution leading a challenge's tally so far.
+//! - `solution_voters` - The judges whose votes count for a solution.
+//! - `is_finalized` - Whether a challenge has been settled.
+//! - `team_members` - A team's roster as shown for a hackathon: empty while it is private and the
+//!                    hackathon's voting has not ended.
+//! - `can_submit` - Whether an account could submit a team's solution to a challenge right now.
+//! - `challenge_archive` - Who won an archived challenge and what they were paid.
+//! - `bounty_escrow` - The funds escrowed for a claimed bounty, or an open one that has not expired.
+//! - `resolved_shares` - Who owns what percentage of a team's prize: approved bounty claimants
+//!                       their percentages, and the founder the rest.
+//! - `total_rewards` - The prize money still at stake in a hackathon.
+//! - `preview_distribution` - Who would be paid what if a challenge were settled on the votes so far.
+//! - `leaderboard` - The teams that won prizes in a hackathon, ranked by the rewards paid to them.
+//! - `hackathon_judges` - Everyone judging in a hackathon: its judge pool and every challenge and
+//!                        prize panel, deduplicated.
+//!
+//! ### Runtime API
+//!
+//! `BuidlApi` in `runtime-api/` exposes `challenge_with_solutions` and `list_challenges` so a
+//! challenge board can be rendered in one call. `rpc/` serves them as `buidl_challengeWithSolutions`
+//! and `buidl_listChallenges`.
 
 
 // Notes:
@@ -86,27 +246,47 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+pub mod weights;
+pub use weights::WeightInfo;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use core::default;
 
 use frame_support::{pallet_prelude::*};
 	use frame_system::pallet_prelude::*;
+	use crate::WeightInfo;
 	use frame_support::{
-		traits::{Currency, LockIdentifier, LockableCurrency, WithdrawReasons},
+		sp_runtime::{
+			traits::{Saturating, Zero},
+			Permill,
+		},
+		traits::{
+			fungible::{InspectHold, MutateHold},
+			BalanceStatus, Currency, ReservableCurrency,
+		},
 	};
 	use sp_core::H256;
+	use sp_std::{collections::btree_map::BTreeMap, prelude::*};
 
-	const DEPOSIT_FOR_CHALLENGE: LockIdentifier = *b" deposit";
+	/// Reputation earned by each member of a challenge's winning solution.
+	pub const WIN_REPUTATION: u32 = 10;
+	/// Reputation earned by the claimant of an approved bounty.
+	pub const BOUNTY_REPUTATION: u32 = 1;
 
 	// Handler for balances
-	type BalanceOf<T> =
+	pub type BalanceOf<T> =
 		<<T as Config>::Deposit as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
 	// Challenge struct
 	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 	#[scale_info(skip_type_params(T))]
 	pub struct Challenge<T: Config> {
+		/// Account that posted the challenge
+		pub author: T::AccountId,
+		/// Account whose hold backs the reward: the author, or the treasury for
+		/// treasury-funded challenges
+		pub funder: T::AccountId,
 		/// Description (ipfs hash)
 		pub description: H256,
 		/// Reward
@@ -115,6 +295,58 @@ use frame_support::{pallet_prelude::*};
 		pub judges: Option<BoundedVec<T::AccountId, T::MaxMembers>>,
 		/// Number of times a challenge has had a solution submitted to it
 		pub submissions: u32,
+		/// Number of times the challenge author has edited the challenge
+		pub edits: u32,
+		/// What teams are expected to submit (ipfs hash of the format docs)
+		pub submission_format: Option<H256>,
+		/// Extra standing given to solutions submitted early
+		pub early_bonus: Option<EarlyBonus<T::BlockNumber>>,
+		/// The team category (track) solutions must come from, if restricted
+		pub required_category: Option<u16>,
+		/// Part of the reward the funder has yet to hold. Solutions are refused until it is zero
+		pub outstanding: BalanceOf<T>,
+		/// Share of the votes cast the winning solution needs, on top of the judge quorum
+		pub required_agreement: Permill,
+	}
+
+	/// A prize within a challenge with its own reward and judge panel, decided separately from
+	/// the challenge's main reward.
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct Prize<T: Config> {
+		/// Name (ipfs hash)
+		pub name: H256,
+		/// Account whose hold backs the prize's reward
+		pub funder: T::AccountId,
+		/// Reward
+		pub reward: BalanceOf<T>,
+		/// Judges deciding this prize, and only this prize
+		pub judges: BoundedVec<T::AccountId, T::MaxMembers>,
+		/// Whether the prize has been settled, with a winner or with its reward released
+		pub settled: bool,
+		/// The solution that won the prize, once settled
+		pub winner: Option<u16>,
+	}
+
+	/// What is kept of a settled challenge once it is archived.
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct ChallengeArchive<T: Config> {
+		/// The team whose solution won, if any
+		pub winner: Option<u32>,
+		/// What the winning team was paid in total, or the reward refunded if nobody won
+		pub reward: BalanceOf<T>,
+		/// The hackathon the challenge belonged to
+		pub hackathon_id: u32,
+	}
+
+	/// Votes added to the tally of every solution submitted before `cutoff`, to reward early work.
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct EarlyBonus<BlockNumber> {
+		/// Solutions submitted before this block receive the bonus
+		pub cutoff: BlockNumber,
+		/// Votes added to such solutions when ranking them
+		pub votes: u32,
 	}
 	
 	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
@@ -122,8 +354,226 @@ use frame_support::{pallet_prelude::*};
 	pub struct SubmittedSolution<T: Config> {
 		/// pointer to solution
 		pub solution: H256,
-		/// participants
+		/// the team that submitted the solution
+		pub team_id: u32,
+		/// the team member who submitted the solution
+		pub submitter: T::AccountId,
+		/// participants, copied from the team roster at submission time
 		pub members: BoundedVec<T::AccountId, T::MaxMembers>,
+		/// whether `members` is left out of listings until voting ends
+		pub anonymous: bool,
+		/// block the solution was submitted at
+		pub submitted_at: T::BlockNumber,
+		/// whether the team is still staging the solution, out of votes and listings
+		pub draft: bool,
+		/// the amount reserved from the submitter until the challenge is settled
+		pub bond: BalanceOf<T>,
+		/// whether the organizer has disqualified the solution, which can then no longer win
+		pub disqualified: bool,
+	}
+
+	/// A submitted solution as listed by `challenge_with_solutions`. Who is behind an anonymous
+	/// solution, its team, submitter and members, is `None` until voting ends.
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo)]
+	#[scale_info(skip_type_params(T))]
+	pub struct ListedSolution<T: Config> {
+		/// pointer to solution
+		pub solution: H256,
+		/// the team that submitted the solution
+		pub team_id: Option<u32>,
+		/// the team member who submitted the solution
+		pub submitter: Option<T::AccountId>,
+		/// participants, copied from the team roster at submission time
+		pub members: Option<BoundedVec<T::AccountId, T::MaxMembers>>,
+		/// whether the solution was submitted anonymously
+		pub anonymous: bool,
+		/// block the solution was submitted at
+		pub submitted_at: T::BlockNumber,
+	}
+
+	impl<T: Config> From<SubmittedSolution<T>> for ListedSolution<T> {
+		fn from(solution: SubmittedSolution<T>) -> Self {
+			ListedSolution {
+				solution: solution.solution,
+				team_id: Some(solution.team_id),
+				submitter: Some(solution.submitter),
+				members: Some(solution.members),
+				anonymous: solution.anonymous,
+				submitted_at: solution.submitted_at,
+			}
+		}
+	}
+
+	/// Coarse grouping of challenges by the size of their reward.
+	#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum RewardTier {
+		/// Reward below the medium threshold
+		Small,
+		/// Reward at or above the medium threshold but below the large one
+		Medium,
+		/// Reward at or above the large threshold
+		Large,
+	}
+
+	/// Who gets what is left when a reward does not divide evenly between the winning team.
+	#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum RoundingPolicy {
+		/// The winning team's founder, or its first member if the founder is not among the winners
+		RemainderToFounder,
+		/// The winning team's first member
+		RemainderToFirst,
+		/// Nobody, the remainder is burned from the funder
+		BurnRemainder,
+	}
+
+	/// Checks the proof a claimant attaches to a bounty claim. `()` accepts every proof.
+	pub trait ProofVerifier<AccountId> {
+		/// Whether `solution` proves `claimant` completed the bounty.
+		fn verify(solution: &H256, bounty_id: u32, claimant: &AccountId) -> bool;
+	}
+
+	impl<AccountId> ProofVerifier<AccountId> for () {
+		fn verify(_solution: &H256, _bounty_id: u32, _claimant: &AccountId) -> bool {
+			true
+		}
+	}
+
+	/// A registered event. Challenges, and the solutions and votes on them, belong to exactly one.
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct Hackathon<T: Config> {
+		/// Account that registered the event and holds its bond
+		pub organizer: T::AccountId,
+		/// Name (ipfs hash)
+		pub name: H256,
+		/// The submission and voting windows, defaulted at registration
+		pub periods: Option<EventPeriods<T::BlockNumber>>,
+		/// Whether challenge authors may keep editing once submissions open
+		pub allow_late_edits: bool,
+		/// Human details such as the timezone and venue (ipfs hash), once the organizer adds them
+		pub metadata: Option<H256>,
+		/// Whether the event appears in `hackathons` listings. Unlisted ones are only found by id
+		pub public: bool,
+		/// Block from which new challenges are refused, closing the creation window
+		pub challenge_deadline: T::BlockNumber,
+		/// Largest roster a team may enter the event with, at most `MaxMembers`
+		pub max_team_size: u32,
+		/// Whether bounties posted for the event may offer a percentage of a team's prize
+		pub allow_ownership_bounties: bool,
+	}
+
+	/// Block numbers bounding the submission and voting phases. Each window starts at its
+	/// `_start` block and closes at its `_end` block.
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct EventPeriods<BlockNumber> {
+		/// First block at which solutions are accepted
+		pub submission_start: BlockNumber,
+		/// Block at which submissions close
+		pub submission_end: BlockNumber,
+		/// First block at which judges can vote
+		pub vote_start: BlockNumber,
+		/// Block at which voting closes
+		pub vote_end: BlockNumber,
+	}
+
+	/// Where a hackathon is in its timeline.
+	#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum Phase {
+		/// Submissions have not opened yet
+		Upcoming,
+		/// Solutions are being accepted
+		Submission,
+		/// Submissions have closed and voting has not opened yet
+		Review,
+		/// Judges are voting
+		Voting,
+		/// Voting has closed
+		Ended,
+	}
+
+	/// A hackathon's timeline as of `now`, for UIs that want it in a single read.
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct Schedule<BlockNumber> {
+		/// First block at which solutions are accepted
+		pub submission_start: BlockNumber,
+		/// Block at which submissions close
+		pub submission_end: BlockNumber,
+		/// Block at which voting closes
+		pub vote_end: BlockNumber,
+		/// Block the schedule was read at
+		pub now: BlockNumber,
+		/// Phase the hackathon is in at `now`
+		pub phase: Phase,
+	}
+
+	/// Why a challenge could not be finalized.
+	#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum FinalizationFailure {
+		/// The leading solution has fewer votes than the quorum requires
+		QuorumNotMet { votes: u32, required: u32 },
+		/// No judge voted on the challenge
+		NoVotes,
+		/// The leading solution has a smaller share of the votes cast than the challenge requires
+		AgreementNotMet { votes: u32, required: u32 },
+	}
+
+	/// A winner's share of a reward, held on them and released linearly over `duration` blocks
+	/// from `start`.
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct VestingSchedule<Balance, BlockNumber> {
+		/// The share paid to the winner
+		pub total: Balance,
+		/// How much of it has been released so far
+		pub released: Balance,
+		/// Block the reward was settled at
+		pub start: BlockNumber,
+		/// Blocks it takes for the whole share to vest
+		pub duration: BlockNumber,
+	}
+
+	/// Lifecycle of a bounty.
+	#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum BountyStatus {
+		/// Posted and waiting for a claimant
+		Open,
+		/// Claimed with a solution that the team has yet to approve
+		Claimed,
+		/// Approved by the team and paid out
+		Approved,
+	}
+
+	/// Work a team offers to other buidlers, paid in funds and/or a share of the team's prize.
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct Bounty<T: Config> {
+		/// Team member that posted the bounty and funds its amount
+		pub poster: T::AccountId,
+		/// Team offering the bounty
+		pub team_id: u32,
+		/// Hackathon the bounty is posted for, whose rules its percentage must follow
+		pub hackathon_id: u32,
+		/// Description (ipfs hash)
+		pub description: H256,
+		/// Funds paid to the claimant on approval, less any milestones already paid
+		pub amount: BalanceOf<T>,
+		/// Block after which the bounty can no longer be claimed
+		pub expiry: T::BlockNumber,
+		/// Blocks between posting and the original expiry, used when a rejection resets the expiry
+		pub duration: T::BlockNumber,
+		/// Percentage of the team's prize offered to the claimant
+		pub percentage: u8,
+		/// Buidler that claimed the bounty
+		pub claimant: Option<T::AccountId>,
+		/// Claimant's solution (ipfs hash)
+		pub solution: Option<H256>,
+		/// Where the bounty is in its lifecycle
+		pub status: BountyStatus,
+		/// Whether a team member has rejected the current claim
+		pub pending_reject: bool,
+		/// Deliverables (ipfs hash) the amount is paid out for one at a time, if any
+		pub milestones: BoundedVec<(H256, BalanceOf<T>), T::MaxMilestones>,
+		/// How many milestones have been paid, which is the index of the next one
+		pub milestones_paid: u32,
 	}
 
 	/// Struct for holding team information
@@ -132,15 +582,31 @@ use frame_support::{pallet_prelude::*};
 	pub struct Team<T: Config> {
 		/// The founding member of this team.
 		/// Note: this could be the prime member from membership pallet.
-		team_founder: T::AccountId,
+		pub team_founder: T::AccountId,
 		/// The team ID.
-		team_id: u32,
+		pub team_id: u32,
 		/// The members of this team.
-		members: BoundedVec<T::AccountId, T::MaxMembers>,
+		pub members: BoundedVec<T::AccountId, T::MaxMembers>,
+		/// The category (track) the team competes in, if any.
+		pub category: Option<u16>,
+		/// The amount reserved from the founder until the team is disbanded.
+		pub bond: BalanceOf<T>,
+		/// The member who manages the team's bounties alongside the founder, if any.
+		pub captain: Option<T::AccountId>,
+		/// Whether `team_members` hides the roster until a hackathon's voting has ended.
+		pub private_roster: bool,
+		/// How many of the founder and captain must approve a bounty payout before it is made.
+		pub required_bounty_approvals: u32,
 	}
 
+	/// The in-code storage version. Bumped to 1 when challenge rewards moved to `fungible` holds.
+	/// `pallet_balances` implements holds on top of reserves, so rewards reserved before the bump
+	/// need no migration.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	/// Configure the pallet by specifying the parameters and types on which it depends.
@@ -148,149 +614,3816 @@ use frame_support::{pallet_prelude::*};
 	pub trait Config: frame_system::Config {
 		/// Because this pallet emits events, it depends on the runtime's definition of an event.
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
-		/// The abstraction over currency and balances for this pallet.
-		type Deposit: LockableCurrency<Self::AccountId, Moment = Self::BlockNumber>;
+		/// Origin allowed to perform event admin actions on any hackathon, alongside its organizer.
+		type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// The abstraction over currency and balances for this pallet. Hackathon bonds and bounty
+		/// funds are reserved through it.
+		type Deposit: ReservableCurrency<Self::AccountId>;
+		/// Holds challenge rewards, so each challenge holds its own amount on top of any others.
+		/// Must share its balances with `Deposit`, as `pallet_balances` does, since redirected
+		/// bounty funds move from a `Deposit` reserve onto a reward hold.
+		type RewardCurrency: MutateHold<Self::AccountId, Balance = BalanceOf<Self>>;
+		/// The account that funds challenges posted through `create_treasury_challenge`.
+		#[pallet::constant]
+		type TreasuryAccount: Get<Self::AccountId>;
+		/// The amount reserved from an organizer for as long as their hackathon is registered.
+		#[pallet::constant]
+		type HackathonBond: Get<BalanceOf<Self>>;
+		/// The amount reserved from a founder for as long as their team exists.
+		#[pallet::constant]
+		type TeamBond: Get<BalanceOf<Self>>;
+		/// How many blocks an account must wait after creating a team before creating another.
+		#[pallet::constant]
+		type TeamCreationCooldown: Get<Self::BlockNumber>;
+		/// How many blocks a team's founder can go without acting for the team before a member
+		/// can claim the founder role.
+		#[pallet::constant]
+		type FounderInactivityTimeout: Get<Self::BlockNumber>;
+		/// The amount reserved from a submitter until the challenge is settled. It is returned
+		/// unless the solution was disqualified, in which case it is slashed.
+		#[pallet::constant]
+		type SubmissionBond: Get<BalanceOf<Self>>;
+		/// The amount reserved from a judge on each challenge they first vote or delegate on,
+		/// until it is settled. It is slashed if the vote costs them their last strike.
+		#[pallet::constant]
+		type JudgeDeposit: Get<BalanceOf<Self>>;
+		/// How many votes for solutions that are later disqualified bar a judge from every panel.
+		/// Zero never bars anyone.
+		#[pallet::constant]
+		type JudgeStrikeLimit: Get<u32>;
+		/// The most blocks a hackathon may span, from the start of submissions to the end of voting.
+		#[pallet::constant]
+		type MaxHackathonDuration: Get<Self::BlockNumber>;
+		/// How many blocks a newly registered hackathon leaves for posting and editing challenges
+		/// before its default submission period opens.
+		#[pallet::constant]
+		type DefaultCreationDuration: Get<Self::BlockNumber>;
+		/// How many blocks a newly registered hackathon accepts submissions for, starting once its
+		/// creation window closes, until its organizer calls `update_period`.
+		#[pallet::constant]
+		type DefaultSubmissionDuration: Get<Self::BlockNumber>;
+		/// How many blocks a newly registered hackathon's voting lasts, starting as its default
+		/// submission period ends.
+		#[pallet::constant]
+		type DefaultVotingDuration: Get<Self::BlockNumber>;
+		/// The maximum amount of hackathons whose submission, or voting, period can end at the
+		/// same block.
+		#[pallet::constant]
+		type MaxHackathonsPerBlock: Get<u32>;
+		/// The maximum amount of hackathons registered and not yet past their voting period.
+		#[pallet::constant]
+		type MaxActiveHackathons: Get<u32>;
+		/// The share of a challenge's judges that must back the winning solution.
+		#[pallet::constant]
+		type JudgeQuorum: Get<Permill>;
+		/// The share of a challenge's reward held when it is created. The rest must be held with
+		/// `fund_challenge` before the challenge accepts solutions.
+		#[pallet::constant]
+		type DepositFraction: Get<Permill>;
+		/// Who gets what is left when a reward does not divide evenly between its winners.
+		#[pallet::constant]
+		type Rounding: Get<RoundingPolicy>;
+		/// How many blocks a winner's share of a challenge reward or prize takes to vest once it
+		/// is settled. It stays held on the winner until released with `claim_vested`. Zero pays
+		/// shares out at once.
+		#[pallet::constant]
+		type VestingDuration: Get<Self::BlockNumber>;
+		/// The number of votes on a challenge counted towards its tally, in the order they were
+		/// cast. Later votes are ignored. At least `MaxMembers` counts every vote.
+		#[pallet::constant]
+		type MaxCountedVotes: Get<u32>;
+		/// Checks the solutions bounty claims are made and updated with. `()` accepts them all.
+		type ProofVerifier: ProofVerifier<Self::AccountId>;
 		/// The maximum amount of people in a team.
 		#[pallet::constant]
 		type MaxMembers: Get<u32>;
 		/// The maximum amount of solutions a challenge can accept.
 		#[pallet::constant]
 		type MaxSolutions: Get<u32>;
+		/// The maximum amount of times an author can edit a challenge.
+		#[pallet::constant]
+		type MaxEdits: Get<u32>;
+		/// Bounty percentages must be a multiple of this. Zero accepts any percentage.
+		#[pallet::constant]
+		type PercentageStep: Get<u8>;
+		/// The `(medium, large)` reward thresholds used to place challenges in a `RewardTier`.
+		#[pallet::constant]
+		type RewardTierThresholds: Get<(BalanceOf<Self>, BalanceOf<Self>)>;
+		/// The maximum amount of solutions a single account can be listed on within one hackathon.
+		#[pallet::constant]
+		type MaxSolutionsPerAccount: Get<u32>;
+		/// The maximum amount of challenges a team can declare it is targeting.
+		#[pallet::constant]
+		type MaxTargets: Get<u32>;
+		/// The maximum amount of distinct accounts that can redirect bounties into one challenge.
+		#[pallet::constant]
+		type MaxContributors: Get<u32>;
+		/// The maximum amount of judges in a hackathon's pool for automatic assignment.
+		#[pallet::constant]
+		type MaxEventJudges: Get<u32>;
+		/// The maximum amount of separately judged prizes a challenge can hold.
+		#[pallet::constant]
+		type MaxPrizes: Get<u32>;
+		/// The maximum amount of milestones a bounty's amount can be split into.
+		#[pallet::constant]
+		type MaxMilestones: Get<u32>;
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
 	}
 
-	/// The next `ChallengeId` to assign.
+	/// The next `HackathonId` to assign.
+	#[pallet::storage]
+	pub type NextHackathonId<T> = StorageValue<_, u32, ValueQuery>;
+
+	/// HackathonId -> Hackathon
+	#[pallet::storage]
+	pub type Hackathons<T: Config> = StorageMap<_, Twox64Concat, u32, Hackathon<T>, OptionQuery>;
+
+	/// The hackathons registered whose voting period has not ended, in registration order
+	#[pallet::storage]
+	pub type ActiveHackathons<T: Config> =
+		StorageValue<_, BoundedVec<u32, T::MaxActiveHackathons>, ValueQuery>;
+
+	/// BlockNumber -> the hackathons whose submission period ends at that block
+	#[pallet::storage]
+	pub type SubmissionEnds<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::BlockNumber,
+		BoundedVec<u32, T::MaxHackathonsPerBlock>,
+		ValueQuery,
+	>;
+
+	/// BlockNumber -> the hackathons whose voting period ends at that block
+	#[pallet::storage]
+	pub type VoteEnds<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::BlockNumber,
+		BoundedVec<u32, T::MaxHackathonsPerBlock>,
+		ValueQuery,
+	>;
+
+	/// The next `ChallengeId` to assign. Ids are unique across hackathons, so storage keyed by
+	/// challenge alone never mixes events.
 	#[pallet::storage]
+	#[pallet::getter(fn next_challenge_id)]
 	pub type NextChallengeId<T> = StorageValue<_, u16, ValueQuery>;
 
-	/// ChallengeId -> Challenge
+	/// (HackathonId, ChallengeId) -> Challenge
 	#[pallet::storage]
 	pub type Challenges<T: Config> =
-		StorageMap<_, Twox64Concat, u16, Challenge<T>, OptionQuery>;
+		StorageDoubleMap<_, Twox64Concat, u32, Twox64Concat, u16, Challenge<T>, OptionQuery>;
+
+	/// (ChallengeId, SolutionId) -> SubmittedSolution
+	#[pallet::storage]
+	pub type ChallengeSolutions<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, u16, Twox64Concat, u16, SubmittedSolution<T>, OptionQuery>;
+
+	/// ChallengeId -> the next `SolutionId` to assign within that challenge.
+	#[pallet::storage]
+	pub type NextSolutionId<T> = StorageMap<_, Twox64Concat, u16, u16, ValueQuery>;
+
+	/// (ChallengeId, judge) -> the SolutionId the judge voted for
+	#[pallet::storage]
+	pub type Votes<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, u16, Blake2_128Concat, T::AccountId, u16, OptionQuery>;
+
+	/// (ChallengeId, judge) -> the judge whose vote also counts for them
+	#[pallet::storage]
+	pub type VoteDelegations<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, u16, Blake2_128Concat, T::AccountId, T::AccountId, OptionQuery>;
+
+	/// ChallengeId -> judges in the order they first voted or delegated their vote, until settled
+	#[pallet::storage]
+	pub type VoteOrder<T: Config> =
+		StorageMap<_, Twox64Concat, u16, BoundedVec<T::AccountId, T::MaxMembers>, ValueQuery>;
+
+	/// (ChallengeId, judge) -> the deposit reserved from the judge until the challenge is settled
+	#[pallet::storage]
+	pub type JudgeDeposits<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		u16,
+		Blake2_128Concat,
+		T::AccountId,
+		BalanceOf<T>,
+		OptionQuery,
+	>;
+
+	/// AccountId -> how many of the judge's votes backed solutions later disqualified
+	#[pallet::storage]
+	#[pallet::getter(fn judge_strikes)]
+	pub type JudgeStrikes<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+	/// AccountId -> () for every judge barred from panels for reaching `JudgeStrikeLimit`
+	#[pallet::storage]
+	pub type BannedJudges<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+
+	/// (ChallengeId, AccountId) -> () for every judge invite awaiting acceptance
+	#[pallet::storage]
+	pub type JudgeInvites<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, u16, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+
+	/// (HackathonId, AccountId) -> number of solutions submitted to the hackathon listing the
+	/// account as a member
+	#[pallet::storage]
+	pub type Coauthorships<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, u32, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+	/// (ChallengeId, contributor) -> amount the contributor has added to the challenge reward
+	#[pallet::storage]
+	pub type ChallengeContributions<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, u16, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
+	/// (AccountId, (ChallengeId, PrizeId)) -> the winner's share still vesting, a `None` prize
+	/// being the challenge's main reward
+	#[pallet::storage]
+	pub type VestingSchedules<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Twox64Concat,
+		(u16, Option<u16>),
+		VestingSchedule<BalanceOf<T>, T::BlockNumber>,
+		OptionQuery,
+	>;
+
+	/// ChallengeId -> the SolutionId that won the challenge
+	#[pallet::storage]
+	pub type ChallengeWinners<T> = StorageMap<_, Twox64Concat, u16, u16, OptionQuery>;
+
+	/// ChallengeId -> why the challenge was settled without a winner and its reward refunded
+	#[pallet::storage]
+	pub type UnawardedChallenges<T> =
+		StorageMap<_, Twox64Concat, u16, FinalizationFailure, OptionQuery>;
+
+	/// ChallengeId -> the result of a settled challenge whose details have been removed
+	#[pallet::storage]
+	#[pallet::getter(fn challenge_archive)]
+	pub type ChallengeArchives<T: Config> =
+		StorageMap<_, Twox64Concat, u16, ChallengeArchive<T>, OptionQuery>;
+
+	/// ChallengeId -> the team that escalated a dispute over the challenge and its solution
+	#[pallet::storage]
+	pub type EscalatedDisputes<T> = StorageMap<_, Twox64Concat, u16, (u32, u16), OptionQuery>;
+
+	/// (ChallengeId, PrizeId) -> a separately judged prize of the challenge
+	#[pallet::storage]
+	pub type ChallengePrizes<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, u16, Twox64Concat, u16, Prize<T>, OptionQuery>;
+
+	/// ((ChallengeId, PrizeId), judge) -> SolutionId the judge voted for to win the prize
+	#[pallet::storage]
+	pub type PrizeVotes<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, (u16, u16), Blake2_128Concat, T::AccountId, u16, OptionQuery>;
+
+	/// (ChallengeId, SolutionId) -> the judges whose votes counted for the solution, delegators
+	/// included, as recorded when the challenge was settled
+	#[pallet::storage]
+	pub type SettledVoters<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		u16,
+		Twox64Concat,
+		u16,
+		BoundedVec<T::AccountId, T::MaxMembers>,
+		ValueQuery,
+	>;
+
+	/// The next `TeamId` to assign.
+	#[pallet::storage]
+	#[pallet::getter(fn next_team_id)]
+	pub type NextTeamId<T> = StorageValue<_, u32, ValueQuery>;
+
+	/// TeamId -> Team
+	#[pallet::storage]
+	pub type Teams<T: Config> = StorageMap<_, Twox64Concat, u32, Team<T>, OptionQuery>;
+
+	/// (TeamId, HackathonId) -> () for every hackathon a team has entered by submitting to it
+	#[pallet::storage]
+	pub type TeamHackathons<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, u32, Twox64Concat, u32, (), OptionQuery>;
+
+	/// (TeamId, AccountId) -> () for every team invite awaiting acceptance
+	#[pallet::storage]
+	pub type TeamInvites<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, u32, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+
+	/// HackathonId -> judges the admin origin can spread across the event's challenges
+	#[pallet::storage]
+	pub type EventJudges<T: Config> =
+		StorageMap<_, Twox64Concat, u32, BoundedVec<T::AccountId, T::MaxEventJudges>, ValueQuery>;
+
+	/// (HackathonId, AccountId) -> the extension, in blocks, a judge in the event's pool backs
+	#[pallet::storage]
+	pub type ExtensionSupport<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		u32,
+		Blake2_128Concat,
+		T::AccountId,
+		T::BlockNumber,
+		OptionQuery,
+	>;
+
+	/// TeamId -> percentage of the team's prize already granted through approved bounties
+	#[pallet::storage]
+	pub type GrantedOwnership<T> = StorageMap<_, Twox64Concat, u32, u8, ValueQuery>;
+
+	/// AccountId -> block the account last created a team at
+	#[pallet::storage]
+	pub type LastTeamCreation<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, T::BlockNumber, OptionQuery>;
+
+	/// TeamId -> block the team's founder last acted for the team at
+	#[pallet::storage]
+	pub type FounderActivity<T: Config> = StorageMap<_, Twox64Concat, u32, T::BlockNumber, ValueQuery>;
+
+	/// TeamId -> number of the team's bounties that are still open or claimed
+	#[pallet::storage]
+	pub type PendingBounties<T> = StorageMap<_, Twox64Concat, u32, u32, ValueQuery>;
+
+	/// TeamId -> ChallengeIds the team intends to submit to
+	#[pallet::storage]
+	pub type TeamTargets<T: Config> =
+		StorageMap<_, Twox64Concat, u32, BoundedVec<u16, T::MaxTargets>, ValueQuery>;
+
+	/// (TeamId, AccountId) -> () for every account endorsing the team
+	#[pallet::storage]
+	pub type TeamEndorsements<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, u32, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+
+	/// TeamId -> number of accounts endorsing the team
+	#[pallet::storage]
+	#[pallet::getter(fn endorsement_count)]
+	pub type TeamEndorsementCount<T> = StorageMap<_, Twox64Concat, u32, u32, ValueQuery>;
+
+	/// The next `BountyId` to assign.
+	#[pallet::storage]
+	pub type NextBountyId<T> = StorageValue<_, u32, ValueQuery>;
+
+	/// BountyId -> Bounty
+	#[pallet::storage]
+	pub type Bounties<T: Config> = StorageMap<_, Twox64Concat, u32, Bounty<T>, OptionQuery>;
+
+	/// (BountyId, team member) -> () for every member rejecting the current claim
+	#[pallet::storage]
+	pub type BountyRejections<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, u32, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+
+	/// (BountyId, bounty manager) -> the payout they approved: the rest of the bounty (`None`),
+	/// or the milestone with this index
+	#[pallet::storage]
+	pub type BountyApprovals<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, u32, Blake2_128Concat, T::AccountId, Option<u32>, OptionQuery>;
+
+	/// AccountId -> reputation score earned from challenge wins and approved bounties
+	#[pallet::storage]
+	#[pallet::getter(fn reputation)]
+	pub type Reputation<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
 
-	/// List of ChallegeIds that are ready to be voted on
+	/// AccountId -> public buidler profile (ipfs hash)
 	#[pallet::storage]
-	pub type ChallengeSolutions<T> = StorageMap<_, Twox64Concat, u16, SubmittedSolution<T>, OptionQuery>;
+	#[pallet::getter(fn buidler_profile)]
+	pub type Buidlers<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, H256, OptionQuery>;
 
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
-		/// A challenge has been created with [id, creator]
-		ChallengeCreated {id: u16, creator: T::AccountId },
-		/// Solution has been submitted for a certain challenge [challengeId, sender]
-		SolutionSubmitted {id: u16, member: T::AccountId },
+		/// A hackathon has been registered [hackathon_id, organizer]
+		HackathonRegistered { hackathon_id: u32, organizer: T::AccountId },
+		/// A challenge has been created with [hackathon_id, id, creator, reward, judges_supplied]
+		ChallengeCreated {
+			hackathon_id: u32,
+			id: u16,
+			creator: T::AccountId,
+			reward: BalanceOf<T>,
+			judges_supplied: bool,
+		},
+		/// A challenge's author has changed its description or format hint [hackathon_id, id, editor]
+		ChallengeEdited { hackathon_id: u32, id: u16, editor: T::AccountId },
+		/// A challenge's early submission bonus has been set or cleared [hackathon_id, id, early_bonus]
+		EarlyBonusSet { hackathon_id: u32, id: u16, early_bonus: Option<EarlyBonus<T::BlockNumber>> },
+		/// A challenge has been withdrawn and its reward returned [hackathon_id, challenge_id]
+		ChallengeCancelled { hackathon_id: u32, challenge_id: u16 },
+		/// Solution has been submitted for a certain challenge, naming the sender unless it was
+		/// submitted anonymously [challengeId, sender]
+		SolutionSubmitted {id: u16, member: Option<T::AccountId> },
+		/// A team has retracted its solution, naming the member unless it was submitted
+		/// anonymously [challenge_id, solution_id, member]
+		SolutionWithdrawn { challenge_id: u16, solution_id: u16, member: Option<T::AccountId> },
+		/// Judges have been invited to a challenge [challenge_id, judges]
+		JudgesInvited { challenge_id: u16, judges: Vec<T::AccountId> },
+		/// An invited judge has joined a challenge's panel [challenge_id, judge]
+		JudgeAccepted { challenge_id: u16, judge: T::AccountId },
+		/// A judge has handed their vote to another judge [challenge_id, judge, delegate]
+		VoteDelegated { challenge_id: u16, judge: T::AccountId, delegate: T::AccountId },
+		/// A judge has taken back their vote [challenge_id, judge]
+		DelegationRevoked { challenge_id: u16, judge: T::AccountId },
+		/// A hackathon's submission and voting windows have been set [hackathon_id, periods]
+		PeriodsUpdated { hackathon_id: u32, periods: EventPeriods<T::BlockNumber> },
+		/// A hackathon's submission period has ended [hackathon_id]
+		SubmissionPhaseEnded { hackathon_id: u32 },
+		/// A hackathon's voting period has ended [hackathon_id]
+		VotingPhaseEnded { hackathon_id: u32 },
+		/// A judge has voted for a solution [challenge_id, judge, solution_id]
+		VoteCast { challenge_id: u16, judge: T::AccountId, solution_id: u16 },
+		/// A challenge has been decided and its reward paid out [challenge_id, winning_solution, winners]
+		ChallengeFinalized {
+			challenge_id: u16,
+			winning_solution: u16,
+			winners: BoundedVec<T::AccountId, T::MaxMembers>,
+		},
+		/// A challenge was settled without a winner and its reward refunded to its funder and
+		/// contributors [challenge_id, reason]
+		ChallengeFinalizationFailed { challenge_id: u16, reason: FinalizationFailure },
+		/// A team has been created [team_id, founder]
+		TeamCreated { team_id: u32, founder: T::AccountId },
+		/// A member has joined a team [team_id, member]
+		MemberAdded { team_id: u32, member: T::AccountId },
+		/// A team has declared the challenges it is targeting [team_id, challenge_ids]
+		TargetsDeclared { team_id: u32, challenge_ids: BoundedVec<u16, T::MaxTargets> },
+		/// A team member has posted a bounty [bounty_id, team_id, poster]
+		BountyPosted { bounty_id: u32, team_id: u32, poster: T::AccountId },
+		/// A buidler has claimed a bounty with their solution [bounty_id, claimant, solution]
+		BountyClaimed { bounty_id: u32, claimant: T::AccountId, solution: H256 },
+		/// A team has approved a claimed bounty and paid the claimant [bounty_id, claimant, amount]
+		BountyApproved { bounty_id: u32, claimant: T::AccountId, amount: BalanceOf<T> },
+		/// A team member has pushed back a bounty's expiry [bounty_id, new_expiry]
+		BountyExtended { bounty_id: u32, new_expiry: T::BlockNumber },
+		/// A team member has rejected a bounty's claim [bounty_id, member]
+		BountyRejected { bounty_id: u32, member: T::AccountId },
+		/// A majority of the team rejected the claim, so the bounty is open again [bounty_id, expiry]
+		BountyReset { bounty_id: u32, expiry: T::BlockNumber },
+		/// An expired bounty's funds have been added to a challenge reward [bounty_id, challenge_id, amount]
+		BountyRedirected { bounty_id: u32, challenge_id: u16, amount: BalanceOf<T> },
+		/// An account has endorsed a team [team_id, who]
+		TeamEndorsed { team_id: u32, who: T::AccountId },
+		/// An account has withdrawn its endorsement of a team [team_id, who]
+		TeamUnendorsed { team_id: u32, who: T::AccountId },
+		/// A buidler has registered a public profile [who, profile]
+		BuidlerRegistered { who: T::AccountId, profile: H256 },
+		/// A buidler has pointed their profile somewhere new [who, profile]
+		BuidlerProfileUpdated { who: T::AccountId, profile: H256 },
+		/// A buidler has removed their profile [who]
+		BuidlerDeregistered { who: T::AccountId },
+		/// A judge wants clarification on a solution [challenge_id, solution_index, judge, note]
+		InfoRequested { challenge_id: u16, solution_index: u16, judge: T::AccountId, note: H256 },
+		/// A bounty's poster has withdrawn it and its funds are free again [bounty_id, poster, amount]
+		BountyCancelled { bounty_id: u32, poster: T::AccountId, amount: BalanceOf<T> },
+		/// A hackathon's organizer has allowed or forbidden late challenge edits [hackathon_id, allowed]
+		LateEditsSet { hackathon_id: u32, allowed: bool },
+		/// A claimant has pointed their claim at a new solution [bounty_id, claimant, solution]
+		ClaimSolutionUpdated { bounty_id: u32, claimant: T::AccountId, solution: H256 },
+		/// A hackathon's details have been pointed at new metadata [hackathon_id, cid]
+		HackathonMetadataSet { hackathon_id: u32, cid: H256 },
+		/// A hackathon has been listed or unlisted [hackathon_id, public]
+		HackathonVisibilitySet { hackathon_id: u32, public: bool },
+		/// A hackathon's submissions have been closed ahead of time and its voting has begun
+		/// [hackathon_id, submission_end]
+		SubmissionsClosedEarly { hackathon_id: u32, submission_end: T::BlockNumber },
+		/// An account has been invited to join a team [team_id, member]
+		MemberInvited { team_id: u32, member: T::AccountId },
+		/// A challenge's required team category has been set or cleared [hackathon_id, id, category]
+		RequiredCategorySet { hackathon_id: u32, id: u16, category: Option<u16> },
+		/// A team's category has been set or cleared [team_id, category]
+		TeamCategorySet { team_id: u32, category: Option<u16> },
+		/// A hackathon's challenge creation window has been extended [hackathon_id, deadline]
+		ChallengeWindowExtended { hackathon_id: u32, deadline: T::BlockNumber },
+		/// A hackathon's judge pool has been replaced [hackathon_id, judges]
+		EventJudgesSet { hackathon_id: u32, judges: BoundedVec<T::AccountId, T::MaxEventJudges> },
+		/// The judge pool has been spread across a hackathon's challenges [hackathon_id, per_challenge]
+		JudgesAutoAssigned { hackathon_id: u32, per_challenge: u32 },
+		/// A prize has been added to a challenge [challenge_id, prize_id, reward]
+		PrizeAdded { challenge_id: u16, prize_id: u16, reward: BalanceOf<T> },
+		/// A judge has voted for a prize's winner [challenge_id, prize_id, judge, solution_id]
+		PrizeVoteCast { challenge_id: u16, prize_id: u16, judge: T::AccountId, solution_id: u16 },
+		/// A prize has been settled, to a winning solution or with its reward released
+		/// [challenge_id, prize_id, winning_solution]
+		PrizeFinalized { challenge_id: u16, prize_id: u16, winning_solution: Option<u16> },
+		/// A team has been disbanded and its bond returned to the founder [team_id, bond]
+		TeamDisbanded { team_id: u32, bond: BalanceOf<T> },
+		/// The admin origin has slashed a team's bond [team_id, amount]
+		TeamBondSlashed { team_id: u32, amount: BalanceOf<T> },
+		/// A bounty's amount has been split into milestones [bounty_id, milestones]
+		BountyMilestonesSet { bounty_id: u32, milestones: Vec<(H256, BalanceOf<T>)> },
+		/// A bounty milestone has been paid to the claimant [bounty_id, milestone_index, claimant, amount]
+		MilestoneApproved {
+			bounty_id: u32,
+			milestone_index: u32,
+			claimant: T::AccountId,
+			amount: BalanceOf<T>,
+		},
+		/// A team has staged a draft solution [challenge_id, solution_id]
+		SolutionDrafted { challenge_id: u16, solution_id: u16 },
+		/// The organizer has disqualified a solution [challenge_id, solution_id]
+		SolutionDisqualified { challenge_id: u16, solution_id: u16 },
+		/// The largest team a hackathon accepts has changed [hackathon_id, max_team_size]
+		TeamSizeUpdated { hackathon_id: u32, max_team_size: u32 },
+		/// A challenge's funder has held more of its reward [hackathon_id, challenge_id, amount, outstanding]
+		ChallengeFunded {
+			hackathon_id: u32,
+			challenge_id: u16,
+			amount: BalanceOf<T>,
+			outstanding: BalanceOf<T>,
+		},
+		/// A team has escalated a dispute over a challenge to root [challenge_id, team_id]
+		DisputeEscalated { challenge_id: u16, team_id: u32 },
+		/// Root has ruled on an escalated dispute [challenge_id, reinstated]
+		DisputeResolved { challenge_id: u16, reinstated: bool },
+		/// A settled challenge has been replaced by a summary of its result [hackathon_id, challenge_id]
+		ChallengeArchived { hackathon_id: u32, challenge_id: u16 },
+		/// A challenge's winner must have this share of the votes cast [hackathon_id, id, agreement]
+		RequiredAgreementSet { hackathon_id: u32, id: u16, agreement: Permill },
+		/// A team's captain has been named or cleared [team_id, captain]
+		TeamCaptainSet { team_id: u32, captain: Option<T::AccountId> },
+		/// A team's roster has been made private or public [team_id, private]
+		RosterPrivacySet { team_id: u32, private: bool },
+		/// A member has taken over the founder role of a team whose founder went inactive [team_id, founder]
+		FounderClaimed { team_id: u32, founder: T::AccountId },
+		/// A team's bounty payouts need this many approvals [team_id, approvals]
+		RequiredBountyApprovalsSet { team_id: u32, approvals: u32 },
+		/// The winning team has credited off-chain contributors with shares of a prize [challenge_id, team_id, contributors]
+		ContributorsAcknowledged { challenge_id: u16, team_id: u32, contributors: Vec<(H256, u8)> },
+		/// A judge's vote backed a solution that was disqualified [judge, strikes]
+		JudgeStruck { judge: T::AccountId, strikes: u32 },
+		/// A judge reached the strike limit, was barred from panels and lost their deposit [judge, slashed]
+		JudgeBanned { judge: T::AccountId, slashed: BalanceOf<T> },
+		/// A team has entered a hackathon [team_id, hackathon_id]
+		TeamEntered { team_id: u32, hackathon_id: u32 },
+		/// A bounty payout has been approved but needs more approvals [bounty_id, approver, milestone, approvals]
+		BountyApprovalRecorded {
+			bounty_id: u32,
+			approver: T::AccountId,
+			milestone: Option<u32>,
+			approvals: u32,
+		},
+		/// Part of a winner's vesting share has been released [who, challenge_id, prize_id, amount]
+		VestedRewardReleased {
+			who: T::AccountId,
+			challenge_id: u16,
+			prize_id: Option<u16>,
+			amount: BalanceOf<T>,
+		},
+		/// A hackathon's organizer has allowed or forbidden ownership bounties [hackathon_id, allowed]
+		OwnershipBountiesSet { hackathon_id: u32, allowed: bool },
+		/// A judge has backed extending a hackathon, short of a majority [hackathon_id, judge, blocks, supporters]
+		ExtensionSupportRecorded {
+			hackathon_id: u32,
+			judge: T::AccountId,
+			blocks: T::BlockNumber,
+			supporters: u32,
+		},
+		/// A majority of a hackathon's judge pool asks for it to be extended [hackathon_id, blocks, supporters]
+		ExtensionRequested { hackathon_id: u32, blocks: T::BlockNumber, supporters: u32 },
 	}
 
 	// Errors inform users that something went wrong.
 	#[pallet::error]
 	pub enum Error<T> {
-		/// Account editing a challenge must be the original challenge author.
-		AccountHasNoChallengeRegistered,
-		/// A challenge must exist for a valid solution submission
+		/// Only the challenge's author may do this.
+		NotChallengeAuthor,
+		/// No challenge is registered under this id in the hackathon.
 		ChallengeDoesNotExist,
 		// Submitted solution contains too many members
-		TooManyMembers
+		TooManyMembers,
+		/// A challenge cannot be edited once its hackathon's submissions open or solutions have been
+		/// submitted to it.
+		ChallengeLocked,
+		/// The account already has a buidler profile.
+		BuidlerAlreadyRegistered,
+		/// The account has no buidler profile.
+		BuidlerNotRegistered,
+		/// Only the challenge's judges may do this.
+		NotAJudge,
+		/// The challenge has already been edited the maximum number of times.
+		TooManyEdits,
+		/// This team has already submitted a solution to this challenge.
+		SolutionAlreadySubmitted,
+		/// The challenge has already accepted the maximum number of solutions.
+		TooManySolutions,
+		/// No solution with this id has been submitted to the challenge.
+		SolutionDoesNotExist,
+		/// No team is registered under this id.
+		TeamDoesNotExist,
+		/// Only members of the team may do this.
+		NotATeamMember,
+		/// The account is already a member of the team.
+		AlreadyTeamMember,
+		/// There are no team ids left to assign.
+		TeamIdOverflow,
+		/// The account cannot cover the challenge reward or bounty amount from its free balance.
+		InsufficientBalance,
+		/// Every judge supplied is already a judge of the challenge.
+		DuplicateJudge,
+		/// There are no challenge ids left to assign.
+		ChallengeIdOverflow,
+		/// Periods must be ordered: submission start, submission end, vote start, vote end.
+		InvalidPeriod,
+		/// Solutions can only be submitted during the submission period.
+		SubmissionPeriodClosed,
+		/// Votes can only be cast during the voting period.
+		VotingPeriodClosed,
+		/// A challenge can only be finalized once the voting period has ended.
+		VotingNotEnded,
+		/// The challenge has already been finalized.
+		ChallengeAlreadyFinalized,
+		/// A challenge cannot be finalized, nor a vote delegated, while submissions are still open.
+		SubmissionsStillOpen,
+		/// No bounty is registered under this id.
+		BountyDoesNotExist,
+		/// There are no bounty ids left to assign.
+		BountyIdOverflow,
+		/// Ownership percentages cannot exceed 100.
+		InvalidPercentage,
+		/// The expiry must be later than the current one, or the current block for a new bounty.
+		ExpiryNotInFuture,
+		/// The bounty has already been claimed or approved.
+		BountyAlreadyClaimed,
+		/// The bounty's expiry has passed.
+		BountyExpired,
+		/// Only a claimed bounty can be approved.
+		BountyNotClaimed,
+		/// The team member has already rejected this claim.
+		AlreadyRejected,
+		/// Only an unclaimed bounty past its expiry can be redirected.
+		BountyNotExpired,
+		/// A team member is already listed on the maximum number of solutions in this hackathon.
+		TooManyCoauthorships,
+		/// No hackathon is registered under this id.
+		HackathonDoesNotExist,
+		/// There are no hackathon ids left to assign.
+		HackathonIdOverflow,
+		/// Only the hackathon's organizer may do this.
+		NotHackathonOrganizer,
+		/// A challenge cannot be cancelled once solutions have been submitted to it.
+		ChallengeHasSubmissions,
+		/// The hackathon's voting period has already started.
+		VotingAlreadyStarted,
+		/// The periods span more than `MaxHackathonDuration` blocks.
+		HackathonTooLong,
+		/// A judge list was supplied but it is empty.
+		NoJudgesProvided,
+		/// The account has been invited to judge but has not accepted yet.
+		JudgeInvitePending,
+		/// The account has not been invited to judge this challenge.
+		NoJudgeInvite,
+		/// The judge is a member of a team that submitted a solution to this challenge.
+		JudgeIsParticipant,
+		/// Delegations cannot be chained, which also rules out cycles: a delegate must vote
+		/// themselves and a judge holding delegations cannot pass them on.
+		DelegationCycle,
+		/// The judge has delegated their vote for this challenge.
+		JudgeHasDelegated,
+		/// The judge has already voted on this challenge.
+		AlreadyVoted,
+		/// The judge has not delegated their vote for this challenge.
+		NoDelegation,
+		/// A hackathon's periods cannot change once its submission period has ended.
+		SubmissionsAlreadyEnded,
+		/// The submission period must end after the current block.
+		PeriodEndNotInFuture,
+		/// Too many hackathons already have a period ending at that block.
+		TooManyPhaseEnds,
+		/// A bounty cannot be claimed by its poster or a member of its team.
+		ClaimantIsTeamMember,
+		/// A team member has rejected the claim, so it cannot be approved.
+		BountyRejectionPending,
+		/// Only the bounty's poster may do this.
+		NotBountyPoster,
+		/// A bounty must offer funds, ownership or both.
+		EmptyBounty,
+		/// Only the bounty's current claimant may do this.
+		NotBountyClaimant,
+		/// Bounty percentages must be a multiple of `PercentageStep`.
+		InvalidPercentageStep,
+		/// The account has not been invited to join this team.
+		NoTeamInvite,
+		/// The challenge already has `MaxContributors` distinct contributors.
+		TooManyContributors,
+		/// The team's category does not match the one the challenge requires.
+		CategoryMismatch,
+		/// Only the team's founder may do this.
+		NotTeamFounder,
+		/// The hackathon no longer accepts new challenges.
+		ChallengeWindowClosed,
+		/// A challenge deadline must be later than the current one and before submissions open.
+		InvalidChallengeDeadline,
+		/// Approving the bounty would grant more than 100% of the team's prize.
+		OwnershipExceeds100,
+		/// The hackathon has no judge pool to assign from.
+		NoEventJudges,
+		/// No prize is registered under this id in the challenge.
+		PrizeDoesNotExist,
+		/// The challenge already holds `MaxPrizes` prizes.
+		TooManyPrizes,
+		/// The prize has already been settled.
+		PrizeAlreadyFinalized,
+		/// The founder cannot afford the team bond.
+		InsufficientBalanceForTeamBond,
+		/// The team still has open or claimed bounties.
+		TeamHasPendingBounties,
+		/// The milestone amounts do not add up to the bounty's amount.
+		MilestonesMismatch,
+		/// Milestones cannot change once one has been paid.
+		MilestonesAlreadyPaid,
+		/// The bounty has no milestone at that index.
+		MilestoneDoesNotExist,
+		/// Milestones are paid in order and this one is not next.
+		MilestoneNotNext,
+		/// The solution has already been made official.
+		SolutionNotDraft,
+		/// The submitter cannot afford the submission bond.
+		InsufficientBalanceForSubmissionBond,
+		/// The solution has already been disqualified.
+		SolutionAlreadyDisqualified,
+		/// The account created a team too recently to create another.
+		TeamCreationCooldownActive,
+		/// A hackathon's team size limit must be between 1 and `MaxMembers`.
+		InvalidTeamSize,
+		/// The team is larger than a hackathon it competes in allows.
+		TeamTooLarge,
+		/// Only the challenge's funder can hold more of its reward.
+		NotChallengeFunder,
+		/// The challenge's reward is already held in full.
+		ChallengeFullyFunded,
+		/// The challenge does not accept solutions until its reward is held in full.
+		ChallengeNotFunded,
+		/// Only a team that submitted to the challenge can escalate a dispute over it.
+		NotChallengeParticipant,
+		/// A dispute over the challenge is already escalated.
+		DisputeAlreadyEscalated,
+		/// No dispute over the challenge is escalated.
+		DisputeNotEscalated,
+		/// A dispute over the challenge awaits root's ruling.
+		PayoutFrozen,
+		/// Only a settled challenge whose prizes are all settled can be archived.
+		ChallengeNotSettled,
+		/// Only the team founder or captain can post and approve the team's bounties.
+		NotBountyManager,
+		/// The solution does not prove the bounty was completed.
+		ProofInvalid,
+		/// The team's founder has acted for it within `FounderInactivityTimeout` blocks.
+		FounderStillActive,
+		/// The caller has already approved this payout.
+		AlreadyApproved,
+		/// `MaxActiveHackathons` hackathons are already registered and still running.
+		TooManyActiveHackathons,
+		/// The challenge has not been won by a team.
+		NoWinningTeam,
+		/// The judge cannot afford the judge deposit.
+		InsufficientBalanceForJudgeDeposit,
+		/// The judge has been barred from panels for backing disqualified solutions.
+		JudgeBanned,
+		/// The team has already entered this hackathon.
+		AlreadyEntered,
+		/// The account has no share of this reward vesting.
+		NoVestingSchedule,
+		/// Nothing more of the share has vested yet.
+		NothingVested,
+		/// The hackathon does not allow bounties to offer a percentage of a team's prize.
+		OwnershipBountiesDisabled,
+		/// The account is not in the hackathon's judge pool.
+		NotEventJudge,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		// Signal phase transitions so indexers don't have to track the periods themselves
+		fn on_initialize(now: T::BlockNumber) -> Weight {
+			let submissions_ended = SubmissionEnds::<T>::take(now);
+			let votes_ended = VoteEnds::<T>::take(now);
+			let ended = submissions_ended.len().max(votes_ended.len()) as u32;
+			for hackathon_id in submissions_ended {
+				Self::deposit_event(Event::SubmissionPhaseEnded { hackathon_id });
+			}
+			if !votes_ended.is_empty() {
+				ActiveHackathons::<T>::mutate(|active| active.retain(|id| !votes_ended.contains(id)));
+			}
+			for hackathon_id in votes_ended {
+				Self::deposit_event(Event::VotingPhaseEnded { hackathon_id });
+			}
+			T::WeightInfo::on_initialize(ended)
+		}
+
+		fn integrity_test() {
+			// challenges posted at registration would be locked straight away
+			assert!(!T::DefaultCreationDuration::get().is_zero());
+			// a submission period ending as it opens would accept nothing
+			assert!(!T::DefaultSubmissionDuration::get().is_zero());
+			assert!(
+				T::DefaultSubmissionDuration::get().saturating_add(T::DefaultVotingDuration::get()) <=
+					T::MaxHackathonDuration::get()
+			);
+		}
 	}
 
 	// Dispatchable functions allows users to interact with the pallet and invoke state changes.
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
+
+		// Allows anyone to register a hackathon, reserving the bond until it is wound down
+		#[pallet::weight(T::WeightInfo::register())]
+		pub fn register(origin: OriginFor<T>, name: H256) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let bond = T::HackathonBond::get();
+			ensure!(T::Deposit::can_reserve(&who, bond), Error::<T>::InsufficientBalance);
+
+			let hackathon_id = NextHackathonId::<T>::get();
+			let next_hackathon_id = hackathon_id.checked_add(1).ok_or(Error::<T>::HackathonIdOverflow)?;
+			// whatever ended without being announced makes room too
+			let mut active = ActiveHackathons::<T>::get();
+			active.retain(|id| !Self::has_voting_ended(*id));
+			active.try_push(hackathon_id).map_err(|_| Error::<T>::TooManyActiveHackathons)?;
+
+			T::Deposit::reserve(&who, bond)?;
+
+			let submission_start = frame_system::Pallet::<T>::block_number()
+				.saturating_add(T::DefaultCreationDuration::get());
+			let submission_end = submission_start.saturating_add(T::DefaultSubmissionDuration::get());
+			let periods = EventPeriods {
+				submission_start,
+				submission_end,
+				vote_start: submission_end,
+				vote_end: submission_end.saturating_add(T::DefaultVotingDuration::get()),
+			};
+			// busy end blocks must not stop an event from registering. Defaults that do not fit
+			// are only announced once the organizer sets periods with `update_period`
+			let _ = Self::schedule_phase_ends(hackathon_id, &periods);
+
+			Hackathons::<T>::insert(
+				hackathon_id,
+				Hackathon::<T> {
+					organizer: who.clone(),
+					name,
+					periods: Some(periods),
+					allow_late_edits: false,
+					metadata: None,
+					public: true,
+					challenge_deadline: submission_start,
+					max_team_size: T::MaxMembers::get(),
+					allow_ownership_bounties: true,
+				},
+			);
+			NextHackathonId::<T>::put(next_hackathon_id);
+			ActiveHackathons::<T>::put(active);
+
+			Self::deposit_event(Event::HackathonRegistered { hackathon_id, organizer: who });
+
+			Ok(())
+		}
 	
-		// A way for anyone to post their challenge and lock their reward.
+		// A way for anyone to post their challenge to a hackathon and lock their reward.
 		// TODO: This should return with PostInfo 
-		#[pallet::weight(0)]
+		#[pallet::weight(T::WeightInfo::create_challenge(judges.as_ref().map_or(0, |j| j.len() as u32)))]
 		pub fn create_challenge(
 			origin: OriginFor<T>, 
+			hackathon_id: u32,
 			description: H256,
 			reward: BalanceOf<T>,
-			judges: Option<BoundedVec<T::AccountId, T::MaxMembers>>
+			judges: Option<BoundedVec<T::AccountId, T::MaxMembers>>,
+			submission_format: Option<H256>,
 		) -> DispatchResult
 		{
 			let who = ensure_signed(origin)?;
-
-			// check has sufficient funds and lock
-			// the trait isn't great for multi assets 
-			// custom custom impl better over multi-assets
-			T::Deposit::set_lock(
-				DEPOSIT_FOR_CHALLENGE,
-				&who,
+			Self::do_create_challenge(
+				hackathon_id,
+				who.clone(),
+				who,
+				description,
 				reward,
-				WithdrawReasons::all(),
-			);
+				judges,
+				submission_format,
+			)
+		}
 
-			// create new challenge object
-			let new_challenge = Challenge::<T> {
+		// Allows the admin origin to post a challenge on behalf of `author` with its reward locked
+		// from the treasury account, so that any refund goes back to the treasury
+		#[pallet::weight(T::WeightInfo::create_treasury_challenge(judges.as_ref().map_or(0, |j| j.len() as u32)))]
+		pub fn create_treasury_challenge(
+			origin: OriginFor<T>,
+			hackathon_id: u32,
+			author: T::AccountId,
+			description: H256,
+			reward: BalanceOf<T>,
+			judges: Option<BoundedVec<T::AccountId, T::MaxMembers>>,
+			submission_format: Option<H256>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			Self::do_create_challenge(
+				hackathon_id,
+				author,
+				T::TreasuryAccount::get(),
 				description,
 				reward,
 				judges,
-				submissions: 0
-			};
-
-			// write to storage
-			let next_challenge_id = NextChallengeId::<T>::get();
-			Challenges::<T>::insert(next_challenge_id, new_challenge);
-			next_challenge_id.checked_add(1);
-			NextChallengeId::<T>::put(next_challenge_id);
-
-			Self::deposit_event(Event::ChallengeCreated { id: next_challenge_id, creator: who.clone() });
-
-			Ok(()).into()
-
+				submission_format,
+			)
 		}
 
-		// Allows challenge author to edit their challenge description
-		#[pallet::weight(0)]
+		// Allows challenge author to edit their challenge description and submission format hint,
+		// until the hackathon's submission period opens
+		#[pallet::weight(T::WeightInfo::edit_challenge())]
 		pub fn edit_challenge(		
 			origin: OriginFor<T>,
+			hackathon_id: u32,
 			id: u16, 
 			new_description: H256,
+			new_submission_format: Option<H256>,
 		) -> DispatchResult {
 
 			let who = ensure_signed(origin)?;
 
 			// check challenge exists and is owned by caller and get the challenge object
-			let mut challenge = Challenges::<T>::get(&id).ok_or(Error::<T>::AccountHasNoChallengeRegistered)?;
+			let mut challenge =
+				Challenges::<T>::get(&hackathon_id, &id).ok_or(Error::<T>::ChallengeDoesNotExist)?;
+			ensure!(challenge.author == who, Error::<T>::NotChallengeAuthor);
+
+			// teams may already be building against the current description, unless the organizer
+			// has allowed late edits
+			let late_edits = Hackathons::<T>::get(&hackathon_id)
+				.map_or(false, |hackathon| hackathon.allow_late_edits);
+			if !late_edits {
+				ensure!(!Self::has_submission_started(hackathon_id), Error::<T>::ChallengeLocked);
+				ensure!(challenge.submissions == 0, Error::<T>::ChallengeLocked);
+			}
+			ensure!(challenge.edits < T::MaxEdits::get(), Error::<T>::TooManyEdits);
 
 			// mutate the description field with new_description
 			challenge.description = new_description;
+			challenge.submission_format = new_submission_format;
+			challenge.edits = challenge.edits.saturating_add(1);
 
 			// write updated object to storage
-			Challenges::<T>::insert(&id, challenge);
+			Challenges::<T>::insert(&hackathon_id, &id, challenge);
+
+			Self::deposit_event(Event::ChallengeEdited { hackathon_id, id, editor: who });
 
 			Ok(())
 		}
 
-		// Allows an account to submit a solution to a challenge
-		#[pallet::weight(0)]
-		pub fn submit_solution(
+		// Allows challenge author to set or clear the bonus given to early submissions, until the
+		// hackathon's submission period opens
+		#[pallet::weight(T::WeightInfo::set_early_bonus())]
+		pub fn set_early_bonus(
 			origin: OriginFor<T>,
-			challengeId: u16,
-			solution: H256,
-			members: Vec<T::AccountId>
+			hackathon_id: u32,
+			id: u16,
+			early_bonus: Option<EarlyBonus<T::BlockNumber>>,
 		) -> DispatchResult {
-			
 			let who = ensure_signed(origin)?;
-			// check if the challenge exists
-			ensure!(Challenges::<T>::contains_key(&challengeId), Error::<T>::ChallengeDoesNotExist);
-		
-			// TODO: ensure that the members added are bounded
 
-			// check that the solution does not exist
+			let mut challenge =
+				Challenges::<T>::get(&hackathon_id, &id).ok_or(Error::<T>::ChallengeDoesNotExist)?;
+			ensure!(challenge.author == who, Error::<T>::NotChallengeAuthor);
 
-			// // create a new solution object
-			// let new_solution = SubmittedSolution::<T> {
-			// 	solution,
-			// 	members,
-			// };
+			// teams decide when to submit knowing the bonus
+			ensure!(!Self::has_submission_started(hackathon_id), Error::<T>::ChallengeLocked);
+			ensure!(challenge.submissions == 0, Error::<T>::ChallengeLocked);
 
-			//ChallengeSolutions::<T>::mutate(challengeId, |sol| {
-			//
-			//	sol = new_solution.clone();
-			//});
+			challenge.early_bonus = early_bonus.clone();
+			Challenges::<T>::insert(&hackathon_id, &id, challenge);
 
-			Self::deposit_event(Event::SolutionSubmitted{ id: challengeId, member: who.clone() });
+			Self::deposit_event(Event::EarlyBonusSet { hackathon_id, id, early_bonus });
 
-			Ok(()).into()
+			Ok(())
 		}
-	}
 
+		// Allows challenge author to restrict submissions to teams of one category, or lift the
+		// restriction, until the hackathon's submission period opens
+		#[pallet::weight(T::WeightInfo::set_required_category())]
+		pub fn set_required_category(
+			origin: OriginFor<T>,
+			hackathon_id: u32,
+			id: u16,
+			category: Option<u16>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let mut challenge =
+				Challenges::<T>::get(&hackathon_id, &id).ok_or(Error::<T>::ChallengeDoesNotExist)?;
+			ensure!(challenge.author == who, Error::<T>::NotChallengeAuthor);
+			ensure!(!Self::has_submission_started(hackathon_id), Error::<T>::ChallengeLocked);
+			ensure!(challenge.submissions == 0, Error::<T>::ChallengeLocked);
+
+			challenge.required_category = category;
+			Challenges::<T>::insert(&hackathon_id, &id, challenge);
+
+			Self::deposit_event(Event::RequiredCategorySet { hackathon_id, id, category });
+
+			Ok(())
+		}
+
+		// Allows challenge author to require the winning solution to have at least a share of the
+		// votes cast, until the hackathon's submission period opens
+		#[pallet::weight(T::WeightInfo::set_required_agreement())]
+		pub fn set_required_agreement(
+			origin: OriginFor<T>,
+			hackathon_id: u32,
+			id: u16,
+			agreement: Permill,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let mut challenge =
+				Challenges::<T>::get(&hackathon_id, &id).ok_or(Error::<T>::ChallengeDoesNotExist)?;
+			ensure!(challenge.author == who, Error::<T>::NotChallengeAuthor);
+			ensure!(!Self::has_submission_started(hackathon_id), Error::<T>::ChallengeLocked);
+			ensure!(challenge.submissions == 0, Error::<T>::ChallengeLocked);
+
+			challenge.required_agreement = agreement;
+			Challenges::<T>::insert(&hackathon_id, &id, challenge);
+
+			Self::deposit_event(Event::RequiredAgreementSet { hackathon_id, id, agreement });
+
+			Ok(())
+		}
+
+		// Allows a challenge's funder to hold more of its reward, up to what is outstanding. The
+		// challenge accepts solutions once the whole reward is held.
+		#[pallet::weight(T::WeightInfo::fund_challenge())]
+		pub fn fund_challenge(
+			origin: OriginFor<T>,
+			hackathon_id: u32,
+			challenge_id: u16,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let mut challenge =
+				Challenges::<T>::get(&hackathon_id, &challenge_id).ok_or(Error::<T>::ChallengeDoesNotExist)?;
+			ensure!(challenge.funder == who, Error::<T>::NotChallengeFunder);
+			ensure!(!challenge.outstanding.is_zero(), Error::<T>::ChallengeFullyFunded);
+			let amount = amount.min(challenge.outstanding);
+			ensure!(T::RewardCurrency::can_hold(&who, amount), Error::<T>::InsufficientBalance);
+
+			T::RewardCurrency::hold(&who, amount)?;
+			challenge.outstanding = challenge.outstanding.saturating_sub(amount);
+			let outstanding = challenge.outstanding;
+			Challenges::<T>::insert(&hackathon_id, &challenge_id, challenge);
+
+			Self::deposit_event(Event::ChallengeFunded { hackathon_id, challenge_id, amount, outstanding });
+
+			Ok(())
+		}
+
+		// Allows challenge author to add a prize with its own judge panel, holding its reward,
+		// until the hackathon's submission period opens
+		#[pallet::weight(T::WeightInfo::add_prize(judges.len() as u32))]
+		pub fn add_prize(
+			origin: OriginFor<T>,
+			hackathon_id: u32,
+			challenge_id: u16,
+			name: H256,
+			reward: BalanceOf<T>,
+			judges: BoundedVec<T::AccountId, T::MaxMembers>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let challenge =
+				Challenges::<T>::get(&hackathon_id, &challenge_id).ok_or(Error::<T>::ChallengeDoesNotExist)?;
+			ensure!(challenge.author == who, Error::<T>::NotChallengeAuthor);
+			ensure!(!Self::has_submission_started(hackathon_id), Error::<T>::ChallengeLocked);
+			ensure!(!judges.is_empty(), Error::<T>::NoJudgesProvided);
+			Self::ensure_not_banned(judges.iter())?;
+			// prizes are only ever removed all together, so their count is the next id
+			let prize_id = ChallengePrizes::<T>::iter_key_prefix(&challenge_id).count() as u32;
+			ensure!(prize_id < T::MaxPrizes::get(), Error::<T>::TooManyPrizes);
+			let prize_id = u16::try_from(prize_id).map_err(|_| Error::<T>::TooManyPrizes)?;
+			ensure!(T::RewardCurrency::can_hold(&who, reward), Error::<T>::InsufficientBalance);
+
+			T::RewardCurrency::hold(&who, reward)?;
+			let prize = Prize::<T> {
+				name,
+				funder: who,
+				reward,
+				judges: Self::dedup_judges(judges),
+				settled: false,
+				winner: None,
+			};
+			ChallengePrizes::<T>::insert(&challenge_id, &prize_id, prize);
+
+			Self::deposit_event(Event::PrizeAdded { challenge_id, prize_id, reward });
+
+			Ok(())
+		}
+
+		// Allows the challenge author to withdraw a challenge nobody has submitted to, releasing
+		// the reward. Only possible until the voting period starts.
+		#[pallet::weight(T::WeightInfo::cancel_challenge(T::MaxContributors::get(), T::MaxPrizes::get()))]
+		pub fn cancel_challenge(
+			origin: OriginFor<T>,
+			hackathon_id: u32,
+			challenge_id: u16,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let challenge =
+				Challenges::<T>::get(&hackathon_id, &challenge_id).ok_or(Error::<T>::ChallengeDoesNotExist)?;
+			ensure!(challenge.author == who, Error::<T>::NotChallengeAuthor);
+			ensure!(!Self::has_voting_started(hackathon_id), Error::<T>::VotingAlreadyStarted);
+			ensure!(challenge.submissions == 0, Error::<T>::ChallengeHasSubmissions);
+
+			// only what is held comes back, releasing more would free the funder's other holds
+			let held = challenge.reward.saturating_sub(challenge.outstanding);
+			Self::refund(challenge_id, &challenge.funder, held)?;
+			for (_, prize) in ChallengePrizes::<T>::drain_prefix(&challenge_id) {
+				T::RewardCurrency::release(&prize.funder, prize.reward, true)?;
+			}
+			Challenges::<T>::remove(&hackathon_id, &challenge_id);
+			NextSolutionId::<T>::remove(&challenge_id);
+			let _ = JudgeInvites::<T>::clear_prefix(&challenge_id, T::MaxMembers::get(), None);
+			let _ = VoteDelegations::<T>::clear_prefix(&challenge_id, T::MaxMembers::get(), None);
+
+			Self::deposit_event(Event::ChallengeCancelled { hackathon_id, challenge_id });
+
+			Ok(())
+		}
+
+		// Allows an account to submit a solution to a challenge, optionally keeping the team's
+		// members out of listings until voting ends. Charged for a full team since the roster is
+		// only known once the team is read, and for a full challenge since every solution is
+		// checked for an earlier one from the same team.
+		#[pallet::weight(T::WeightInfo::submit_solution(T::MaxMembers::get(), T::MaxSolutions::get()))]
+		pub fn submit_solution(
+			origin: OriginFor<T>,
+			hackathon_id: u32,
+			challenge_id: u16,
+			team_id: u32,
+			solution: H256,
+			anonymous: bool,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_submit_solution(
+				who,
+				hackathon_id,
+				challenge_id,
+				team_id,
+				solution,
+				anonymous,
+				false,
+			)
+		}
+
+		// Allows a team member to stage a solution for a challenge while submissions are open. A
+		// draft takes the team's one solution slot like a submission, but judges cannot vote on it
+		// and listings leave it out until it is made official with `set_official_solution`.
+		#[pallet::weight(T::WeightInfo::draft_solution(T::MaxMembers::get(), T::MaxSolutions::get()))]
+		pub fn draft_solution(
+			origin: OriginFor<T>,
+			hackathon_id: u32,
+			challenge_id: u16,
+			team_id: u32,
+			solution: H256,
+			anonymous: bool,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_submit_solution(
+				who,
+				hackathon_id,
+				challenge_id,
+				team_id,
+				solution,
+				anonymous,
+				true,
+			)
+		}
+
+		// Allows a member of a drafting team to make its draft official while submissions are open
+		#[pallet::weight(T::WeightInfo::set_official_solution())]
+		pub fn set_official_solution(
+			origin: OriginFor<T>,
+			hackathon_id: u32,
+			challenge_id: u16,
+			solution_id: u16,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				Challenges::<T>::contains_key(&hackathon_id, &challenge_id),
+				Error::<T>::ChallengeDoesNotExist
+			);
+			let mut solution = ChallengeSolutions::<T>::get(&challenge_id, &solution_id)
+				.ok_or(Error::<T>::SolutionDoesNotExist)?;
+			ensure!(solution.members.contains(&who), Error::<T>::NotATeamMember);
+			ensure!(Self::is_submission_open(hackathon_id), Error::<T>::SubmissionPeriodClosed);
+			ensure!(solution.draft, Error::<T>::SolutionNotDraft);
+
+			solution.draft = false;
+			let member = if solution.anonymous { None } else { Some(who) };
+			ChallengeSolutions::<T>::insert(&challenge_id, &solution_id, solution);
+
+			Self::deposit_event(Event::SolutionSubmitted { id: challenge_id, member });
+
+			Ok(())
+		}
+
+		// Allows a member of a submitting team to retract its solution before voting starts,
+		// returning the submission bond. The team may submit again while submissions are open.
+		// Charged for a full team.
+		#[pallet::weight(T::WeightInfo::withdraw_solution(T::MaxMembers::get()))]
+		pub fn withdraw_solution(
+			origin: OriginFor<T>,
+			hackathon_id: u32,
+			challenge_id: u16,
+			solution_id: u16,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let mut challenge =
+				Challenges::<T>::get(&hackathon_id, &challenge_id).ok_or(Error::<T>::ChallengeDoesNotExist)?;
+			let solution = ChallengeSolutions::<T>::get(&challenge_id, &solution_id)
+				.ok_or(Error::<T>::SolutionDoesNotExist)?;
+			ensure!(solution.members.contains(&who), Error::<T>::NotATeamMember);
+			ensure!(!Self::has_voting_started(hackathon_id), Error::<T>::VotingAlreadyStarted);
+
+			T::Deposit::unreserve(&solution.submitter, solution.bond);
+			for member in solution.members.iter() {
+				Coauthorships::<T>::mutate(&hackathon_id, member, |count| {
+					*count = count.saturating_sub(1)
+				});
+			}
+			ChallengeSolutions::<T>::remove(&challenge_id, &solution_id);
+
+			challenge.submissions = challenge.submissions.saturating_sub(1);
+			Challenges::<T>::insert(&hackathon_id, &challenge_id, challenge);
+
+			let member = if solution.anonymous { None } else { Some(who) };
+			Self::deposit_event(Event::SolutionWithdrawn { challenge_id, solution_id, member });
+
+			Ok(())
+		}
+
+		// Allows a hackathon's organizer, or the admin origin, to disqualify a solution until its
+		// challenge is settled. Judges can no longer back it, votes already cast on it count for
+		// nothing, and its submission bond is slashed when the challenge is settled.
+		#[pallet::weight(T::WeightInfo::disqualify_solution(T::MaxMembers::get()))]
+		pub fn disqualify_solution(
+			origin: OriginFor<T>,
+			hackathon_id: u32,
+			challenge_id: u16,
+			solution_id: u16,
+		) -> DispatchResult {
+			let hackathon = Hackathons::<T>::get(&hackathon_id).ok_or(Error::<T>::HackathonDoesNotExist)?;
+			Self::ensure_organizer(origin, &hackathon)?;
+			ensure!(
+				Challenges::<T>::contains_key(&hackathon_id, &challenge_id),
+				Error::<T>::ChallengeDoesNotExist
+			);
+			ensure!(!Self::is_finalized(challenge_id), Error::<T>::ChallengeAlreadyFinalized);
+			ensure!(!EscalatedDisputes::<T>::contains_key(&challenge_id), Error::<T>::PayoutFrozen);
+			let mut solution = ChallengeSolutions::<T>::get(&challenge_id, &solution_id)
+				.ok_or(Error::<T>::SolutionDoesNotExist)?;
+			ensure!(!solution.disqualified, Error::<T>::SolutionAlreadyDisqualified);
+
+			solution.disqualified = true;
+			ChallengeSolutions::<T>::insert(&challenge_id, &solution_id, solution);
+
+			Self::deposit_event(Event::SolutionDisqualified { challenge_id, solution_id });
+
+			for (judge, backed) in Self::counted_votes(challenge_id) {
+				if backed == solution_id {
+					Self::strike_judge(challenge_id, judge);
+				}
+			}
+
+			Ok(())
+		}
+
+		// Allows the challenge author to invite judges. Invitees only join the panel once they
+		// accept with `accept_judge_role`.
+		#[pallet::weight(T::WeightInfo::add_judges(new_judges.len() as u32))]
+		pub fn add_judges(
+			origin: OriginFor<T>,
+			hackathon_id: u32,
+			challenge_id: u16,
+			new_judges: Vec<T::AccountId>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let challenge =
+				Challenges::<T>::get(&hackathon_id, &challenge_id).ok_or(Error::<T>::ChallengeDoesNotExist)?;
+			ensure!(challenge.author == who, Error::<T>::NotChallengeAuthor);
+
+			// skip anyone already judging or invited; pending invites count towards the panel size
+			let judges = challenge.judges.unwrap_or_default();
+			let mut seats = (judges.len() as u32)
+				.saturating_add(JudgeInvites::<T>::iter_prefix(&challenge_id).count() as u32);
+			let mut invited = Vec::new();
+			for judge in new_judges {
+				if judges.contains(&judge) ||
+					invited.contains(&judge) ||
+					JudgeInvites::<T>::contains_key(&challenge_id, &judge)
+				{
+					continue
+				}
+				ensure!(seats < T::MaxMembers::get(), Error::<T>::TooManyMembers);
+				seats = seats.saturating_add(1);
+				invited.push(judge);
+			}
+			ensure!(!invited.is_empty(), Error::<T>::DuplicateJudge);
+			Self::ensure_not_banned(invited.iter())?;
+
+			for judge in invited.iter() {
+				JudgeInvites::<T>::insert(&challenge_id, judge, ());
+			}
+
+			Self::deposit_event(Event::JudgesInvited { challenge_id, judges: invited });
+
+			Ok(())
+		}
+
+		// Allows an invited judge to accept, joining the challenge's panel
+		#[pallet::weight(T::WeightInfo::accept_judge_role())]
+		pub fn accept_judge_role(
+			origin: OriginFor<T>,
+			hackathon_id: u32,
+			challenge_id: u16,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let mut challenge =
+				Challenges::<T>::get(&hackathon_id, &challenge_id).ok_or(Error::<T>::ChallengeDoesNotExist)?;
+			ensure!(JudgeInvites::<T>::contains_key(&challenge_id, &who), Error::<T>::NoJudgeInvite);
+			Self::ensure_not_banned([&who])?;
+
+			let mut judges = challenge.judges.take().unwrap_or_default();
+			judges.try_push(who.clone()).map_err(|_| Error::<T>::TooManyMembers)?;
+			challenge.judges = Some(judges);
+			Challenges::<T>::insert(&hackathon_id, &challenge_id, challenge);
+			JudgeInvites::<T>::remove(&challenge_id, &who);
+
+			Self::deposit_event(Event::JudgeAccepted { challenge_id, judge: who });
+
+			Ok(())
+		}
+
+		// Allows a judge to ask for clarification on a solution before voting
+		#[pallet::weight(T::WeightInfo::request_info())]
+		pub fn request_info(
+			origin: OriginFor<T>,
+			hackathon_id: u32,
+			challenge_id: u16,
+			solution_index: u16,
+			note: H256,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let challenge =
+				Challenges::<T>::get(&hackathon_id, &challenge_id).ok_or(Error::<T>::ChallengeDoesNotExist)?;
+			ensure!(Self::is_judge(&challenge, &who), Error::<T>::NotAJudge);
+			ensure!(Self::is_eligible(challenge_id, solution_index), Error::<T>::SolutionDoesNotExist);
+
+			Self::deposit_event(Event::InfoRequested { challenge_id, solution_index, judge: who, note });
+
+			Ok(())
+		}
+
+		// Allows a judge in a hackathon's pool to back extending it by `blocks`, replacing any
+		// extension they backed before. Once a majority of the pool backs the same extension it is
+		// requested from the organizer and the admin origin, and the backing starts over. Requests
+		// close with submissions, after which `update_period` can no longer honor them. Charged
+		// for a full pool since each judge's backing is counted.
+		#[pallet::weight(T::WeightInfo::judges_request_extension(T::MaxEventJudges::get()))]
+		pub fn judges_request_extension(
+			origin: OriginFor<T>,
+			hackathon_id: u32,
+			blocks: T::BlockNumber,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(Hackathons::<T>::contains_key(&hackathon_id), Error::<T>::HackathonDoesNotExist);
+			ensure!(!Self::has_submission_ended(hackathon_id), Error::<T>::SubmissionsAlreadyEnded);
+			ensure!(!blocks.is_zero(), Error::<T>::InvalidPeriod);
+			let pool = EventJudges::<T>::get(&hackathon_id);
+			ensure!(pool.contains(&who), Error::<T>::NotEventJudge);
+			Self::ensure_not_banned([&who])?;
+
+			ExtensionSupport::<T>::insert(&hackathon_id, &who, blocks);
+			// backing left by judges since removed from the pool does not count
+			let supporters = pool
+				.iter()
+				.filter(|judge| ExtensionSupport::<T>::get(&hackathon_id, judge) == Some(blocks))
+				.count() as u32;
+
+			if supporters.saturating_mul(2) > pool.len() as u32 {
+				let _ = ExtensionSupport::<T>::clear_prefix(
+					&hackathon_id,
+					T::MaxEventJudges::get(),
+					None,
+				);
+				Self::deposit_event(Event::ExtensionRequested { hackathon_id, blocks, supporters });
+			} else {
+				Self::deposit_event(Event::ExtensionSupportRecorded {
+					hackathon_id,
+					judge: who,
+					blocks,
+					supporters,
+				});
+			}
+
+			Ok(())
+		}
+
+		// Allows a hackathon's organizer, or the admin origin, to set its submission and voting
+		// windows until submissions end. Both periods must end after the current block, so the
+		// phase end events fire exactly once.
+		#[pallet::weight(T::WeightInfo::update_period())]
+		pub fn update_period(
+			origin: OriginFor<T>,
+			hackathon_id: u32,
+			submission_start: T::BlockNumber,
+			submission_end: T::BlockNumber,
+			vote_start: T::BlockNumber,
+			vote_end: T::BlockNumber,
+		) -> DispatchResult {
+			let mut hackathon = Hackathons::<T>::get(&hackathon_id).ok_or(Error::<T>::HackathonDoesNotExist)?;
+			Self::ensure_organizer(origin, &hackathon)?;
+
+			ensure!(
+				submission_start <= submission_end &&
+					submission_end <= vote_start &&
+					vote_start <= vote_end,
+				Error::<T>::InvalidPeriod
+			);
+			ensure!(
+				vote_end.saturating_sub(submission_start) <= T::MaxHackathonDuration::get(),
+				Error::<T>::HackathonTooLong
+			);
+			// an end already reached would never be announced, and moving one that has been
+			// announced would announce it again
+			ensure!(!Self::has_submission_ended(hackathon_id), Error::<T>::SubmissionsAlreadyEnded);
+			ensure!(
+				submission_end > frame_system::Pallet::<T>::block_number(),
+				Error::<T>::PeriodEndNotInFuture
+			);
+
+			let periods = EventPeriods { submission_start, submission_end, vote_start, vote_end };
+			if let Some(old) = hackathon.periods.replace(periods.clone()) {
+				Self::unschedule_phase_ends(hackathon_id, &old);
+			}
+			Self::schedule_phase_ends(hackathon_id, &periods)?;
+			Hackathons::<T>::insert(&hackathon_id, hackathon);
+
+			Self::deposit_event(Event::PeriodsUpdated { hackathon_id, periods });
+
+			Ok(())
+		}
+
+		// Allows a hackathon's organizer, or the admin origin, to end submissions at the current block
+		// and open voting straight away, once every expected team has submitted
+		#[pallet::weight(T::WeightInfo::close_submissions_early())]
+		pub fn close_submissions_early(origin: OriginFor<T>, hackathon_id: u32) -> DispatchResult {
+			let mut hackathon = Hackathons::<T>::get(&hackathon_id).ok_or(Error::<T>::HackathonDoesNotExist)?;
+			Self::ensure_organizer(origin, &hackathon)?;
+			ensure!(Self::is_submission_open(hackathon_id), Error::<T>::SubmissionPeriodClosed);
+
+			let now = frame_system::Pallet::<T>::block_number();
+			let periods = hackathon.periods.as_mut().ok_or(Error::<T>::SubmissionPeriodClosed)?;
+			SubmissionEnds::<T>::mutate(periods.submission_end, |ids| {
+				ids.retain(|id| *id != hackathon_id)
+			});
+			// voting still ends as planned, which is after `now` as submissions were open
+			periods.submission_end = now;
+			periods.vote_start = now;
+			Hackathons::<T>::insert(&hackathon_id, hackathon);
+
+			// this block's `on_initialize` has already run, so announce the end here
+			Self::deposit_event(Event::SubmissionPhaseEnded { hackathon_id });
+			Self::deposit_event(Event::SubmissionsClosedEarly { hackathon_id, submission_end: now });
+
+			Ok(())
+		}
+
+		// Allows a hackathon's organizer, or the admin origin, to let challenge authors keep editing
+		// after submissions open
+		#[pallet::weight(T::WeightInfo::set_allow_late_edits())]
+		pub fn set_allow_late_edits(
+			origin: OriginFor<T>,
+			hackathon_id: u32,
+			allowed: bool,
+		) -> DispatchResult {
+			let mut hackathon = Hackathons::<T>::get(&hackathon_id).ok_or(Error::<T>::HackathonDoesNotExist)?;
+			Self::ensure_organizer(origin, &hackathon)?;
+
+			hackathon.allow_late_edits = allowed;
+			Hackathons::<T>::insert(&hackathon_id, hackathon);
+
+			Self::deposit_event(Event::LateEditsSet { hackathon_id, allowed });
+
+			Ok(())
+		}
+
+		// Allows a hackathon's organizer, or the admin origin, to allow or forbid bounties posted
+		// for the event from offering a percentage of a team's prize. Bounties already posted keep
+		// their percentage
+		#[pallet::weight(T::WeightInfo::set_allow_ownership_bounties())]
+		pub fn set_allow_ownership_bounties(
+			origin: OriginFor<T>,
+			hackathon_id: u32,
+			allowed: bool,
+		) -> DispatchResult {
+			let mut hackathon = Hackathons::<T>::get(&hackathon_id).ok_or(Error::<T>::HackathonDoesNotExist)?;
+			Self::ensure_organizer(origin, &hackathon)?;
+
+			hackathon.allow_ownership_bounties = allowed;
+			Hackathons::<T>::insert(&hackathon_id, hackathon);
+
+			Self::deposit_event(Event::OwnershipBountiesSet { hackathon_id, allowed });
+
+			Ok(())
+		}
+
+		// Allows a hackathon's organizer, or the admin origin, to attach details such as the
+		// timezone and venue
+		#[pallet::weight(T::WeightInfo::set_hackathon_metadata())]
+		pub fn set_hackathon_metadata(
+			origin: OriginFor<T>,
+			hackathon_id: u32,
+			cid: H256,
+		) -> DispatchResult {
+			let mut hackathon = Hackathons::<T>::get(&hackathon_id).ok_or(Error::<T>::HackathonDoesNotExist)?;
+			Self::ensure_organizer(origin, &hackathon)?;
+
+			hackathon.metadata = Some(cid);
+			Hackathons::<T>::insert(&hackathon_id, hackathon);
+
+			Self::deposit_event(Event::HackathonMetadataSet { hackathon_id, cid });
+
+			Ok(())
+		}
+
+		// Allows a hackathon's organizer, or the admin origin, to hide an invite-only event from
+		// public listings, or list it again
+		#[pallet::weight(T::WeightInfo::set_hackathon_visibility())]
+		pub fn set_hackathon_visibility(
+			origin: OriginFor<T>,
+			hackathon_id: u32,
+			public: bool,
+		) -> DispatchResult {
+			let mut hackathon = Hackathons::<T>::get(&hackathon_id).ok_or(Error::<T>::HackathonDoesNotExist)?;
+			Self::ensure_organizer(origin, &hackathon)?;
+
+			hackathon.public = public;
+			Hackathons::<T>::insert(&hackathon_id, hackathon);
+
+			Self::deposit_event(Event::HackathonVisibilitySet { hackathon_id, public });
+
+			Ok(())
+		}
+
+		// Allows a hackathon's organizer, or the admin origin, to accept challenges for longer
+		// when too few have been posted. The window can only grow and must close before
+		// submissions open.
+		#[pallet::weight(T::WeightInfo::extend_challenge_window())]
+		pub fn extend_challenge_window(
+			origin: OriginFor<T>,
+			hackathon_id: u32,
+			new_deadline: T::BlockNumber,
+		) -> DispatchResult {
+			let mut hackathon = Hackathons::<T>::get(&hackathon_id).ok_or(Error::<T>::HackathonDoesNotExist)?;
+			Self::ensure_organizer(origin, &hackathon)?;
+
+			ensure!(
+				new_deadline > hackathon.challenge_deadline &&
+					hackathon.periods.as_ref().map_or(false, |p| new_deadline < p.submission_start),
+				Error::<T>::InvalidChallengeDeadline
+			);
+
+			hackathon.challenge_deadline = new_deadline;
+			Hackathons::<T>::insert(&hackathon_id, hackathon);
+
+			Self::deposit_event(Event::ChallengeWindowExtended { hackathon_id, deadline: new_deadline });
+
+			Ok(())
+		}
+
+		// Allows the admin origin to change the largest team a hackathon accepts, up to
+		// `MaxMembers`. Teams that already entered the event keep competing at their size.
+		#[pallet::weight(T::WeightInfo::update_team_size())]
+		pub fn update_team_size(origin: OriginFor<T>, hackathon_id: u32, new_max: u32) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			let mut hackathon = Hackathons::<T>::get(&hackathon_id).ok_or(Error::<T>::HackathonDoesNotExist)?;
+			ensure!(new_max > 0 && new_max <= T::MaxMembers::get(), Error::<T>::InvalidTeamSize);
+
+			hackathon.max_team_size = new_max;
+			Hackathons::<T>::insert(&hackathon_id, hackathon);
+
+			Self::deposit_event(Event::TeamSizeUpdated { hackathon_id, max_team_size: new_max });
+
+			Ok(())
+		}
+
+		// Allows a hackathon's organizer, or the admin origin, to replace the event's judge pool.
+		// Repeated accounts are kept once.
+		#[pallet::weight(T::WeightInfo::set_event_judges(judges.len() as u32))]
+		pub fn set_event_judges(
+			origin: OriginFor<T>,
+			hackathon_id: u32,
+			judges: BoundedVec<T::AccountId, T::MaxEventJudges>,
+		) -> DispatchResult {
+			let hackathon = Hackathons::<T>::get(&hackathon_id).ok_or(Error::<T>::HackathonDoesNotExist)?;
+			Self::ensure_organizer(origin, &hackathon)?;
+
+			Self::ensure_not_banned(judges.iter())?;
+			let mut unique = BoundedVec::<T::AccountId, T::MaxEventJudges>::default();
+			for judge in judges {
+				if !unique.contains(&judge) {
+					// cannot fail: `unique` never holds more entries than the input
+					let _ = unique.try_push(judge);
+				}
+			}
+			EventJudges::<T>::insert(&hackathon_id, &unique);
+
+			Self::deposit_event(Event::EventJudgesSet { hackathon_id, judges: unique });
+
+			Ok(())
+		}
+
+		// Allows the admin origin to fill every challenge of a hackathon up to `per_challenge`
+		// judges from the event's pool, before voting starts. The pool is walked in order and the
+		// walk carries on from one challenge to the next, so the load is spread evenly. Judges
+		// already on a panel or invited to it are skipped. Charged for the challenges the
+		// hackathon has.
+		#[pallet::weight(T::WeightInfo::auto_assign_judges(
+			Challenges::<T>::iter_key_prefix(hackathon_id).count() as u32
+		))]
+		pub fn auto_assign_judges(
+			origin: OriginFor<T>,
+			hackathon_id: u32,
+			per_challenge: u32,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			ensure!(Hackathons::<T>::contains_key(&hackathon_id), Error::<T>::HackathonDoesNotExist);
+			ensure!(!Self::has_voting_started(hackathon_id), Error::<T>::VotingAlreadyStarted);
+			ensure!(per_challenge <= T::MaxMembers::get(), Error::<T>::TooManyMembers);
+			// judges barred since the pool was set are left out
+			let pool: Vec<T::AccountId> = EventJudges::<T>::get(&hackathon_id)
+				.into_iter()
+				.filter(|judge| !BannedJudges::<T>::contains_key(judge))
+				.collect();
+			ensure!(!pool.is_empty(), Error::<T>::NoEventJudges);
+
+			let mut challenges: Vec<(u16, Challenge<T>)> =
+				Challenges::<T>::iter_prefix(&hackathon_id).collect();
+			challenges.sort_by_key(|(id, _)| *id);
+			let mut next = 0usize;
+			for (challenge_id, mut challenge) in challenges {
+				let mut judges = challenge.judges.take().unwrap_or_default();
+				// each pass over the pool offers every judge once
+				for _ in 0..pool.len() {
+					if judges.len() as u32 >= per_challenge {
+						break
+					}
+					let judge = &pool[next % pool.len()];
+					next = next.wrapping_add(1);
+					if judges.contains(judge) || JudgeInvites::<T>::contains_key(&challenge_id, judge) {
+						continue
+					}
+					judges.try_push(judge.clone()).map_err(|_| Error::<T>::TooManyMembers)?;
+				}
+				challenge.judges = if judges.is_empty() { None } else { Some(judges) };
+				Challenges::<T>::insert(&hackathon_id, &challenge_id, challenge);
+			}
+
+			Self::deposit_event(Event::JudgesAutoAssigned { hackathon_id, per_challenge });
+
+			Ok(())
+		}
+
+		// Allows a judge to vote for a solution, replacing any earlier vote on the challenge.
+		// Judges who are members of a submitting team cannot vote. Charged for a challenge with
+		// every solution slot taken.
+		#[pallet::weight(T::WeightInfo::vote(T::MaxSolutions::get()))]
+		pub fn vote(
+			origin: OriginFor<T>,
+			hackathon_id: u32,
+			challenge_id: u16,
+			solution_id: u16,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let challenge =
+				Challenges::<T>::get(&hackathon_id, &challenge_id).ok_or(Error::<T>::ChallengeDoesNotExist)?;
+			ensure!(
+				!JudgeInvites::<T>::contains_key(&challenge_id, &who),
+				Error::<T>::JudgeInvitePending
+			);
+			ensure!(Self::is_judge(&challenge, &who), Error::<T>::NotAJudge);
+			ensure!(!Self::is_judge_conflicted(challenge_id, &who), Error::<T>::JudgeIsParticipant);
+			ensure!(
+				!VoteDelegations::<T>::contains_key(&challenge_id, &who),
+				Error::<T>::JudgeHasDelegated
+			);
+			ensure!(Self::is_voting_open(hackathon_id), Error::<T>::VotingPeriodClosed);
+			ensure!(Self::is_eligible(challenge_id, solution_id), Error::<T>::SolutionDoesNotExist);
+
+			// a judge changing their vote keeps their place in the count
+			if !Votes::<T>::contains_key(&challenge_id, &who) {
+				Self::record_vote_order(challenge_id, &who)?;
+			}
+			Votes::<T>::insert(&challenge_id, &who, solution_id);
+
+			Self::deposit_event(Event::VoteCast { challenge_id, judge: who, solution_id });
+
+			Ok(())
+		}
+
+		// Allows a judge who has not voted to let another judge's vote count for them too, from the
+		// end of submissions until voting ends. Charged for a challenge with every solution slot
+		// taken.
+		#[pallet::weight(T::WeightInfo::delegate_vote(T::MaxSolutions::get()))]
+		pub fn delegate_vote(
+			origin: OriginFor<T>,
+			hackathon_id: u32,
+			challenge_id: u16,
+			delegate: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let challenge =
+				Challenges::<T>::get(&hackathon_id, &challenge_id).ok_or(Error::<T>::ChallengeDoesNotExist)?;
+			ensure!(Self::is_judge(&challenge, &who), Error::<T>::NotAJudge);
+			ensure!(Self::is_judge(&challenge, &delegate), Error::<T>::NotAJudge);
+			// rosters are final once submissions close, so a delegator found unconflicted here
+			// stays that way and its delegated vote never needs re-checking
+			ensure!(Self::has_submission_ended(hackathon_id), Error::<T>::SubmissionsStillOpen);
+			ensure!(!Self::has_voting_ended(hackathon_id), Error::<T>::VotingPeriodClosed);
+			ensure!(!Self::is_judge_conflicted(challenge_id, &who), Error::<T>::JudgeIsParticipant);
+			ensure!(!Self::is_judge_conflicted(challenge_id, &delegate), Error::<T>::JudgeIsParticipant);
+			// a judge's own vote and a delegated one would otherwise both be counted
+			ensure!(!Votes::<T>::contains_key(&challenge_id, &who), Error::<T>::AlreadyVoted);
+			ensure!(
+				!VoteDelegations::<T>::contains_key(&challenge_id, &who),
+				Error::<T>::JudgeHasDelegated
+			);
+			// one hop only, so following a delegation always ends at a judge who votes
+			ensure!(
+				delegate != who &&
+					!VoteDelegations::<T>::contains_key(&challenge_id, &delegate) &&
+					!VoteDelegations::<T>::iter_prefix_values(&challenge_id).any(|d| d == who),
+				Error::<T>::DelegationCycle
+			);
+
+			Self::record_vote_order(challenge_id, &who)?;
+			VoteDelegations::<T>::insert(&challenge_id, &who, &delegate);
+
+			Self::deposit_event(Event::VoteDelegated { challenge_id, judge: who, delegate });
+
+			Ok(())
+		}
+
+		// Allows a judge to take back their delegated vote, until voting ends
+		#[pallet::weight(T::WeightInfo::revoke_delegation())]
+		pub fn revoke_delegation(
+			origin: OriginFor<T>,
+			hackathon_id: u32,
+			challenge_id: u16,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				Challenges::<T>::contains_key(&hackathon_id, &challenge_id),
+				Error::<T>::ChallengeDoesNotExist
+			);
+			ensure!(!Self::has_voting_ended(hackathon_id), Error::<T>::VotingPeriodClosed);
+			ensure!(
+				VoteDelegations::<T>::contains_key(&challenge_id, &who),
+				Error::<T>::NoDelegation
+			);
+
+			VoteDelegations::<T>::remove(&challenge_id, &who);
+			VoteOrder::<T>::mutate(&challenge_id, |order| order.retain(|judge| *judge != who));
+
+			Self::deposit_event(Event::DelegationRevoked { challenge_id, judge: who });
+
+			Ok(())
+		}
+
+		// Allows anyone to settle a challenge once voting has ended: the solution with the most
+		// votes wins (lowest solution id on a tie) and the reward is split evenly across its members.
+		// A challenge nobody voted on, or whose leader falls short of the quorum, is settled
+		// without a winner and its reward refunded. Charged for a full winning team and a full
+		// set of contributors, and for every solution slot taken since each submission bond is
+		// returned, or slashed if the solution was disqualified.
+		#[pallet::weight(T::WeightInfo::finalize_challenge(
+			T::MaxMembers::get(),
+			T::MaxContributors::get(),
+			T::MaxSolutions::get(),
+		))]
+		pub fn finalize_challenge(
+			origin: OriginFor<T>,
+			hackathon_id: u32,
+			challenge_id: u16,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let challenge =
+				Challenges::<T>::get(&hackathon_id, &challenge_id).ok_or(Error::<T>::ChallengeDoesNotExist)?;
+			ensure!(Self::has_submission_ended(hackathon_id), Error::<T>::SubmissionsStillOpen);
+			ensure!(Self::has_voting_ended(hackathon_id), Error::<T>::VotingNotEnded);
+			ensure!(!Self::is_finalized(challenge_id), Error::<T>::ChallengeAlreadyFinalized);
+			ensure!(!EscalatedDisputes::<T>::contains_key(&challenge_id), Error::<T>::PayoutFrozen);
+
+			// votes can no longer be cast, so a challenge without a backed winner never gets one
+			let winning_solution = match Self::outcome(hackathon_id, challenge_id, &challenge) {
+				Ok(winning_solution) => winning_solution,
+				Err(reason) => {
+					let held = challenge.reward.saturating_sub(challenge.outstanding);
+					Self::refund(challenge_id, &challenge.funder, held)?;
+					Self::settle_submission_bonds(challenge_id);
+					Self::settle_votes(challenge_id);
+					UnawardedChallenges::<T>::insert(&challenge_id, reason);
+					Self::deposit_event(Event::ChallengeFinalizationFailed { challenge_id, reason });
+					return Ok(())
+				},
+			};
+
+			let SubmittedSolution { team_id, members: winners, .. } =
+				ChallengeSolutions::<T>::get(&challenge_id, &winning_solution)
+					.ok_or(Error::<T>::SolutionDoesNotExist)?;
+
+			let reward_id = (challenge_id, None);
+			Self::pay_out(&challenge.funder, challenge.reward, team_id, &winners, reward_id)?;
+			Self::settle_submission_bonds(challenge_id);
+			// recorded before the winner, which is what marks the challenge settled
+			Self::settle_votes(challenge_id);
+			ChallengeWinners::<T>::insert(&challenge_id, winning_solution);
+			let _ = ChallengeContributions::<T>::clear_prefix(
+				&challenge_id,
+				T::MaxContributors::get(),
+				None,
+			);
+			for winner in winners.iter() {
+				Self::add_reputation(winner, WIN_REPUTATION);
+			}
+
+			Self::deposit_event(Event::ChallengeFinalized { challenge_id, winning_solution, winners });
+
+			Ok(())
+		}
+
+		// Allows a judge on a prize's panel to vote for the solution that should win it, replacing
+		// any earlier vote on the prize. Votes on prizes and on the main reward are independent.
+		// Charged for a challenge with every solution slot taken.
+		#[pallet::weight(T::WeightInfo::vote_prize(T::MaxSolutions::get()))]
+		pub fn vote_prize(
+			origin: OriginFor<T>,
+			hackathon_id: u32,
+			challenge_id: u16,
+			prize_id: u16,
+			solution_id: u16,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				Challenges::<T>::contains_key(&hackathon_id, &challenge_id),
+				Error::<T>::ChallengeDoesNotExist
+			);
+			let prize =
+				ChallengePrizes::<T>::get(&challenge_id, &prize_id).ok_or(Error::<T>::PrizeDoesNotExist)?;
+			ensure!(
+				prize.judges.contains(&who) && !BannedJudges::<T>::contains_key(&who),
+				Error::<T>::NotAJudge
+			);
+			ensure!(!Self::is_judge_conflicted(challenge_id, &who), Error::<T>::JudgeIsParticipant);
+			ensure!(Self::is_voting_open(hackathon_id), Error::<T>::VotingPeriodClosed);
+			ensure!(Self::is_eligible(challenge_id, solution_id), Error::<T>::SolutionDoesNotExist);
+
+			PrizeVotes::<T>::insert((challenge_id, prize_id), &who, solution_id);
+
+			Self::deposit_event(Event::PrizeVoteCast { challenge_id, prize_id, judge: who, solution_id });
+
+			Ok(())
+		}
+
+		// Allows anyone to settle a prize once voting has ended, exactly as `finalize_challenge`
+		// settles the main reward but on the prize panel's votes alone. A prize whose leader falls
+		// short of the quorum releases its reward to the funder. Charged for a full winning team.
+		#[pallet::weight(T::WeightInfo::finalize_prize(T::MaxMembers::get()))]
+		pub fn finalize_prize(
+			origin: OriginFor<T>,
+			hackathon_id: u32,
+			challenge_id: u16,
+			prize_id: u16,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			ensure!(
+				Challenges::<T>::contains_key(&hackathon_id, &challenge_id),
+				Error::<T>::ChallengeDoesNotExist
+			);
+			let mut prize =
+				ChallengePrizes::<T>::get(&challenge_id, &prize_id).ok_or(Error::<T>::PrizeDoesNotExist)?;
+			ensure!(Self::has_voting_ended(hackathon_id), Error::<T>::VotingNotEnded);
+			ensure!(!prize.settled, Error::<T>::PrizeAlreadyFinalized);
+			ensure!(!EscalatedDisputes::<T>::contains_key(&challenge_id), Error::<T>::PayoutFrozen);
+
+			let mut counts = BTreeMap::<u16, u32>::new();
+			// votes on a solution disqualified since they were cast count for nothing
+			for solution_id in PrizeVotes::<T>::iter_prefix_values((challenge_id, prize_id))
+				.filter(|solution_id| Self::is_eligible(challenge_id, *solution_id))
+			{
+				*counts.entry(solution_id).or_default() += 1;
+			}
+			// only replacing on strictly more votes keeps the lowest id on a tie
+			let leader = counts.into_iter().fold(None, |leader, (solution_id, votes)| match leader {
+				Some((_, best)) if best >= votes => leader,
+				_ => Some((solution_id, votes)),
+			});
+			let required = T::JudgeQuorum::get().mul_ceil(prize.judges.len() as u32);
+			let winner = leader
+				.filter(|(_, votes)| *votes >= required)
+				.and_then(|(solution_id, _)| {
+					ChallengeSolutions::<T>::get(&challenge_id, &solution_id)
+						.map(|solution| (solution_id, solution.team_id, solution.members))
+				});
+
+			let winning_solution = match winner {
+				Some((solution_id, team_id, members)) => {
+					Self::pay_out(
+						&prize.funder,
+						prize.reward,
+						team_id,
+						&members,
+						(challenge_id, Some(prize_id)),
+					)?;
+					Some(solution_id)
+				},
+				None => {
+					T::RewardCurrency::release(&prize.funder, prize.reward, true)?;
+					None
+				},
+			};
+			prize.settled = true;
+			prize.winner = winning_solution;
+			ChallengePrizes::<T>::insert(&challenge_id, &prize_id, prize);
+			let _ = PrizeVotes::<T>::clear_prefix((challenge_id, prize_id), T::MaxMembers::get(), None);
+
+			Self::deposit_event(Event::PrizeFinalized { challenge_id, prize_id, winning_solution });
+
+			Ok(())
+		}
+
+		// Allows a winner to release what has vested so far of their share of a challenge's main
+		// reward, or of one of its prizes if `prize_id` is given. The share is released once
+		// `VestingDuration` blocks have passed since it was settled, and proportionally before.
+		#[pallet::weight(T::WeightInfo::claim_vested())]
+		pub fn claim_vested(
+			origin: OriginFor<T>,
+			challenge_id: u16,
+			prize_id: Option<u16>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let reward_id = (challenge_id, prize_id);
+			let mut schedule = VestingSchedules::<T>::get(&who, reward_id)
+				.ok_or(Error::<T>::NoVestingSchedule)?;
+			let vested = Self::vested(&schedule, <frame_system::Pallet<T>>::block_number());
+			let amount = vested.saturating_sub(schedule.released);
+			ensure!(!amount.is_zero(), Error::<T>::NothingVested);
+
+			T::RewardCurrency::release(&who, amount, true)?;
+			schedule.released = vested;
+			if schedule.released >= schedule.total {
+				VestingSchedules::<T>::remove(&who, reward_id);
+			} else {
+				VestingSchedules::<T>::insert(&who, reward_id, schedule);
+			}
+
+			Self::deposit_event(Event::VestedRewardReleased { who, challenge_id, prize_id, amount });
+
+			Ok(())
+		}
+
+		// Allows a member of a team that submitted to a challenge to contest how it is being
+		// judged, such as the organizer disqualifying the team's solution. Nothing about the
+		// challenge is settled until root rules with `resolve_dispute`. Charged for a challenge
+		// with every solution slot taken since each is searched for the caller's team.
+		#[pallet::weight(T::WeightInfo::escalate_dispute(T::MaxSolutions::get()))]
+		pub fn escalate_dispute(origin: OriginFor<T>, challenge_id: u16) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(!Self::is_finalized(challenge_id), Error::<T>::ChallengeAlreadyFinalized);
+			ensure!(
+				!EscalatedDisputes::<T>::contains_key(&challenge_id),
+				Error::<T>::DisputeAlreadyEscalated
+			);
+			// drafts were never entered, so their teams have nothing to contest yet
+			let (solution_id, team_id) = ChallengeSolutions::<T>::iter_prefix(&challenge_id)
+				.find(|(_, solution)| !solution.draft && solution.members.contains(&who))
+				.map(|(solution_id, solution)| (solution_id, solution.team_id))
+				.ok_or(Error::<T>::NotChallengeParticipant)?;
+
+			EscalatedDisputes::<T>::insert(&challenge_id, (team_id, solution_id));
+
+			Self::deposit_event(Event::DisputeEscalated { challenge_id, team_id });
+
+			Ok(())
+		}
+
+		// Allows root to rule on an escalated dispute. Reinstating lifts a disqualification of
+		// the escalating team's solution; either way the challenge can be settled again.
+		#[pallet::weight(T::WeightInfo::resolve_dispute())]
+		pub fn resolve_dispute(
+			origin: OriginFor<T>,
+			challenge_id: u16,
+			reinstate: bool,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+
+			let (_, solution_id) =
+				EscalatedDisputes::<T>::take(&challenge_id).ok_or(Error::<T>::DisputeNotEscalated)?;
+			if reinstate {
+				// the solution may have been withdrawn while the dispute was open
+				ChallengeSolutions::<T>::mutate(&challenge_id, &solution_id, |solution| {
+					if let Some(solution) = solution {
+						solution.disqualified = false;
+					}
+				});
+			}
+
+			Self::deposit_event(Event::DisputeResolved { challenge_id, reinstated: reinstate });
+
+			Ok(())
+		}
+
+		// Allows the admin origin to archive a challenge once it and its prizes are settled,
+		// keeping only who won and what was paid. Charged for a challenge with every solution slot
+		// taken, since each solution and the voters recorded for it are removed.
+		#[pallet::weight(T::WeightInfo::archive_challenge(T::MaxSolutions::get()))]
+		pub fn archive_challenge(
+			origin: OriginFor<T>,
+			hackathon_id: u32,
+			challenge_id: u16,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			let challenge =
+				Challenges::<T>::get(&hackathon_id, &challenge_id).ok_or(Error::<T>::ChallengeDoesNotExist)?;
+			// a dispute can only be escalated before the challenge is settled
+			ensure!(Self::is_finalized(challenge_id), Error::<T>::ChallengeNotSettled);
+			ensure!(
+				ChallengePrizes::<T>::iter_prefix_values(&challenge_id).all(|prize| prize.settled),
+				Error::<T>::ChallengeNotSettled
+			);
+
+			let winner = ChallengeWinners::<T>::take(&challenge_id)
+				.and_then(|solution_id| ChallengeSolutions::<T>::get(&challenge_id, &solution_id));
+			let archive = match winner {
+				Some(solution) => {
+					let (shares, _) =
+						Self::reward_shares(challenge.reward, solution.team_id, &solution.members);
+					let paid = shares
+						.into_iter()
+						.fold(Zero::zero(), |paid: BalanceOf<T>, (_, share)| paid.saturating_add(share));
+					ChallengeArchive::<T> { winner: Some(solution.team_id), reward: paid, hackathon_id }
+				},
+				None => ChallengeArchive::<T> { winner: None, reward: challenge.reward, hackathon_id },
+			};
+
+			Challenges::<T>::remove(&hackathon_id, &challenge_id);
+			UnawardedChallenges::<T>::remove(&challenge_id);
+			NextSolutionId::<T>::remove(&challenge_id);
+			let _ = ChallengeSolutions::<T>::clear_prefix(&challenge_id, T::MaxSolutions::get(), None);
+			let _ = SettledVoters::<T>::clear_prefix(&challenge_id, T::MaxSolutions::get(), None);
+			let _ = ChallengePrizes::<T>::clear_prefix(&challenge_id, T::MaxPrizes::get(), None);
+			let _ = JudgeInvites::<T>::clear_prefix(&challenge_id, T::MaxMembers::get(), None);
+			ChallengeArchives::<T>::insert(&challenge_id, archive);
+
+			Self::deposit_event(Event::ChallengeArchived { hackathon_id, challenge_id });
+
+			Ok(())
+		}
+
+		// Allows a member of the team that won a challenge to credit contributors paid off-chain,
+		// by the hash of whatever identifies them, with a percentage of the prize each. Nothing
+		// is paid or stored; the event is the record.
+		#[pallet::weight(T::WeightInfo::acknowledge_contributors(contributors.len() as u32))]
+		pub fn acknowledge_contributors(
+			origin: OriginFor<T>,
+			challenge_id: u16,
+			contributors: BoundedVec<(H256, u8), T::MaxMembers>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			// archiving keeps the winning team, so its contributors can still be acknowledged
+			let team_id = match ChallengeWinners::<T>::get(&challenge_id) {
+				Some(solution_id) =>
+					ChallengeSolutions::<T>::get(&challenge_id, &solution_id).map(|solution| solution.team_id),
+				None => ChallengeArchives::<T>::get(&challenge_id).and_then(|archive| archive.winner),
+			}
+			.ok_or(Error::<T>::NoWinningTeam)?;
+			let team = Teams::<T>::get(&team_id).ok_or(Error::<T>::TeamDoesNotExist)?;
+			ensure!(team.members.contains(&who), Error::<T>::NotATeamMember);
+			let total = contributors
+				.iter()
+				.try_fold(0u8, |total, (_, percentage)| total.checked_add(*percentage))
+				.filter(|total| *total <= 100);
+			ensure!(total.is_some(), Error::<T>::InvalidPercentage);
+
+			Self::deposit_event(Event::ContributorsAcknowledged {
+				challenge_id,
+				team_id,
+				contributors: contributors.into_inner(),
+			});
+
+			Ok(())
+		}
+
+		// Allows a participant to register a team, with themselves as founder. The other members
+		// are invited and only join the roster once they accept with `accept_team_invite`.
+		#[pallet::weight(T::WeightInfo::create_team(members.len() as u32))]
+		pub fn create_team(origin: OriginFor<T>, members: Vec<T::AccountId>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			// the founder is always a member, and nobody is invited twice
+			let mut invited = Vec::with_capacity(members.len());
+			for member in members {
+				if member != who && !invited.contains(&member) {
+					invited.push(member);
+				}
+			}
+			// pending invites count towards the team size, as accepting them must not overflow it
+			ensure!((invited.len() as u32) < T::MaxMembers::get(), Error::<T>::TooManyMembers);
+			let members: BoundedVec<T::AccountId, T::MaxMembers> =
+				vec![who.clone()].try_into().map_err(|_| Error::<T>::TooManyMembers)?;
+
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(
+				LastTeamCreation::<T>::get(&who)
+					.map_or(true, |last| now >= last.saturating_add(T::TeamCreationCooldown::get())),
+				Error::<T>::TeamCreationCooldownActive
+			);
+			let bond = T::TeamBond::get();
+			ensure!(T::Deposit::can_reserve(&who, bond), Error::<T>::InsufficientBalanceForTeamBond);
+
+			let team_id = NextTeamId::<T>::get();
+			let next_team_id = team_id.checked_add(1).ok_or(Error::<T>::TeamIdOverflow)?;
+
+			T::Deposit::reserve(&who, bond)?;
+
+			Teams::<T>::insert(
+				team_id,
+				Team::<T> {
+					team_founder: who.clone(),
+					team_id,
+					members,
+					category: None,
+					bond,
+					captain: None,
+					private_roster: false,
+					required_bounty_approvals: 1,
+				},
+			);
+			NextTeamId::<T>::put(next_team_id);
+			LastTeamCreation::<T>::insert(&who, now);
+			FounderActivity::<T>::insert(&team_id, now);
+
+			Self::deposit_event(Event::TeamCreated { team_id, founder: who });
+			for member in invited {
+				TeamInvites::<T>::insert(&team_id, &member, ());
+				Self::deposit_event(Event::MemberInvited { team_id, member });
+			}
+
+			Ok(())
+		}
+
+		// Allows a team member to invite another account, who joins once they accept
+		#[pallet::weight(T::WeightInfo::add_member())]
+		pub fn add_member(
+			origin: OriginFor<T>,
+			team_id: u32,
+			new_member: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let team = Teams::<T>::get(&team_id).ok_or(Error::<T>::TeamDoesNotExist)?;
+			ensure!(team.members.contains(&who), Error::<T>::NotATeamMember);
+			ensure!(
+				!team.members.contains(&new_member) &&
+					!TeamInvites::<T>::contains_key(&team_id, &new_member),
+				Error::<T>::AlreadyTeamMember
+			);
+			let seats = (team.members.len() as u32)
+				.saturating_add(TeamInvites::<T>::iter_prefix(&team_id).count() as u32);
+			ensure!(seats < T::MaxMembers::get(), Error::<T>::TooManyMembers);
+			// so do the limits of every event the team still competes in
+			for hackathon_id in TeamHackathons::<T>::iter_key_prefix(&team_id) {
+				if let Some(hackathon) = Hackathons::<T>::get(&hackathon_id) {
+					ensure!(
+						Self::has_submission_ended(hackathon_id) || seats < hackathon.max_team_size,
+						Error::<T>::TeamTooLarge
+					);
+				}
+			}
+
+			Self::note_activity(&team, &who);
+			TeamInvites::<T>::insert(&team_id, &new_member, ());
+
+			Self::deposit_event(Event::MemberInvited { team_id, member: new_member });
+
+			Ok(())
+		}
+
+		// Allows an invited account to accept, joining the team's roster
+		#[pallet::weight(T::WeightInfo::accept_team_invite())]
+		pub fn accept_team_invite(origin: OriginFor<T>, team_id: u32) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(TeamInvites::<T>::contains_key(&team_id, &who), Error::<T>::NoTeamInvite);
+			Teams::<T>::try_mutate(team_id, |maybe_team| -> DispatchResult {
+				let team = maybe_team.as_mut().ok_or(Error::<T>::TeamDoesNotExist)?;
+				team.members.try_push(who.clone()).map_err(|_| Error::<T>::TooManyMembers)?;
+				Ok(())
+			})?;
+			TeamInvites::<T>::remove(&team_id, &who);
+
+			Self::deposit_event(Event::MemberAdded { team_id, member: who });
+
+			Ok(())
+		}
+
+		// Allows the team founder to set or clear the category (track) the team competes in
+		#[pallet::weight(T::WeightInfo::set_team_category())]
+		pub fn set_team_category(
+			origin: OriginFor<T>,
+			team_id: u32,
+			category: Option<u16>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let mut team = Teams::<T>::get(&team_id).ok_or(Error::<T>::TeamDoesNotExist)?;
+			ensure!(team.team_founder == who, Error::<T>::NotTeamFounder);
+
+			Self::note_activity(&team, &who);
+			team.category = category;
+			Teams::<T>::insert(&team_id, team);
+
+			Self::deposit_event(Event::TeamCategorySet { team_id, category });
+
+			Ok(())
+		}
+
+		// Allows the team founder to name a member captain, or to clear the role. The captain
+		// posts and approves bounties alongside the founder.
+		#[pallet::weight(T::WeightInfo::set_team_captain())]
+		pub fn set_team_captain(
+			origin: OriginFor<T>,
+			team_id: u32,
+			captain: Option<T::AccountId>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let mut team = Teams::<T>::get(&team_id).ok_or(Error::<T>::TeamDoesNotExist)?;
+			ensure!(team.team_founder == who, Error::<T>::NotTeamFounder);
+			if let Some(captain) = &captain {
+				ensure!(team.members.contains(captain), Error::<T>::NotATeamMember);
+			}
+
+			Self::note_activity(&team, &who);
+			team.captain = captain.clone();
+			Teams::<T>::insert(&team_id, team);
+
+			Self::deposit_event(Event::TeamCaptainSet { team_id, captain });
+
+			Ok(())
+		}
+
+		// Allows the team founder to hide the team's roster from `team_members` until the voting
+		// of the hackathon asked about has ended, or to make it public again
+		#[pallet::weight(T::WeightInfo::set_private_roster())]
+		pub fn set_private_roster(origin: OriginFor<T>, team_id: u32, private: bool) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let mut team = Teams::<T>::get(&team_id).ok_or(Error::<T>::TeamDoesNotExist)?;
+			ensure!(team.team_founder == who, Error::<T>::NotTeamFounder);
+
+			Self::note_activity(&team, &who);
+			team.private_roster = private;
+			Teams::<T>::insert(&team_id, team);
+
+			Self::deposit_event(Event::RosterPrivacySet { team_id, private });
+
+			Ok(())
+		}
+
+		// Allows the team founder to require more than one approval, from the founder and the
+		// captain, before a bounty or milestone is paid. One or zero lets either pay alone.
+		#[pallet::weight(T::WeightInfo::set_required_bounty_approvals())]
+		pub fn set_required_bounty_approvals(
+			origin: OriginFor<T>,
+			team_id: u32,
+			approvals: u32,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let mut team = Teams::<T>::get(&team_id).ok_or(Error::<T>::TeamDoesNotExist)?;
+			ensure!(team.team_founder == who, Error::<T>::NotTeamFounder);
+
+			Self::note_activity(&team, &who);
+			team.required_bounty_approvals = approvals;
+			Teams::<T>::insert(&team_id, team);
+
+			Self::deposit_event(Event::RequiredBountyApprovalsSet { team_id, approvals });
+
+			Ok(())
+		}
+
+		// Allows the team founder to disband the team, unreserving its bond. Bounties need the
+		// roster to be settled, so the team cannot disband while any are open or claimed.
+		// Solutions already submitted keep the roster they were submitted with.
+		#[pallet::weight(T::WeightInfo::disband_team(T::MaxMembers::get()))]
+		pub fn disband_team(origin: OriginFor<T>, team_id: u32) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let team = Teams::<T>::get(&team_id).ok_or(Error::<T>::TeamDoesNotExist)?;
+			ensure!(team.team_founder == who, Error::<T>::NotTeamFounder);
+			ensure!(PendingBounties::<T>::get(&team_id) == 0, Error::<T>::TeamHasPendingBounties);
+
+			T::Deposit::unreserve(&who, team.bond);
+			Teams::<T>::remove(&team_id);
+			let _ = TeamInvites::<T>::clear_prefix(&team_id, T::MaxMembers::get(), None);
+			TeamTargets::<T>::remove(&team_id);
+			GrantedOwnership::<T>::remove(&team_id);
+			FounderActivity::<T>::remove(&team_id);
+
+			Self::deposit_event(Event::TeamDisbanded { team_id, bond: team.bond });
+
+			Ok(())
+		}
+
+		// Allows a member to take over as founder once the founder has not acted for the team in
+		// `FounderInactivityTimeout` blocks. The claimant reserves the team bond in the old
+		// founder's place, and the old founder gets theirs back and stays a member.
+		#[pallet::weight(T::WeightInfo::claim_founder())]
+		pub fn claim_founder(origin: OriginFor<T>, team_id: u32) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let mut team = Teams::<T>::get(&team_id).ok_or(Error::<T>::TeamDoesNotExist)?;
+			ensure!(team.members.contains(&who), Error::<T>::NotATeamMember);
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(
+				team.team_founder != who &&
+					now >= FounderActivity::<T>::get(&team_id)
+						.saturating_add(T::FounderInactivityTimeout::get()),
+				Error::<T>::FounderStillActive
+			);
+			ensure!(T::Deposit::can_reserve(&who, team.bond), Error::<T>::InsufficientBalanceForTeamBond);
+
+			T::Deposit::reserve(&who, team.bond)?;
+			T::Deposit::unreserve(&team.team_founder, team.bond);
+			team.team_founder = who.clone();
+			Teams::<T>::insert(&team_id, team);
+			FounderActivity::<T>::insert(&team_id, now);
+
+			Self::deposit_event(Event::FounderClaimed { team_id, founder: who });
+
+			Ok(())
+		}
+
+		// Allows the admin origin to slash a team's bond for abuse. The team itself stays.
+		#[pallet::weight(T::WeightInfo::slash_team_bond())]
+		pub fn slash_team_bond(origin: OriginFor<T>, team_id: u32) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			let mut team = Teams::<T>::get(&team_id).ok_or(Error::<T>::TeamDoesNotExist)?;
+
+			let (_, unslashed) = T::Deposit::slash_reserved(&team.team_founder, team.bond);
+			let amount = team.bond.saturating_sub(unslashed);
+			team.bond = Zero::zero();
+			Teams::<T>::insert(&team_id, team);
+
+			Self::deposit_event(Event::TeamBondSlashed { team_id, amount });
+
+			Ok(())
+		}
+
+		// Allows a team member to declare which challenges the team is aiming for,
+		// replacing any earlier declaration
+		#[pallet::weight(T::WeightInfo::declare_targets())]
+		pub fn declare_targets(
+			origin: OriginFor<T>,
+			team_id: u32,
+			hackathon_id: u32,
+			challenge_ids: BoundedVec<u16, T::MaxTargets>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let team = Teams::<T>::get(&team_id).ok_or(Error::<T>::TeamDoesNotExist)?;
+			ensure!(team.members.contains(&who), Error::<T>::NotATeamMember);
+			ensure!(
+				challenge_ids.iter().all(|id| Challenges::<T>::contains_key(&hackathon_id, id)),
+				Error::<T>::ChallengeDoesNotExist
+			);
+
+			Self::note_activity(&team, &who);
+			TeamTargets::<T>::insert(&team_id, &challenge_ids);
+
+			Self::deposit_event(Event::TargetsDeclared { team_id, challenge_ids });
+
+			Ok(())
+		}
+
+		// Allows the team founder to enter an existing team into a hackathon before its
+		// submissions end, held to that event's size limit from then on. Nobody on the event's
+		// judge pool can compete in it. Submitting enters a team too; entering first fixes the
+		// roster against later changes to the limit.
+		#[pallet::weight(T::WeightInfo::enter_hackathon())]
+		pub fn enter_hackathon(origin: OriginFor<T>, team_id: u32, hackathon_id: u32) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let team = Teams::<T>::get(&team_id).ok_or(Error::<T>::TeamDoesNotExist)?;
+			ensure!(team.team_founder == who, Error::<T>::NotTeamFounder);
+			let hackathon = Hackathons::<T>::get(&hackathon_id).ok_or(Error::<T>::HackathonDoesNotExist)?;
+			ensure!(
+				!TeamHackathons::<T>::contains_key(&team_id, &hackathon_id),
+				Error::<T>::AlreadyEntered
+			);
+			ensure!(!Self::has_submission_ended(hackathon_id), Error::<T>::SubmissionsAlreadyEnded);
+			ensure!(team.members.len() as u32 <= hackathon.max_team_size, Error::<T>::TeamTooLarge);
+			let pool = EventJudges::<T>::get(&hackathon_id);
+			ensure!(
+				!team.members.iter().any(|member| pool.contains(member)),
+				Error::<T>::JudgeIsParticipant
+			);
+
+			Self::note_activity(&team, &who);
+			TeamHackathons::<T>::insert(&team_id, &hackathon_id, ());
+
+			Self::deposit_event(Event::TeamEntered { team_id, hackathon_id });
+
+			Ok(())
+		}
+
+		// Allows a team member to post a bounty for a hackathon, reserving its amount until it is
+		// approved. Only hackathons allowing ownership bounties accept a percentage
+		#[pallet::weight(T::WeightInfo::post_bounty())]
+		pub fn post_bounty(
+			origin: OriginFor<T>,
+			team_id: u32,
+			hackathon_id: u32,
+			description: H256,
+			amount: BalanceOf<T>,
+			expiry: T::BlockNumber,
+			percentage: u8,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let team = Teams::<T>::get(&team_id).ok_or(Error::<T>::TeamDoesNotExist)?;
+			ensure!(team.members.contains(&who), Error::<T>::NotATeamMember);
+			ensure!(Self::is_bounty_manager(&team, &who), Error::<T>::NotBountyManager);
+			ensure!(percentage <= 100, Error::<T>::InvalidPercentage);
+			let step = T::PercentageStep::get();
+			ensure!(step == 0 || percentage % step == 0, Error::<T>::InvalidPercentageStep);
+			ensure!(!amount.is_zero() || percentage > 0, Error::<T>::EmptyBounty);
+			let hackathon = Hackathons::<T>::get(&hackathon_id).ok_or(Error::<T>::HackathonDoesNotExist)?;
+			ensure!(
+				percentage == 0 || hackathon.allow_ownership_bounties,
+				Error::<T>::OwnershipBountiesDisabled
+			);
+			ensure!(
+				expiry > frame_system::Pallet::<T>::block_number(),
+				Error::<T>::ExpiryNotInFuture
+			);
+			ensure!(T::Deposit::can_reserve(&who, amount), Error::<T>::InsufficientBalance);
+
+			let bounty_id = NextBountyId::<T>::get();
+			let next_bounty_id = bounty_id.checked_add(1).ok_or(Error::<T>::BountyIdOverflow)?;
+
+			T::Deposit::reserve(&who, amount)?;
+			Self::note_activity(&team, &who);
+
+			let bounty = Bounty::<T> {
+				poster: who.clone(),
+				team_id,
+				hackathon_id,
+				description,
+				amount,
+				expiry,
+				duration: expiry.saturating_sub(frame_system::Pallet::<T>::block_number()),
+				percentage,
+				claimant: None,
+				solution: None,
+				status: BountyStatus::Open,
+				pending_reject: false,
+				milestones: BoundedVec::default(),
+				milestones_paid: 0,
+			};
+			Bounties::<T>::insert(bounty_id, bounty);
+			NextBountyId::<T>::put(next_bounty_id);
+			PendingBounties::<T>::mutate(&team_id, |pending| *pending = pending.saturating_add(1));
+
+			Self::deposit_event(Event::BountyPosted { bounty_id, team_id, poster: who });
+
+			Ok(())
+		}
+
+		// Allows a buidler outside the posting team to claim an open bounty with their solution
+		#[pallet::weight(T::WeightInfo::claim_bounty())]
+		pub fn claim_bounty(origin: OriginFor<T>, bounty_id: u32, solution: H256) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Bounties::<T>::try_mutate(&bounty_id, |maybe_bounty| -> DispatchResult {
+				let bounty = maybe_bounty.as_mut().ok_or(Error::<T>::BountyDoesNotExist)?;
+				// a team approving its own claims would mint reputation
+				let team = Teams::<T>::get(&bounty.team_id).ok_or(Error::<T>::TeamDoesNotExist)?;
+				ensure!(
+					bounty.poster != who && !team.members.contains(&who),
+					Error::<T>::ClaimantIsTeamMember
+				);
+				ensure!(bounty.status == BountyStatus::Open, Error::<T>::BountyAlreadyClaimed);
+				ensure!(
+					frame_system::Pallet::<T>::block_number() < bounty.expiry,
+					Error::<T>::BountyExpired
+				);
+				ensure!(T::ProofVerifier::verify(&solution, bounty_id, &who), Error::<T>::ProofInvalid);
+
+				bounty.claimant = Some(who.clone());
+				bounty.solution = Some(solution);
+				bounty.status = BountyStatus::Claimed;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::BountyClaimed { bounty_id, claimant: who, solution });
+
+			Ok(())
+		}
+
+		// Allows a bounty's claimant to point their claim at a new solution until it is approved
+		#[pallet::weight(T::WeightInfo::update_claim_solution())]
+		pub fn update_claim_solution(
+			origin: OriginFor<T>,
+			bounty_id: u32,
+			new_solution: H256,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Bounties::<T>::try_mutate(&bounty_id, |maybe_bounty| -> DispatchResult {
+				let bounty = maybe_bounty.as_mut().ok_or(Error::<T>::BountyDoesNotExist)?;
+				ensure!(bounty.status == BountyStatus::Claimed, Error::<T>::BountyNotClaimed);
+				ensure!(bounty.claimant.as_ref() == Some(&who), Error::<T>::NotBountyClaimant);
+				ensure!(T::ProofVerifier::verify(&new_solution, bounty_id, &who), Error::<T>::ProofInvalid);
+
+				bounty.solution = Some(new_solution);
+				Ok(())
+			})?;
+			// approvals were given for the old solution
+			let _ = BountyApprovals::<T>::clear_prefix(&bounty_id, T::MaxMembers::get(), None);
+
+			Self::deposit_event(Event::ClaimSolutionUpdated {
+				bounty_id,
+				claimant: who,
+				solution: new_solution,
+			});
+
+			Ok(())
+		}
+
+		// Allows a team member to approve a claimed bounty nobody on the team has rejected, paying
+		// its amount to the claimant once the team's required approvals are in. The team can
+		// grant at most 100% of its prize overall.
+		#[pallet::weight(T::WeightInfo::approve_bounty())]
+		pub fn approve_bounty(origin: OriginFor<T>, bounty_id: u32) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let mut bounty = Bounties::<T>::get(&bounty_id).ok_or(Error::<T>::BountyDoesNotExist)?;
+			let team = Teams::<T>::get(&bounty.team_id).ok_or(Error::<T>::TeamDoesNotExist)?;
+			ensure!(team.members.contains(&who), Error::<T>::NotATeamMember);
+			ensure!(Self::is_bounty_manager(&team, &who), Error::<T>::NotBountyManager);
+			ensure!(bounty.status == BountyStatus::Claimed, Error::<T>::BountyNotClaimed);
+			// a claim a member has objected to is settled by the rejection vote, not by approval
+			ensure!(!bounty.pending_reject, Error::<T>::BountyRejectionPending);
+			let claimant = bounty.claimant.clone().ok_or(Error::<T>::BountyNotClaimed)?;
+
+			Self::note_activity(&team, &who);
+			if Self::record_approval(&team, bounty_id, &who, None)? {
+				Self::approve(bounty_id, bounty, claimant)?;
+			}
+
+			Ok(())
+		}
+
+		// Allows the poster of an unclaimed bounty to split its amount into milestones, each
+		// paid on its own approval. The milestone amounts must add up to the bounty's amount.
+		// An empty list makes it a single payment again.
+		#[pallet::weight(T::WeightInfo::set_bounty_milestones(milestones.len() as u32))]
+		pub fn set_bounty_milestones(
+			origin: OriginFor<T>,
+			bounty_id: u32,
+			milestones: BoundedVec<(H256, BalanceOf<T>), T::MaxMilestones>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let mut bounty = Bounties::<T>::get(&bounty_id).ok_or(Error::<T>::BountyDoesNotExist)?;
+			ensure!(bounty.poster == who, Error::<T>::NotBountyPoster);
+			ensure!(bounty.status == BountyStatus::Open, Error::<T>::BountyAlreadyClaimed);
+			ensure!(bounty.milestones_paid == 0, Error::<T>::MilestonesAlreadyPaid);
+			let total = milestones
+				.iter()
+				.fold(BalanceOf::<T>::zero(), |total, (_, amount)| total.saturating_add(*amount));
+			ensure!(milestones.is_empty() || total == bounty.amount, Error::<T>::MilestonesMismatch);
+
+			bounty.milestones = milestones.clone();
+			Bounties::<T>::insert(&bounty_id, bounty);
+
+			Self::deposit_event(Event::BountyMilestonesSet {
+				bounty_id,
+				milestones: milestones.into_inner(),
+			});
+
+			Ok(())
+		}
+
+		// Allows a team member to pay the next milestone of a claimed bounty to its claimant.
+		// Milestones are paid in order, and approving the last one approves the bounty itself.
+		#[pallet::weight(T::WeightInfo::approve_milestone())]
+		pub fn approve_milestone(
+			origin: OriginFor<T>,
+			bounty_id: u32,
+			milestone_index: u32,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let mut bounty = Bounties::<T>::get(&bounty_id).ok_or(Error::<T>::BountyDoesNotExist)?;
+			let team = Teams::<T>::get(&bounty.team_id).ok_or(Error::<T>::TeamDoesNotExist)?;
+			ensure!(team.members.contains(&who), Error::<T>::NotATeamMember);
+			ensure!(Self::is_bounty_manager(&team, &who), Error::<T>::NotBountyManager);
+			ensure!(bounty.status == BountyStatus::Claimed, Error::<T>::BountyNotClaimed);
+			ensure!(!bounty.pending_reject, Error::<T>::BountyRejectionPending);
+			let claimant = bounty.claimant.clone().ok_or(Error::<T>::BountyNotClaimed)?;
+			let (_, amount) = *bounty
+				.milestones
+				.get(milestone_index as usize)
+				.ok_or(Error::<T>::MilestoneDoesNotExist)?;
+			ensure!(milestone_index == bounty.milestones_paid, Error::<T>::MilestoneNotNext);
+
+			Self::note_activity(&team, &who);
+			if !Self::record_approval(&team, bounty_id, &who, Some(milestone_index))? {
+				return Ok(())
+			}
+			bounty.milestones_paid = bounty.milestones_paid.saturating_add(1);
+			if bounty.milestones_paid as usize == bounty.milestones.len() {
+				// what is left of the amount is the last milestone's
+				return Self::approve(bounty_id, bounty, claimant)
+			}
+
+			T::Deposit::repatriate_reserved(&bounty.poster, &claimant, amount, BalanceStatus::Free)?;
+			bounty.amount = bounty.amount.saturating_sub(amount);
+			Bounties::<T>::insert(&bounty_id, bounty);
+
+			Self::deposit_event(Event::MilestoneApproved { bounty_id, milestone_index, claimant, amount });
+
+			Ok(())
+		}
+
+		// Allows a team member to push back the expiry of a bounty that has not been approved
+		#[pallet::weight(T::WeightInfo::extend_bounty_expiry())]
+		pub fn extend_bounty_expiry(
+			origin: OriginFor<T>,
+			bounty_id: u32,
+			new_expiry: T::BlockNumber,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let mut bounty = Bounties::<T>::get(&bounty_id).ok_or(Error::<T>::BountyDoesNotExist)?;
+			let team = Teams::<T>::get(&bounty.team_id).ok_or(Error::<T>::TeamDoesNotExist)?;
+			ensure!(team.members.contains(&who), Error::<T>::NotATeamMember);
+			ensure!(bounty.status != BountyStatus::Approved, Error::<T>::BountyAlreadyClaimed);
+			ensure!(new_expiry > bounty.expiry, Error::<T>::ExpiryNotInFuture);
+
+			bounty.expiry = new_expiry;
+			Bounties::<T>::insert(&bounty_id, bounty);
+
+			Self::deposit_event(Event::BountyExtended { bounty_id, new_expiry });
+
+			Ok(())
+		}
+
+		// Allows a team member to reject a claimed bounty. Once a majority of the team has
+		// rejected it, the claimant is cleared and the expiry is reset so others can claim it.
+		#[pallet::weight(T::WeightInfo::reject_bounty())]
+		pub fn reject_bounty(origin: OriginFor<T>, bounty_id: u32) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let mut bounty = Bounties::<T>::get(&bounty_id).ok_or(Error::<T>::BountyDoesNotExist)?;
+			let team = Teams::<T>::get(&bounty.team_id).ok_or(Error::<T>::TeamDoesNotExist)?;
+			ensure!(team.members.contains(&who), Error::<T>::NotATeamMember);
+			ensure!(bounty.status == BountyStatus::Claimed, Error::<T>::BountyNotClaimed);
+			ensure!(
+				!BountyRejections::<T>::contains_key(&bounty_id, &who),
+				Error::<T>::AlreadyRejected
+			);
+
+			BountyRejections::<T>::insert(&bounty_id, &who, ());
+			bounty.pending_reject = true;
+			Self::deposit_event(Event::BountyRejected { bounty_id, member: who });
+
+			// only members still on the team count towards the majority
+			let rejections =
+				team.members.iter().filter(|m| BountyRejections::<T>::contains_key(&bounty_id, *m)).count();
+			if rejections * 2 > team.members.len() {
+				let _ = BountyRejections::<T>::clear_prefix(&bounty_id, T::MaxMembers::get(), None);
+				let _ = BountyApprovals::<T>::clear_prefix(&bounty_id, T::MaxMembers::get(), None);
+				bounty.claimant = None;
+				bounty.solution = None;
+				bounty.status = BountyStatus::Open;
+				bounty.pending_reject = false;
+				bounty.expiry = frame_system::Pallet::<T>::block_number().saturating_add(bounty.duration);
+				Self::deposit_event(Event::BountyReset { bounty_id, expiry: bounty.expiry });
+			}
+
+			Bounties::<T>::insert(&bounty_id, bounty);
+
+			Ok(())
+		}
+
+		// Allows a team member to donate an expired, unclaimed bounty's funds to a challenge prize.
+		// The funds move onto the challenge funder's reward hold and the bounty is removed. Charged
+		// for a challenge that already has every other contributor.
+		#[pallet::weight(T::WeightInfo::redirect_bounty_to_challenge(T::MaxContributors::get()))]
+		pub fn redirect_bounty_to_challenge(
+			origin: OriginFor<T>,
+			bounty_id: u32,
+			hackathon_id: u32,
+			challenge_id: u16,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let bounty = Bounties::<T>::get(&bounty_id).ok_or(Error::<T>::BountyDoesNotExist)?;
+			let team = Teams::<T>::get(&bounty.team_id).ok_or(Error::<T>::TeamDoesNotExist)?;
+			ensure!(team.members.contains(&who), Error::<T>::NotATeamMember);
+			ensure!(
+				bounty.status == BountyStatus::Open &&
+					frame_system::Pallet::<T>::block_number() >= bounty.expiry,
+				Error::<T>::BountyNotExpired
+			);
+
+			let mut challenge =
+				Challenges::<T>::get(&hackathon_id, &challenge_id).ok_or(Error::<T>::ChallengeDoesNotExist)?;
+			ensure!(!Self::is_finalized(challenge_id), Error::<T>::ChallengeAlreadyFinalized);
+			// contributions are refunded one by one, so their number has to stay bounded
+			ensure!(
+				ChallengeContributions::<T>::contains_key(&challenge_id, &bounty.poster) ||
+					(ChallengeContributions::<T>::iter_key_prefix(&challenge_id).count() as u32) <
+						T::MaxContributors::get(),
+				Error::<T>::TooManyContributors
+			);
+
+			T::RewardCurrency::transfer_held(&bounty.poster, &challenge.funder, bounty.amount, false, true)?;
+			challenge.reward = challenge.reward.saturating_add(bounty.amount);
+			ChallengeContributions::<T>::mutate(&challenge_id, &bounty.poster, |contributed| {
+				*contributed = contributed.saturating_add(bounty.amount)
+			});
+			Challenges::<T>::insert(&hackathon_id, &challenge_id, challenge);
+			Bounties::<T>::remove(&bounty_id);
+			PendingBounties::<T>::mutate(&bounty.team_id, |pending| *pending = pending.saturating_sub(1));
+
+			Self::deposit_event(Event::BountyRedirected { bounty_id, challenge_id, amount: bounty.amount });
+
+			Ok(())
+		}
+
+		// Allows a bounty's poster to withdraw it while nobody has claimed it, unreserving its funds
+		#[pallet::weight(T::WeightInfo::cancel_bounty())]
+		pub fn cancel_bounty(origin: OriginFor<T>, bounty_id: u32) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let bounty = Bounties::<T>::get(&bounty_id).ok_or(Error::<T>::BountyDoesNotExist)?;
+			ensure!(bounty.poster == who, Error::<T>::NotBountyPoster);
+			ensure!(bounty.status == BountyStatus::Open, Error::<T>::BountyAlreadyClaimed);
+
+			T::Deposit::unreserve(&who, bounty.amount);
+			Bounties::<T>::remove(&bounty_id);
+			PendingBounties::<T>::mutate(&bounty.team_id, |pending| *pending = pending.saturating_sub(1));
+
+			Self::deposit_event(Event::BountyCancelled { bounty_id, poster: who, amount: bounty.amount });
+
+			Ok(())
+		}
+
+		// Allows any account to publicly support a team. Endorsing twice has no further effect.
+		#[pallet::weight(T::WeightInfo::endorse_team())]
+		pub fn endorse_team(origin: OriginFor<T>, team_id: u32) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(Teams::<T>::contains_key(&team_id), Error::<T>::TeamDoesNotExist);
+			if TeamEndorsements::<T>::contains_key(&team_id, &who) {
+				return Ok(())
+			}
+
+			TeamEndorsements::<T>::insert(&team_id, &who, ());
+			TeamEndorsementCount::<T>::mutate(&team_id, |count| *count = count.saturating_add(1));
+
+			Self::deposit_event(Event::TeamEndorsed { team_id, who });
+
+			Ok(())
+		}
+
+		// Allows an account to withdraw its endorsement. Withdrawing twice has no further effect.
+		#[pallet::weight(T::WeightInfo::unendorse_team())]
+		pub fn unendorse_team(origin: OriginFor<T>, team_id: u32) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			if TeamEndorsements::<T>::take(&team_id, &who).is_none() {
+				return Ok(())
+			}
+			TeamEndorsementCount::<T>::mutate(&team_id, |count| *count = count.saturating_sub(1));
+
+			Self::deposit_event(Event::TeamUnendorsed { team_id, who });
+
+			Ok(())
+		}
+
+		// Allows any account to publish a profile independent of the teams it joins
+		#[pallet::weight(T::WeightInfo::register_buidler())]
+		pub fn register_buidler(origin: OriginFor<T>, profile: H256) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(!Buidlers::<T>::contains_key(&who), Error::<T>::BuidlerAlreadyRegistered);
+			Buidlers::<T>::insert(&who, profile);
+
+			Self::deposit_event(Event::BuidlerRegistered { who, profile });
+
+			Ok(())
+		}
+
+		// Allows a registered buidler to point their profile at a new description
+		#[pallet::weight(T::WeightInfo::update_buidler_profile())]
+		pub fn update_buidler_profile(origin: OriginFor<T>, profile: H256) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(Buidlers::<T>::contains_key(&who), Error::<T>::BuidlerNotRegistered);
+			Buidlers::<T>::insert(&who, profile);
+
+			Self::deposit_event(Event::BuidlerProfileUpdated { who, profile });
+
+			Ok(())
+		}
+
+		// Allows a registered buidler to remove their profile
+		#[pallet::weight(T::WeightInfo::deregister_buidler())]
+		pub fn deregister_buidler(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(Buidlers::<T>::contains_key(&who), Error::<T>::BuidlerNotRegistered);
+			Buidlers::<T>::remove(&who);
+
+			Self::deposit_event(Event::BuidlerDeregistered { who });
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Everything stored about a challenge, including its submission format hint.
+		pub fn challenge_detail(hackathon_id: u32, challenge_id: u16) -> Option<Challenge<T>> {
+			Challenges::<T>::get(&hackathon_id, &challenge_id)
+		}
+
+		/// A challenge of a hackathon with every official solution submitted to it, in solution id
+		/// order, leaving drafts out. Anonymous solutions are listed without their team, submitter and members until voting
+		/// ends. This only affects the listing: storage keeps the full roster for payouts.
+		pub fn challenge_with_solutions(
+			hackathon_id: u32,
+			challenge_id: u16,
+		) -> Option<(Challenge<T>, Vec<ListedSolution<T>>)> {
+			let challenge = Challenges::<T>::get(&hackathon_id, &challenge_id)?;
+			let mut solutions: Vec<(u16, SubmittedSolution<T>)> =
+				ChallengeSolutions::<T>::iter_prefix(&challenge_id)
+					.filter(|(_, solution)| !solution.draft)
+					.collect();
+			solutions.sort_by_key(|(id, _)| *id);
+			let reveal = Self::has_voting_ended(hackathon_id);
+			let solutions = solutions
+				.into_iter()
+				.map(|(_, solution)| {
+					let mut listed = ListedSolution::from(solution);
+					if listed.anonymous && !reveal {
+						listed.team_id = None;
+						listed.submitter = None;
+						listed.members = None;
+					}
+					listed
+				})
+				.collect();
+			Some((challenge, solutions))
+		}
+
+		/// Every challenge of a hackathon with its id, in challenge id order.
+		pub fn list_challenges(hackathon_id: u32) -> Vec<(u16, Challenge<T>)> {
+			let mut challenges: Vec<(u16, Challenge<T>)> = Challenges::<T>::iter_prefix(&hackathon_id).collect();
+			challenges.sort_by_key(|(id, _)| *id);
+			challenges
+		}
+
+		/// The ids of every challenge in a hackathon that lists `who` as a judge, in ascending order.
+		pub fn challenges_for_judge(hackathon_id: u32, who: &T::AccountId) -> Vec<u16> {
+			let mut ids: Vec<u16> = Challenges::<T>::iter_prefix(&hackathon_id)
+				.filter(|(_, challenge)| Self::is_judge(challenge, who))
+				.map(|(id, _)| id)
+				.collect();
+			ids.sort();
+			ids
+		}
+
+		/// The ids of every team that has declared `challenge_id` as a target, in ascending order.
+		pub fn teams_targeting(challenge_id: u16) -> Vec<u32> {
+			let mut ids: Vec<u32> = TeamTargets::<T>::iter()
+				.filter(|(_, targets)| targets.contains(&challenge_id))
+				.map(|(id, _)| id)
+				.collect();
+			ids.sort();
+			ids
+		}
+
+		/// The prize money still at stake in a hackathon: the rewards of its challenges that have
+		/// not been settled. Cancelled challenges are removed, so they drop out too.
+		pub fn total_rewards(hackathon_id: u32) -> BalanceOf<T> {
+			Challenges::<T>::iter_prefix(&hackathon_id)
+				.filter(|(challenge_id, _)| !Self::is_finalized(*challenge_id))
+				.fold(Zero::zero(), |total: BalanceOf<T>, (_, challenge)| {
+					total.saturating_add(challenge.reward)
+				})
+		}
+
+		/// The teams that won challenges in a hackathon with the rewards paid to their members in
+		/// total, highest first and ties in team id order. Unsettled challenges are left out, while
+		/// archived ones count with what their archive records.
+		pub fn leaderboard(hackathon_id: u32) -> Vec<(u32, BalanceOf<T>)> {
+			let mut totals = BTreeMap::<u32, BalanceOf<T>>::new();
+			for archive in ChallengeArchives::<T>::iter_values()
+				.filter(|archive| archive.hackathon_id == hackathon_id)
+			{
+				if let Some(team_id) = archive.winner {
+					let total = totals.entry(team_id).or_insert_with(Zero::zero);
+					*total = total.saturating_add(archive.reward);
+				}
+			}
+			for (challenge_id, challenge) in Challenges::<T>::iter_prefix(&hackathon_id) {
+				let winner = ChallengeWinners::<T>::get(&challenge_id)
+					.and_then(|solution_id| ChallengeSolutions::<T>::get(&challenge_id, &solution_id));
+				if let Some(solution) = winner {
+					let (shares, _) =
+						Self::reward_shares(challenge.reward, solution.team_id, &solution.members);
+					let total = totals.entry(solution.team_id).or_insert_with(Zero::zero);
+					for (_, share) in shares {
+						*total = total.saturating_add(share);
+					}
+				}
+			}
+			let mut ranking: Vec<(u32, BalanceOf<T>)> = totals.into_iter().collect();
+			ranking.sort_by(|a, b| b.1.cmp(&a.1));
+			ranking
+		}
+
+		/// Everyone judging in a hackathon, each listed once: the event's judge pool first, then
+		/// the panels of its challenges and their prizes in challenge id order.
+		pub fn hackathon_judges(hackathon_id: u32) -> Vec<T::AccountId> {
+			let mut judges: Vec<T::AccountId> = EventJudges::<T>::get(&hackathon_id).into_inner();
+			let mut challenges: Vec<(u16, Challenge<T>)> =
+				Challenges::<T>::iter_prefix(&hackathon_id).collect();
+			challenges.sort_by_key(|(challenge_id, _)| *challenge_id);
+			for (challenge_id, challenge) in challenges {
+				let prize_panels = ChallengePrizes::<T>::iter_prefix_values(&challenge_id)
+					.flat_map(|prize| prize.judges.into_inner());
+				for judge in challenge.judges.into_iter().flatten().chain(prize_panels) {
+					if !judges.contains(&judge) {
+						judges.push(judge);
+					}
+				}
+			}
+			judges
+		}
+
+		/// What each member of the winning team would be paid if a challenge were settled on the
+		/// votes cast so far, computed exactly as `finalize_challenge` pays out. Empty when the
+		/// reward would be refunded instead, or when no such challenge exists.
+		pub fn preview_distribution(challenge_id: u16) -> Vec<(T::AccountId, BalanceOf<T>)> {
+			let found = Challenges::<T>::iter().find(|(_, id, _)| *id == challenge_id);
+			let (hackathon_id, _, challenge) = match found {
+				Some(found) => found,
+				None => return Vec::new(),
+			};
+			Self::outcome(hackathon_id, challenge_id, &challenge)
+				.ok()
+				.and_then(|solution_id| ChallengeSolutions::<T>::get(&challenge_id, &solution_id))
+				.map_or_else(Vec::new, |solution| {
+					Self::reward_shares(challenge.reward, solution.team_id, &solution.members).0
+				})
+		}
+
+		/// The reward tier of a challenge, or `None` if it does not exist.
+		pub fn reward_tier(hackathon_id: u32, challenge_id: u16) -> Option<RewardTier> {
+			let reward = Challenges::<T>::get(&hackathon_id, &challenge_id)?.reward;
+			let (medium, large) = T::RewardTierThresholds::get();
+			Some(if reward >= large {
+				RewardTier::Large
+			} else if reward >= medium {
+				RewardTier::Medium
+			} else {
+				RewardTier::Small
+			})
+		}
+
+		/// Count the votes cast on a challenge, delegated ones included, returning the leading
+		/// solution and the votes cast for it. Solutions are ranked by their votes plus any early bonus they earned. Ties go to
+		/// the earliest submission, then the lowest solution id.
+		pub fn tally_votes(hackathon_id: u32, challenge_id: u16) -> Option<(u16, u32)> {
+			let early_bonus = Challenges::<T>::get(&hackathon_id, &challenge_id)
+				.and_then(|challenge| challenge.early_bonus);
+			// iterating in ascending id order and only replacing on a strictly better standing
+			// keeps the lowest id on a full tie
+			Self::vote_counts(challenge_id)
+				.into_iter()
+				.filter_map(|(solution_id, count)| {
+					let submitted_at = ChallengeSolutions::<T>::get(&challenge_id, &solution_id)
+						.filter(|solution| !solution.draft && !solution.disqualified)?
+						.submitted_at;
+					let score = match &early_bonus {
+						Some(bonus) if submitted_at < bonus.cutoff => count.saturating_add(bonus.votes),
+						_ => count,
+					};
+					Some((solution_id, count, score, submitted_at))
+				})
+				.fold(None, |leader, (solution_id, count, score, submitted_at)| match leader {
+					Some((_, _, best, best_at))
+						if best > score || (best == score && best_at <= submitted_at) =>
+						leader,
+					_ => Some((solution_id, count, score, submitted_at)),
+				})
+				.map(|(solution_id, count, _, _)| (solution_id, count))
+		}
+
+		/// The amount escrowed for a bounty: the full amount while it is open or claimed, and
+		/// nothing once it is approved or has expired unclaimed. An expired bounty's funds stay
+		/// reserved from the poster until it is cancelled or redirected. `None` if no such bounty
+		/// exists.
+		pub fn bounty_escrow(bounty_id: u32) -> Option<BalanceOf<T>> {
+			let bounty = Bounties::<T>::get(&bounty_id)?;
+			let expired = frame_system::Pallet::<T>::block_number() >= bounty.expiry;
+			Some(match bounty.status {
+				BountyStatus::Open if expired => Zero::zero(),
+				BountyStatus::Open | BountyStatus::Claimed => bounty.amount,
+				BountyStatus::Approved => Zero::zero(),
+			})
+		}
+
+		/// Each account's percentage of a team's prize. Claimants of the team's approved bounties
+		/// are granted the bounties' percentages and the founder keeps the rest, so the shares add
+		/// up to 100. The founder comes first, then claimants in bounty id order. Empty if no
+		/// such team exists.
+		pub fn resolved_shares(team_id: u32) -> Vec<(T::AccountId, u8)> {
+			let team = match Teams::<T>::get(&team_id) {
+				Some(team) => team,
+				None => return Vec::new(),
+			};
+			let mut approved: Vec<(u32, Bounty<T>)> = Bounties::<T>::iter()
+				.filter(|(_, bounty)| {
+					bounty.team_id == team_id &&
+						bounty.status == BountyStatus::Approved &&
+						bounty.percentage > 0
+				})
+				.collect();
+			approved.sort_by_key(|(bounty_id, _)| *bounty_id);
+
+			let mut shares = vec![(team.team_founder, 100u8)];
+			let claims = approved
+				.into_iter()
+				.filter_map(|(_, bounty)| bounty.claimant.map(|claimant| (claimant, bounty.percentage)));
+			for (claimant, percentage) in claims {
+				// approvals never grant more than 100 in total, see `GrantedOwnership`
+				shares[0].1 = shares[0].1.saturating_sub(percentage);
+				match shares.iter_mut().find(|(who, _)| *who == claimant) {
+					Some((_, share)) => *share = share.saturating_add(percentage),
+					None => shares.push((claimant, percentage)),
+				}
+			}
+			shares
+		}
+
+		/// Whether a challenge has been settled, with a winner or with its reward refunded.
+		pub fn is_finalized(challenge_id: u16) -> bool {
+			ChallengeWinners::<T>::contains_key(&challenge_id) ||
+				UnawardedChallenges::<T>::contains_key(&challenge_id) ||
+				ChallengeArchives::<T>::contains_key(&challenge_id)
+		}
+
+		/// The votes counted for each solution of a challenge that received any, delegated ones
+		/// included. Once the challenge is settled these are the votes recorded at the time.
+		fn vote_counts(challenge_id: u16) -> BTreeMap<u16, u32> {
+			if Self::is_finalized(challenge_id) {
+				return SettledVoters::<T>::iter_prefix(&challenge_id)
+					.map(|(solution_id, voters)| (solution_id, voters.len() as u32))
+					.collect()
+			}
+			let mut counts = BTreeMap::<u16, u32>::new();
+			for (_, solution_id) in Self::counted_votes(challenge_id) {
+				*counts.entry(solution_id).or_default() += 1;
+			}
+			counts
+		}
+
+		/// The votes counted on a challenge that is not settled yet, delegated ones included: the
+		/// first `MaxCountedVotes` in the order they were cast, each judge with the solution they
+		/// back. A delegation waits for its delegate's vote before it counts or takes a place.
+		fn counted_votes(challenge_id: u16) -> Vec<(T::AccountId, u16)> {
+			VoteOrder::<T>::get(&challenge_id)
+				.into_iter()
+				.filter_map(|judge| {
+					// delegators never vote themselves, so each judge backs at most one solution
+					let voter = VoteDelegations::<T>::get(&challenge_id, &judge)
+						.unwrap_or_else(|| judge.clone());
+					Votes::<T>::get(&challenge_id, &voter).map(|solution_id| (judge, solution_id))
+				})
+				.take(T::MaxCountedVotes::get() as usize)
+				.collect()
+		}
+
+		/// Note that `judge` has cast a vote or delegated one, unless they already had, reserving
+		/// their deposit on the challenge the first time.
+		fn record_vote_order(challenge_id: u16, judge: &T::AccountId) -> DispatchResult {
+			VoteOrder::<T>::try_mutate(&challenge_id, |order| {
+				if !order.contains(judge) {
+					// only panel judges vote, and panels are bounded by `MaxMembers`
+					order.try_push(judge.clone()).map_err(|_| Error::<T>::TooManyMembers)?;
+				}
+				// a judge who revoked their delegation keeps the deposit they already paid
+				if !JudgeDeposits::<T>::contains_key(&challenge_id, judge) {
+					let deposit = T::JudgeDeposit::get();
+					ensure!(
+						T::Deposit::can_reserve(judge, deposit),
+						Error::<T>::InsufficientBalanceForJudgeDeposit
+					);
+					T::Deposit::reserve(judge, deposit)?;
+					JudgeDeposits::<T>::insert(&challenge_id, judge, deposit);
+				}
+				Ok(())
+			})
+		}
+
+		/// Give a judge a strike for backing a disqualified solution of a challenge, barring them
+		/// and slashing their deposit on it once they reach `JudgeStrikeLimit`.
+		fn strike_judge(challenge_id: u16, judge: T::AccountId) {
+			let strikes = JudgeStrikes::<T>::mutate(&judge, |strikes| {
+				*strikes = strikes.saturating_add(1);
+				*strikes
+			});
+			let limit = T::JudgeStrikeLimit::get();
+			if limit == 0 || strikes < limit || BannedJudges::<T>::contains_key(&judge) {
+				Self::deposit_event(Event::JudgeStruck { judge, strikes });
+				return
+			}
+			let deposit = JudgeDeposits::<T>::take(&challenge_id, &judge).unwrap_or_default();
+			let (_, unslashed) = T::Deposit::slash_reserved(&judge, deposit);
+			BannedJudges::<T>::insert(&judge, ());
+			Self::deposit_event(Event::JudgeBanned { judge, slashed: deposit.saturating_sub(unslashed) });
+		}
+
+		/// Fail if any of `judges` has been barred from panels.
+		fn ensure_not_banned<'a>(judges: impl IntoIterator<Item = &'a T::AccountId>) -> DispatchResult
+		where
+			T::AccountId: 'a,
+		{
+			for judge in judges {
+				ensure!(!BannedJudges::<T>::contains_key(judge), Error::<T>::JudgeBanned);
+			}
+			Ok(())
+		}
+
+		/// The judges whose votes count for a solution, delegators included, in ascending order.
+		/// Once the challenge is settled these are the voters recorded at the time.
+		pub fn solution_voters(challenge_id: u16, solution_index: u16) -> Vec<T::AccountId> {
+			if Self::is_finalized(challenge_id) {
+				return SettledVoters::<T>::get(&challenge_id, &solution_index).into_inner()
+			}
+			let mut voters: Vec<T::AccountId> = Self::counted_votes(challenge_id)
+				.into_iter()
+				.filter(|(_, solution_id)| *solution_id == solution_index)
+				.map(|(judge, _)| judge)
+				.collect();
+			voters.sort();
+			voters
+		}
+
+		/// The solution currently leading a challenge's tally, or `None` before any vote. Meant for
+		/// live standings while voting is open; once settled it reports the final standing.
+		pub fn current_leader(hackathon_id: u32, challenge_id: u16) -> Option<u16> {
+			Self::tally_votes(hackathon_id, challenge_id).map(|(solution_id, _)| solution_id)
+		}
+
+		/// Post a challenge to a hackathon, holding its reward from `funder`.
+		fn do_create_challenge(
+			hackathon_id: u32,
+			author: T::AccountId,
+			funder: T::AccountId,
+			description: H256,
+			reward: BalanceOf<T>,
+			judges: Option<BoundedVec<T::AccountId, T::MaxMembers>>,
+			submission_format: Option<H256>,
+		) -> DispatchResult {
+			let hackathon = Hackathons::<T>::get(&hackathon_id).ok_or(Error::<T>::HackathonDoesNotExist)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() < hackathon.challenge_deadline,
+				Error::<T>::ChallengeWindowClosed
+			);
+
+			// an empty panel could never judge the challenge, and the same judge listed twice
+			// would be counted twice
+			ensure!(judges.as_ref().map_or(true, |j| !j.is_empty()), Error::<T>::NoJudgesProvided);
+			Self::ensure_not_banned(judges.iter().flatten())?;
+			let judges = judges.map(Self::dedup_judges);
+			let judges_supplied = judges.is_some();
+
+			// only the deposit is held up front, except from the treasury, which cannot sign to
+			// top a challenge up later
+			let deposit = if funder == T::TreasuryAccount::get() {
+				reward
+			} else {
+				T::DepositFraction::get().mul_ceil(reward)
+			};
+			// check has sufficient funds and hold them
+			// `can_hold` also accounts for existing locks and the existential deposit
+			ensure!(T::RewardCurrency::can_hold(&funder, deposit), Error::<T>::InsufficientBalance);
+
+			let challenge_id = NextChallengeId::<T>::get();
+			let next_challenge_id = challenge_id.checked_add(1).ok_or(Error::<T>::ChallengeIdOverflow)?;
+
+			// every fallible check has passed, so the hold cannot be stranded without a challenge
+			T::RewardCurrency::hold(&funder, deposit)?;
+
+			// create new challenge object
+			let new_challenge = Challenge::<T> {
+				author: author.clone(),
+				funder,
+				description,
+				reward,
+				judges,
+				submissions: 0,
+				edits: 0,
+				submission_format,
+				early_bonus: None,
+				required_category: None,
+				outstanding: reward.saturating_sub(deposit),
+				required_agreement: Permill::zero(),
+			};
+
+			// write to storage
+			Challenges::<T>::insert(hackathon_id, challenge_id, new_challenge);
+			NextChallengeId::<T>::put(next_challenge_id);
+
+			Self::deposit_event(Event::ChallengeCreated {
+				hackathon_id,
+				id: challenge_id,
+				creator: author,
+				reward,
+				judges_supplied,
+			});
+
+			Ok(())
+		}
+
+		/// The solution a challenge would be awarded to on the votes cast so far, or why it would
+		/// be settled without a winner.
+		fn outcome(
+			hackathon_id: u32,
+			challenge_id: u16,
+			challenge: &Challenge<T>,
+		) -> Result<u16, FinalizationFailure> {
+			let panel = challenge.judges.as_ref().map_or(0, |judges| judges.len() as u32);
+			let required = T::JudgeQuorum::get().mul_ceil(panel);
+			match Self::tally_votes(hackathon_id, challenge_id) {
+				None => Err(FinalizationFailure::NoVotes),
+				Some((_, votes)) if votes < required =>
+					Err(FinalizationFailure::QuorumNotMet { votes, required }),
+				Some((winning_solution, votes)) => {
+					// votes on solutions that can no longer win were not cast for anything
+					let cast = Self::vote_counts(challenge_id)
+						.into_iter()
+						.filter(|(solution_id, _)| Self::is_eligible(challenge_id, *solution_id))
+						.fold(0u32, |cast, (_, count)| cast.saturating_add(count));
+					let required = challenge.required_agreement.mul_ceil(cast);
+					if votes < required {
+						return Err(FinalizationFailure::AgreementNotMet { votes, required })
+					}
+					Ok(winning_solution)
+				},
+			}
+		}
+
+		/// Split `reward` evenly across `winners` of team `team_id`, returning what each is paid
+		/// and what nobody is. The remainder left by the division goes where `Rounding` says.
+		/// Nobody is listed, and the whole reward is left unpaid, when the shares would be zero.
+		fn reward_shares(
+			reward: BalanceOf<T>,
+			team_id: u32,
+			winners: &[T::AccountId],
+		) -> (Vec<(T::AccountId, BalanceOf<T>)>, BalanceOf<T>) {
+			if winners.is_empty() {
+				return (Vec::new(), reward)
+			}
+			let share = reward / BalanceOf::<T>::from(winners.len() as u32);
+			if share.is_zero() {
+				return (Vec::new(), reward)
+			}
+			let paid = share.saturating_mul(BalanceOf::<T>::from(winners.len() as u32));
+			let remainder = reward.saturating_sub(paid);
+			let mut shares: Vec<(T::AccountId, BalanceOf<T>)> =
+				winners.iter().map(|winner| (winner.clone(), share)).collect();
+			let recipient = match T::Rounding::get() {
+				RoundingPolicy::BurnRemainder => return (shares, remainder),
+				RoundingPolicy::RemainderToFirst => 0,
+				// a founder who joined the roster after the team submitted is not a winner
+				RoundingPolicy::RemainderToFounder => Teams::<T>::get(&team_id)
+					.and_then(|team| winners.iter().position(|winner| *winner == team.team_founder))
+					.unwrap_or(0),
+			};
+			shares[recipient].1 = share.saturating_add(remainder);
+			(shares, Zero::zero())
+		}
+
+		/// Move `reward` out of `funder`'s hold, split across `winners` of team `team_id` as
+		/// `reward_shares` does. A reward too small to split is released to the funder, while a
+		/// remainder left unpaid by `RoundingPolicy::BurnRemainder` is burned. Unless
+		/// `VestingDuration` is zero, each share stays held on its winner under a schedule keyed
+		/// by `reward_id`.
+		fn pay_out(
+			funder: &T::AccountId,
+			reward: BalanceOf<T>,
+			team_id: u32,
+			winners: &BoundedVec<T::AccountId, T::MaxMembers>,
+			reward_id: (u16, Option<u16>),
+		) -> DispatchResult {
+			let (shares, unpaid) = Self::reward_shares(reward, team_id, winners);
+			let split = !shares.is_empty();
+			let duration = T::VestingDuration::get();
+			let vesting = !duration.is_zero();
+			for (winner, share) in shares {
+				let paid = T::RewardCurrency::transfer_held(funder, &winner, share, true, vesting)?;
+				if vesting {
+					let schedule = VestingSchedule {
+						total: paid,
+						released: Zero::zero(),
+						start: <frame_system::Pallet<T>>::block_number(),
+						duration,
+					};
+					VestingSchedules::<T>::insert(&winner, reward_id, schedule);
+				}
+			}
+			T::RewardCurrency::release(funder, unpaid, true)?;
+			if split {
+				// `Deposit` shares its balances with `RewardCurrency`
+				let _ = T::Deposit::slash(funder, unpaid);
+			}
+			Ok(())
+		}
+
+		/// How much of a `schedule`'s share has vested by `now`, released or not.
+		fn vested(
+			schedule: &VestingSchedule<BalanceOf<T>, T::BlockNumber>,
+			now: T::BlockNumber,
+		) -> BalanceOf<T> {
+			let elapsed = now.saturating_sub(schedule.start);
+			if elapsed >= schedule.duration {
+				return schedule.total
+			}
+			Permill::from_rational(elapsed, schedule.duration).mul_floor(schedule.total)
+		}
+
+		/// Release a challenge's `reward` from `funder`'s hold: contributors get back what they
+		/// added and the funder gets back the rest. There are at most `MaxContributors`
+		/// contributors.
+		fn refund(challenge_id: u16, funder: &T::AccountId, reward: BalanceOf<T>) -> DispatchResult {
+			let mut remaining = reward;
+			for (contributor, amount) in ChallengeContributions::<T>::drain_prefix(&challenge_id) {
+				T::RewardCurrency::transfer_held(funder, &contributor, amount, true, false)?;
+				remaining = remaining.saturating_sub(amount);
+			}
+			T::RewardCurrency::release(funder, remaining, true)?;
+			Ok(())
+		}
+
+		/// Record who voted for each solution of a settled challenge, among the votes its tally
+		/// counted, then drop its per-judge votes, delegations and vote order. Only judges on the
+		/// panel can vote or delegate, so none of them outgrows `MaxMembers`. Judges get their
+		/// deposits back.
+		fn settle_votes(challenge_id: u16) {
+			let mut voters = BTreeMap::<u16, Vec<T::AccountId>>::new();
+			for (judge, solution_id) in Self::counted_votes(challenge_id) {
+				voters.entry(solution_id).or_default().push(judge);
+			}
+			for (solution_id, mut judges) in voters {
+				judges.sort();
+				if let Ok(judges) = BoundedVec::try_from(judges) {
+					SettledVoters::<T>::insert(&challenge_id, &solution_id, judges);
+				}
+			}
+			let _ = Votes::<T>::clear_prefix(&challenge_id, T::MaxMembers::get(), None);
+			let _ = VoteDelegations::<T>::clear_prefix(&challenge_id, T::MaxMembers::get(), None);
+			VoteOrder::<T>::remove(&challenge_id);
+			for (judge, deposit) in JudgeDeposits::<T>::drain_prefix(&challenge_id) {
+				T::Deposit::unreserve(&judge, deposit);
+			}
+		}
+
+		/// Whether `who` could submit a solution for a team to a challenge right now: the same
+		/// checks `submit_solution` runs before it writes anything.
+		pub fn can_submit(hackathon_id: u32, challenge_id: u16, team_id: u32, who: &T::AccountId) -> bool {
+			Self::check_submission(who, hackathon_id, challenge_id, team_id).is_ok()
+		}
+
+		/// Every check a submission must pass, returning the challenge, the team and the id the
+		/// solution would get.
+		fn check_submission(
+			who: &T::AccountId,
+			hackathon_id: u32,
+			challenge_id: u16,
+			team_id: u32,
+		) -> Result<(Challenge<T>, Team<T>, u16), DispatchError> {
+			// check if the challenge exists
+			let challenge =
+				Challenges::<T>::get(&hackathon_id, &challenge_id).ok_or(Error::<T>::ChallengeDoesNotExist)?;
+
+			// only registered teams are eligible, and only their members may submit for them
+			let team = Teams::<T>::get(&team_id).ok_or(Error::<T>::TeamDoesNotExist)?;
+			ensure!(team.members.contains(who), Error::<T>::NotATeamMember);
+			ensure!(
+				challenge.required_category.map_or(true, |category| team.category == Some(category)),
+				Error::<T>::CategoryMismatch
+			);
+			// a team is held to the event's size limit when it enters, and grandfathered after
+			let max_team_size = Hackathons::<T>::get(&hackathon_id)
+				.ok_or(Error::<T>::HackathonDoesNotExist)?
+				.max_team_size;
+			ensure!(
+				TeamHackathons::<T>::contains_key(&team_id, &hackathon_id) ||
+					team.members.len() as u32 <= max_team_size,
+				Error::<T>::TeamTooLarge
+			);
+
+			ensure!(Self::is_submission_open(hackathon_id), Error::<T>::SubmissionPeriodClosed);
+			ensure!(challenge.outstanding.is_zero(), Error::<T>::ChallengeNotFunded);
+			ensure!(challenge.submissions < T::MaxSolutions::get(), Error::<T>::TooManySolutions);
+
+			// check that the team has not already submitted a solution
+			ensure!(
+				!ChallengeSolutions::<T>::iter_prefix_values(&challenge_id)
+					.any(|existing| existing.team_id == team_id),
+				Error::<T>::SolutionAlreadySubmitted
+			);
+
+			// stop one account padding many teams' submissions to the same hackathon
+			let cap = T::MaxSolutionsPerAccount::get();
+			ensure!(
+				team.members
+					.iter()
+					.all(|member| Coauthorships::<T>::get(&hackathon_id, member) < cap),
+				Error::<T>::TooManyCoauthorships
+			);
+			ensure!(
+				T::Deposit::can_reserve(who, T::SubmissionBond::get()),
+				Error::<T>::InsufficientBalanceForSubmissionBond
+			);
+
+			let solution_id = NextSolutionId::<T>::get(&challenge_id);
+			solution_id.checked_add(1).ok_or(Error::<T>::TooManySolutions)?;
+
+			Ok((challenge, team, solution_id))
+		}
+
+		/// Store a team's solution to a challenge, official or as a draft, once every check passes.
+		/// Shared by `submit_solution` and `draft_solution`.
+		fn do_submit_solution(
+			who: T::AccountId,
+			hackathon_id: u32,
+			challenge_id: u16,
+			team_id: u32,
+			solution: H256,
+			anonymous: bool,
+			draft: bool,
+		) -> DispatchResult {
+			let (mut challenge, team, solution_id) =
+				Self::check_submission(&who, hackathon_id, challenge_id, team_id)?;
+			let bond = T::SubmissionBond::get();
+
+			// all checks passed, create a new solution object and write to storage
+			T::Deposit::reserve(&who, bond)?;
+			TeamHackathons::<T>::insert(&team_id, &hackathon_id, ());
+			for member in team.members.iter() {
+				Coauthorships::<T>::mutate(&hackathon_id, member, |count| {
+					*count = count.saturating_add(1)
+				});
+			}
+			Self::note_activity(&team, &who);
+			let new_solution = SubmittedSolution::<T> {
+				solution,
+				team_id,
+				submitter: who.clone(),
+				members: team.members,
+				anonymous,
+				submitted_at: frame_system::Pallet::<T>::block_number(),
+				draft,
+				bond,
+				disqualified: false,
+			};
+			ChallengeSolutions::<T>::insert(&challenge_id, solution_id, new_solution);
+			NextSolutionId::<T>::insert(&challenge_id, solution_id.saturating_add(1));
+
+			challenge.submissions = challenge.submissions.saturating_add(1);
+			Challenges::<T>::insert(&hackathon_id, &challenge_id, challenge);
+
+			if draft {
+				Self::deposit_event(Event::SolutionDrafted { challenge_id, solution_id });
+			} else {
+				let member = if anonymous { None } else { Some(who) };
+				Self::deposit_event(Event::SolutionSubmitted{ id: challenge_id, member });
+			}
+
+			Ok(())
+		}
+
+		/// Pay what is left of a claimed bounty's amount to its claimant and mark it approved,
+		/// granting its percentage of the team's prize.
+		fn approve(bounty_id: u32, mut bounty: Bounty<T>, claimant: T::AccountId) -> DispatchResult {
+			// bounties are only checked one at a time when posted, so open ones can add up to
+			// more than the whole prize
+			let granted = GrantedOwnership::<T>::get(&bounty.team_id)
+				.checked_add(bounty.percentage)
+				.filter(|granted| *granted <= 100)
+				.ok_or(Error::<T>::OwnershipExceeds100)?;
+
+			T::Deposit::repatriate_reserved(&bounty.poster, &claimant, bounty.amount, BalanceStatus::Free)?;
+
+			GrantedOwnership::<T>::insert(&bounty.team_id, granted);
+			PendingBounties::<T>::mutate(&bounty.team_id, |pending| *pending = pending.saturating_sub(1));
+			bounty.status = BountyStatus::Approved;
+			let amount = bounty.amount;
+			Bounties::<T>::insert(&bounty_id, bounty);
+			Self::add_reputation(&claimant, BOUNTY_REPUTATION);
+
+			Self::deposit_event(Event::BountyApproved { bounty_id, claimant, amount });
+
+			Ok(())
+		}
+
+		fn add_reputation(who: &T::AccountId, points: u32) {
+			Reputation::<T>::mutate(who, |score| *score = score.saturating_add(points));
+		}
+
+		/// The submission and voting windows of a hackathon, if it exists.
+		pub fn periods(hackathon_id: u32) -> Option<EventPeriods<T::BlockNumber>> {
+			Hackathons::<T>::get(&hackathon_id).and_then(|hackathon| hackathon.periods)
+		}
+
+		/// The ids of the hackathons whose voting has not ended, in registration order. Unlisted
+		/// hackathons are included.
+		pub fn active_hackathons() -> Vec<u32> {
+			// an end that could not be indexed is never announced, so check the periods too
+			ActiveHackathons::<T>::get()
+				.into_iter()
+				.filter(|id| !Self::has_voting_ended(*id))
+				.collect()
+		}
+
+		/// A hackathon by id, whether it is listed or not.
+		pub fn hackathon(hackathon_id: u32) -> Option<Hackathon<T>> {
+			Hackathons::<T>::get(&hackathon_id)
+		}
+
+		/// Up to `limit` public hackathons with their ids, in id order from `start`. Unlisted
+		/// hackathons are skipped, so a page may span more ids than `limit`.
+		pub fn hackathons(start: u32, limit: u32) -> Vec<(u32, Hackathon<T>)> {
+			(start..NextHackathonId::<T>::get())
+				.filter_map(|id| Hackathons::<T>::get(&id).map(|hackathon| (id, hackathon)))
+				.filter(|(_, hackathon)| hackathon.public)
+				.take(limit as usize)
+				.collect()
+		}
+
+		/// The metadata pointer attached to a hackathon, if it exists and has one.
+		pub fn hackathon_metadata(hackathon_id: u32) -> Option<H256> {
+			Hackathons::<T>::get(&hackathon_id).and_then(|hackathon| hackathon.metadata)
+		}
+
+		/// The timeline of a hackathon and the phase it is in, if it exists.
+		pub fn hackathon_schedule(hackathon_id: u32) -> Option<Schedule<T::BlockNumber>> {
+			let p = Self::periods(hackathon_id)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			let phase = if now < p.submission_start {
+				Phase::Upcoming
+			} else if now < p.submission_end {
+				Phase::Submission
+			} else if now < p.vote_start {
+				Phase::Review
+			} else if now < p.vote_end {
+				Phase::Voting
+			} else {
+				Phase::Ended
+			};
+			Some(Schedule {
+				submission_start: p.submission_start,
+				submission_end: p.submission_end,
+				vote_end: p.vote_end,
+				now,
+				phase,
+			})
+		}
+
+		/// Whether the current block is at or past the start of a hackathon's submission window.
+		pub fn has_submission_started(hackathon_id: u32) -> bool {
+			let now = frame_system::Pallet::<T>::block_number();
+			Self::periods(hackathon_id).map_or(false, |p| now >= p.submission_start)
+		}
+
+		/// Whether the current block is past the end of a hackathon's submission window.
+		pub fn has_submission_ended(hackathon_id: u32) -> bool {
+			let now = frame_system::Pallet::<T>::block_number();
+			Self::periods(hackathon_id).map_or(false, |p| now >= p.submission_end)
+		}
+
+		/// Whether the current block is at or past the start of a hackathon's voting window.
+		pub fn has_voting_started(hackathon_id: u32) -> bool {
+			let now = frame_system::Pallet::<T>::block_number();
+			Self::periods(hackathon_id).map_or(false, |p| now >= p.vote_start)
+		}
+
+		/// Whether the current block is past the end of a hackathon's voting window.
+		pub fn has_voting_ended(hackathon_id: u32) -> bool {
+			let now = frame_system::Pallet::<T>::block_number();
+			Self::periods(hackathon_id).map_or(false, |p| now >= p.vote_end)
+		}
+
+		/// Whether the current block is inside a hackathon's submission window.
+		pub fn is_submission_open(hackathon_id: u32) -> bool {
+			let now = frame_system::Pallet::<T>::block_number();
+			Self::periods(hackathon_id)
+				.map_or(false, |p| p.submission_start <= now && now < p.submission_end)
+		}
+
+		/// Whether the current block is inside a hackathon's voting window.
+		pub fn is_voting_open(hackathon_id: u32) -> bool {
+			let now = frame_system::Pallet::<T>::block_number();
+			Self::periods(hackathon_id).map_or(false, |p| p.vote_start <= now && now < p.vote_end)
+		}
+
+		/// Index a hackathon's period ends so `on_initialize` announces them. Either both ends are
+		/// indexed or, if one of their blocks is full, neither is.
+		fn schedule_phase_ends(
+			hackathon_id: u32,
+			periods: &EventPeriods<T::BlockNumber>,
+		) -> DispatchResult {
+			let has_room = |ids: BoundedVec<u32, T::MaxHackathonsPerBlock>| {
+				(ids.len() as u32) < T::MaxHackathonsPerBlock::get()
+			};
+			ensure!(
+				has_room(SubmissionEnds::<T>::get(periods.submission_end)) &&
+					has_room(VoteEnds::<T>::get(periods.vote_end)),
+				Error::<T>::TooManyPhaseEnds
+			);
+			SubmissionEnds::<T>::try_mutate(periods.submission_end, |ids| {
+				ids.try_push(hackathon_id)
+			})
+			.map_err(|_| Error::<T>::TooManyPhaseEnds)?;
+			VoteEnds::<T>::try_mutate(periods.vote_end, |ids| ids.try_push(hackathon_id))
+				.map_err(|_| Error::<T>::TooManyPhaseEnds)?;
+			Ok(())
+		}
+
+		/// Drop a hackathon's period ends from the index, once they are replaced. An emptied entry
+		/// is cleared when its block arrives.
+		fn unschedule_phase_ends(hackathon_id: u32, periods: &EventPeriods<T::BlockNumber>) {
+			SubmissionEnds::<T>::mutate(periods.submission_end, |ids| {
+				ids.retain(|id| *id != hackathon_id)
+			});
+			VoteEnds::<T>::mutate(periods.vote_end, |ids| ids.retain(|id| *id != hackathon_id));
+		}
+
+		/// Ensure `origin` is the admin origin or the signed organizer of `hackathon`.
+		fn ensure_organizer(origin: OriginFor<T>, hackathon: &Hackathon<T>) -> DispatchResult {
+			match T::AdminOrigin::try_origin(origin) {
+				Ok(_) => Ok(()),
+				Err(origin) => {
+					let who = ensure_signed(origin)?;
+					ensure!(who == hackathon.organizer, Error::<T>::NotHackathonOrganizer);
+					Ok(())
+				},
+			}
+		}
+
+		/// A team's members as shown for a hackathon. A private roster is empty until the
+		/// hackathon's voting has ended; the members are still in storage, so this only keeps
+		/// frontends from listing them.
+		pub fn team_members(hackathon_id: u32, team_id: u32) -> Vec<T::AccountId> {
+			match Teams::<T>::get(&team_id) {
+				Some(team) if !team.private_roster || Self::has_voting_ended(hackathon_id) =>
+					team.members.into_inner(),
+				_ => Vec::new(),
+			}
+		}
+
+		/// Note that `who` acted for a team, which keeps its founder role theirs if they hold it.
+		fn note_activity(team: &Team<T>, who: &T::AccountId) {
+			if team.team_founder == *who {
+				FounderActivity::<T>::insert(&team.team_id, frame_system::Pallet::<T>::block_number());
+			}
+		}
+
+		/// Record `who`'s approval of a bounty's next payout, the rest of it or one milestone,
+		/// and whether enough of the team's bounty managers now agree on it to pay it. The
+		/// threshold is capped at the number of managers the team has, so clearing the captain
+		/// never strands a claim.
+		fn record_approval(
+			team: &Team<T>,
+			bounty_id: u32,
+			who: &T::AccountId,
+			milestone: Option<u32>,
+		) -> Result<bool, DispatchError> {
+			ensure!(
+				BountyApprovals::<T>::get(&bounty_id, who) != Some(milestone),
+				Error::<T>::AlreadyApproved
+			);
+			BountyApprovals::<T>::insert(&bounty_id, who, milestone);
+
+			let mut managers = vec![&team.team_founder];
+			if let Some(captain) = team.captain.as_ref().filter(|captain| **captain != team.team_founder) {
+				managers.push(captain);
+			}
+			let required = team.required_bounty_approvals.clamp(1, managers.len() as u32);
+			let approvals = managers
+				.into_iter()
+				.filter(|manager| BountyApprovals::<T>::get(&bounty_id, *manager) == Some(milestone))
+				.count() as u32;
+			if approvals < required {
+				Self::deposit_event(Event::BountyApprovalRecorded {
+					bounty_id,
+					approver: who.clone(),
+					milestone,
+					approvals,
+				});
+				return Ok(false)
+			}
+			let _ = BountyApprovals::<T>::clear_prefix(&bounty_id, T::MaxMembers::get(), None);
+			Ok(true)
+		}
+
+		/// Whether `who` manages a team's bounties: its founder, or its captain if it has one.
+		fn is_bounty_manager(team: &Team<T>, who: &T::AccountId) -> bool {
+			team.team_founder == *who || team.captain.as_ref() == Some(who)
+		}
+
+		/// Whether a challenge has a solution with this id that judges can back: one that is
+		/// neither a draft nor disqualified.
+		fn is_eligible(challenge_id: u16, solution_id: u16) -> bool {
+			ChallengeSolutions::<T>::get(&challenge_id, &solution_id)
+				.map_or(false, |solution| !solution.draft && !solution.disqualified)
+		}
+
+		/// Return each submitter's bond once a challenge is settled, slashing the bonds of
+		/// disqualified solutions instead.
+		fn settle_submission_bonds(challenge_id: u16) {
+			let solutions: Vec<(u16, SubmittedSolution<T>)> =
+				ChallengeSolutions::<T>::iter_prefix(&challenge_id).collect();
+			for (solution_id, mut solution) in solutions {
+				if solution.disqualified {
+					let _ = T::Deposit::slash_reserved(&solution.submitter, solution.bond);
+				} else {
+					T::Deposit::unreserve(&solution.submitter, solution.bond);
+				}
+				solution.bond = Zero::zero();
+				ChallengeSolutions::<T>::insert(&challenge_id, &solution_id, solution);
+			}
+		}
+
+		/// Whether `who` is listed as a judge of `challenge`.
+		fn is_judge(challenge: &Challenge<T>, who: &T::AccountId) -> bool {
+			challenge.judges.as_ref().map_or(false, |judges| judges.contains(who)) &&
+				!BannedJudges::<T>::contains_key(who)
+		}
+
+		/// Whether `judge` is among the members of a solution submitted to a challenge. Rosters
+		/// only list accounts that accepted their invite, so nobody can be conflicted against their
+		/// will.
+		pub fn is_judge_conflicted(challenge_id: u16, judge: &T::AccountId) -> bool {
+			ChallengeSolutions::<T>::iter_prefix_values(&challenge_id)
+				.any(|solution| solution.members.contains(judge))
+		}
+
+		/// Remove repeated accounts from a judge list, keeping the first occurrence of each.
+		fn dedup_judges(
+			judges: BoundedVec<T::AccountId, T::MaxMembers>,
+		) -> BoundedVec<T::AccountId, T::MaxMembers> {
+			let mut unique = BoundedVec::<T::AccountId, T::MaxMembers>::default();
+			for judge in judges {
+				if !unique.contains(&judge) {
+					// cannot fail: `unique` never holds more entries than the input
+					let _ = unique.try_push(judge);
+				}
+			}
+			unique
+		}
+	}
 }
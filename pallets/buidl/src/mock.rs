@@ -1,15 +1,17 @@
 use crate as pallet_buidl;
-use crate as pallet_balances;
-use frame_support::traits::{ConstU16, ConstU32, ConstU64};
-use frame_system;
+use crate::{ProofVerifier, RoundingPolicy};
+use frame_support::{
+	parameter_types,
+	traits::{ConstU16, ConstU32, ConstU64, ConstU8},
+};
+use frame_system as system;
 use sp_core::H256;
 use sp_runtime::{
 	testing::Header,
 	traits::{BlakeTwo256, IdentityLookup},
+	Permill,
 };
 
-use sp_runtime::traits::parameter_types;
-
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
 type Block = frame_system::mocking::MockBlock<Test>;
 
@@ -22,17 +24,17 @@ frame_support::construct_runtime!(
 	{
 		System: frame_system,
 		Balances: pallet_balances,
+		Buidl: pallet_buidl,
 	}
 );
 
-
-impl frame_system::Config for Test {
+impl system::Config for Test {
 	type BaseCallFilter = frame_support::traits::Everything;
 	type BlockWeights = ();
 	type BlockLength = ();
 	type DbWeight = ();
-	type RuntimeOrigin = Self::RuntimeOrigin;
-	type RuntimeCall = Self::RuntimeCall;
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
 	type Index = u64;
 	type BlockNumber = u64;
 	type Hash = H256;
@@ -40,11 +42,11 @@ impl frame_system::Config for Test {
 	type AccountId = u64;
 	type Lookup = IdentityLookup<Self::AccountId>;
 	type Header = Header;
-	type RuntimeEvent = Self::RuntimeEvent;
+	type RuntimeEvent = RuntimeEvent;
 	type BlockHashCount = ConstU64<250>;
 	type Version = ();
-	type PalletInfo = Self::PalletInfo;
-	type AccountData = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<u64>;
 	type OnNewAccount = ();
 	type OnKilledAccount = ();
 	type SystemWeightInfo = ();
@@ -56,7 +58,32 @@ impl frame_system::Config for Test {
 parameter_types! {
 	pub const ExistentialDeposit: u64 = 1;
 	pub const MaxLocks: u32 = 10;
+	pub const RewardTierThresholds: (u64, u64) = (100, 500);
+	pub const HackathonBond: u64 = 50;
+	// no team or submission bond unless a test sets one, so balances stay round
+	pub static TeamBond: u64 = 0;
+	pub static SubmissionBond: u64 = 0;
+	pub static JudgeDeposit: u64 = 0;
+	// founders can create teams back to back unless a test sets a cooldown
+	pub static TeamCreationCooldown: u64 = 0;
+	pub const JudgeQuorum: Permill = Permill::from_percent(30);
+	// challenges hold their whole reward unless a test has them hold a deposit
+	pub static DepositFraction: Permill = Permill::from_percent(100);
+	pub static Rounding: RoundingPolicy = RoundingPolicy::RemainderToFounder;
+	// as many as a panel can cast unless a test caps them
+	pub static MaxCountedVotes: u32 = 5;
+	// rewards are paid out at once unless a test vests them
+	pub static VestingDuration: u64 = 0;
+	pub const TreasuryAccount: u64 = TREASURY;
 }
+
+/// Account that registers the hackathon every test starts with.
+pub const ORGANIZER: u64 = 100;
+/// Account funding treasury-backed challenges.
+pub const TREASURY: u64 = 99;
+/// Id of the hackathon registered in `new_test_ext`.
+pub const HACKATHON: u32 = 0;
+
 impl pallet_balances::Config for Test {
 	type Balance = u64;
 	type DustRemoval = ();
@@ -69,13 +96,66 @@ impl pallet_balances::Config for Test {
 	type ReserveIdentifier = [u8; 8];
 }
 
+/// Rejects a solution of all `0xff` bytes as a bad proof and accepts the rest.
+pub struct MockVerifier;
+impl ProofVerifier<u64> for MockVerifier {
+	fn verify(solution: &H256, _bounty_id: u32, _claimant: &u64) -> bool {
+		*solution != H256::repeat_byte(0xff)
+	}
+}
+
 impl pallet_buidl::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
+	type AdminOrigin = frame_system::EnsureRoot<u64>;
 	type Deposit = Balances;
+	type RewardCurrency = Balances;
+	type HackathonBond = HackathonBond;
+	type TeamBond = TeamBond;
+	type SubmissionBond = SubmissionBond;
+	type JudgeDeposit = JudgeDeposit;
+	type JudgeStrikeLimit = ConstU32<2>;
+	type TeamCreationCooldown = TeamCreationCooldown;
+	type FounderInactivityTimeout = ConstU64<50>;
+	type TreasuryAccount = TreasuryAccount;
+	type MaxHackathonDuration = ConstU64<100>;
+	// submissions open at block 5 and both periods end where the ones most tests set explicitly do
+	type DefaultCreationDuration = ConstU64<4>;
+	type DefaultSubmissionDuration = ConstU64<5>;
+	type DefaultVotingDuration = ConstU64<10>;
+	type MaxHackathonsPerBlock = ConstU32<2>;
+	type MaxActiveHackathons = ConstU32<3>;
+	type JudgeQuorum = JudgeQuorum;
+	type DepositFraction = DepositFraction;
+	type Rounding = Rounding;
+	type VestingDuration = VestingDuration;
+	type MaxCountedVotes = MaxCountedVotes;
+	type ProofVerifier = MockVerifier;
 	type MaxMembers = ConstU32<5>;
+	type MaxSolutions = ConstU32<10>;
+	type MaxEdits = ConstU32<2>;
+	type MaxTargets = ConstU32<3>;
+	type MaxContributors = ConstU32<2>;
+	type MaxEventJudges = ConstU32<6>;
+	type MaxPrizes = ConstU32<2>;
+	type MaxMilestones = ConstU32<3>;
+	type MaxSolutionsPerAccount = ConstU32<2>;
+	type PercentageStep = ConstU8<5>;
+	type RewardTierThresholds = RewardTierThresholds;
+	type WeightInfo = ();
 }
 
-// Build genesis storage according to the mock runtime.
+// Build genesis storage according to the mock runtime, with `HACKATHON` registered by `ORGANIZER`.
 pub fn new_test_ext() -> sp_io::TestExternalities {
-	system::GenesisConfig::default().build_storage::<Test>().unwrap().into()
+	let mut t = system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	pallet_balances::GenesisConfig::<Test> {
+		balances: vec![(1, 1_000), (2, 1_000), (3, 1_000), (4, 1_000), (5, 1_000), (ORGANIZER, 1_000), (TREASURY, 1_000)],
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		Buidl::register(RuntimeOrigin::signed(ORGANIZER), H256::zero()).unwrap();
+	});
+	ext
 }
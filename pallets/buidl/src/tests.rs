@@ -1,20 +1,3789 @@
-use crate::{mock::*, Error};
-use frame_support::{assert_noop, assert_ok};
+use crate::{
+	mock::*, Bounties, BountyStatus, Challenge, ChallengeArchive, ChallengeContributions, ChallengePrizes, ChallengeSolutions, ChallengeWinners, Challenges, Coauthorships, EarlyBonus, Error, EventJudges, EventPeriods, ExtensionSupport, GrantedOwnership,
+	FinalizationFailure, Hackathons, JudgeInvites, NextChallengeId, NextHackathonId, NextTeamId, Phase, RewardTier, RoundingPolicy, Schedule, SubmissionEnds, TeamInvites,
+	TeamTargets, Teams, UnawardedChallenges, VestingSchedules, Votes,
+};
+use crate::{BOUNTY_REPUTATION, WIN_REPUTATION};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{fungible::InspectHold, Get, Hooks},
+	BoundedVec,
+};
+use sp_core::H256;
+use sp_runtime::Permill;
+
+const SUBMISSION_START: u64 = 1;
+const SUBMISSION_END: u64 = 10;
+const VOTE_START: u64 = 10;
+const VOTE_END: u64 = 20;
+
+fn set_periods() {
+	assert_ok!(Buidl::update_period(
+		RuntimeOrigin::root(),
+		HACKATHON,
+		SUBMISSION_START,
+		SUBMISSION_END,
+		VOTE_START,
+		VOTE_END
+	));
+}
+
+/// Create a team and have every invited member accept.
+fn team(founder: u64, members: Vec<u64>) -> u32 {
+	let team_id = NextTeamId::<Test>::get();
+	assert_ok!(Buidl::create_team(RuntimeOrigin::signed(founder), members.clone()));
+	for member in members {
+		assert_ok!(Buidl::accept_team_invite(RuntimeOrigin::signed(member), team_id));
+	}
+	team_id
+}
+
+fn judges(accounts: Vec<u64>) -> Option<BoundedVec<u64, <Test as crate::Config>::MaxMembers>> {
+	Some(accounts.try_into().unwrap())
+}
+
+fn insert_challenge(id: u16, panel: Vec<u64>) {
+	Challenges::<Test>::insert(
+		HACKATHON,
+		id,
+		Challenge::<Test> {
+			author: 1,
+			funder: 1,
+			description: H256::repeat_byte(1),
+			reward: 10,
+			judges: judges(panel),
+			submissions: 0,
+			edits: 0,
+			submission_format: None,
+			early_bonus: None,
+			required_category: None,
+			outstanding: 0,
+			required_agreement: Permill::zero(),
+		},
+	);
+}
+
+#[test]
+fn create_challenge_deduplicates_judges() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Buidl::create_challenge(
+			RuntimeOrigin::signed(1),
+			HACKATHON,
+			H256::repeat_byte(1),
+			10,
+			judges(vec![2, 2, 3]),
+			None
+		));
+		let challenge = Challenges::<Test>::get(HACKATHON, 0).unwrap();
+		assert_eq!(challenge.judges.unwrap().into_inner(), vec![2, 3]);
+	});
+}
+
+#[test]
+fn challenge_rewards_are_reserved_additively() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Buidl::create_challenge(RuntimeOrigin::signed(1), HACKATHON, H256::repeat_byte(1), 10, None, None));
+		assert_ok!(Buidl::create_challenge(RuntimeOrigin::signed(1), HACKATHON, H256::repeat_byte(2), 20, None, None));
+		assert_eq!(Balances::reserved_balance(1), 30);
+		assert_eq!(<Balances as InspectHold<u64>>::balance_on_hold(&1), 30);
+		assert_eq!(Balances::free_balance(1), 970);
+
+		// each challenge releases only its own reward
+		assert_ok!(Buidl::cancel_challenge(RuntimeOrigin::signed(1), HACKATHON, 0));
+		assert_eq!(<Balances as InspectHold<u64>>::balance_on_hold(&1), 20);
+		assert_eq!(Challenges::<Test>::get(HACKATHON, 1).unwrap().reward, 20);
+		assert_eq!(Balances::free_balance(1), 980);
+	});
+}
+
+#[test]
+fn edit_challenge_works_before_submissions() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Buidl::create_challenge(RuntimeOrigin::signed(1), HACKATHON, H256::repeat_byte(1), 10, None, None));
+		assert_ok!(Buidl::edit_challenge(RuntimeOrigin::signed(1), HACKATHON, 0, H256::repeat_byte(2), None));
+		assert_eq!(Challenges::<Test>::get(HACKATHON, 0).unwrap().description, H256::repeat_byte(2));
+	});
+}
+
+#[test]
+fn edit_challenge_rejected_after_submissions() {
+	new_test_ext().execute_with(|| {
+		Challenges::<Test>::insert(
+			HACKATHON,
+			0,
+			Challenge::<Test> {
+				author: 1,
+				funder: 1,
+				description: H256::repeat_byte(1),
+				reward: 10,
+				judges: None,
+				submissions: 1,
+				edits: 0,
+				submission_format: None,
+				early_bonus: None,
+				required_category: None,
+				outstanding: 0,
+				required_agreement: Permill::zero(),
+			},
+		);
+		assert_noop!(
+			Buidl::edit_challenge(RuntimeOrigin::signed(1), HACKATHON, 0, H256::repeat_byte(2), None),
+			Error::<Test>::ChallengeLocked
+		);
+	});
+}
+
+#[test]
+fn late_edits_are_allowed_only_when_the_organizer_opts_in() {
+	new_test_ext().execute_with(|| {
+		challenge_with_two_solutions(10);
+		assert_noop!(
+			Buidl::edit_challenge(RuntimeOrigin::signed(1), HACKATHON, 0, H256::repeat_byte(2), None),
+			Error::<Test>::ChallengeLocked
+		);
+
+		assert_noop!(
+			Buidl::set_allow_late_edits(RuntimeOrigin::signed(1), HACKATHON, true),
+			Error::<Test>::NotHackathonOrganizer
+		);
+		assert_ok!(Buidl::set_allow_late_edits(RuntimeOrigin::signed(ORGANIZER), HACKATHON, true));
+		System::assert_last_event(crate::Event::LateEditsSet { hackathon_id: HACKATHON, allowed: true }.into());
+		assert!(Hackathons::<Test>::get(HACKATHON).unwrap().allow_late_edits);
+		assert_ok!(Buidl::edit_challenge(RuntimeOrigin::signed(1), HACKATHON, 0, H256::repeat_byte(2), None));
+		assert_eq!(Challenges::<Test>::get(HACKATHON, 0).unwrap().description, H256::repeat_byte(2));
+
+		assert_ok!(Buidl::set_allow_late_edits(RuntimeOrigin::root(), HACKATHON, false));
+		assert_noop!(
+			Buidl::edit_challenge(RuntimeOrigin::signed(1), HACKATHON, 0, H256::repeat_byte(3), None),
+			Error::<Test>::ChallengeLocked
+		);
+	});
+}
+
+#[test]
+fn organizer_can_attach_hackathon_metadata() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(Buidl::hackathon_metadata(HACKATHON), None);
+		assert_noop!(
+			Buidl::set_hackathon_metadata(RuntimeOrigin::signed(1), HACKATHON, H256::repeat_byte(9)),
+			Error::<Test>::NotHackathonOrganizer
+		);
+		assert_noop!(
+			Buidl::set_hackathon_metadata(RuntimeOrigin::root(), HACKATHON + 1, H256::repeat_byte(9)),
+			Error::<Test>::HackathonDoesNotExist
+		);
+
+		assert_ok!(Buidl::set_hackathon_metadata(RuntimeOrigin::signed(ORGANIZER), HACKATHON, H256::repeat_byte(9)));
+		System::assert_last_event(
+			crate::Event::HackathonMetadataSet { hackathon_id: HACKATHON, cid: H256::repeat_byte(9) }.into(),
+		);
+		assert_eq!(Buidl::hackathon_metadata(HACKATHON), Some(H256::repeat_byte(9)));
+
+		assert_ok!(Buidl::set_hackathon_metadata(RuntimeOrigin::root(), HACKATHON, H256::repeat_byte(8)));
+		assert_eq!(Hackathons::<Test>::get(HACKATHON).unwrap().metadata, Some(H256::repeat_byte(8)));
+	});
+}
+
+#[test]
+fn unlisted_hackathons_are_hidden_from_listings_but_fetchable_by_id() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Buidl::register(RuntimeOrigin::signed(1), H256::repeat_byte(7)));
+		assert_ok!(Buidl::register(RuntimeOrigin::signed(2), H256::repeat_byte(8)));
+		let ids = |page: Vec<(u32, crate::Hackathon<Test>)>| {
+			page.into_iter().map(|(id, _)| id).collect::<Vec<u32>>()
+		};
+		assert!(Buidl::hackathon(HACKATHON).unwrap().public);
+		assert_eq!(ids(Buidl::hackathons(0, 10)), vec![HACKATHON, HACKATHON + 1, HACKATHON + 2]);
+
+		assert_noop!(
+			Buidl::set_hackathon_visibility(RuntimeOrigin::signed(2), HACKATHON + 1, false),
+			Error::<Test>::NotHackathonOrganizer
+		);
+		assert_ok!(Buidl::set_hackathon_visibility(RuntimeOrigin::signed(1), HACKATHON + 1, false));
+		System::assert_last_event(
+			crate::Event::HackathonVisibilitySet { hackathon_id: HACKATHON + 1, public: false }.into(),
+		);
+
+		assert_eq!(ids(Buidl::hackathons(0, 10)), vec![HACKATHON, HACKATHON + 2]);
+		assert_eq!(ids(Buidl::hackathons(0, 1)), vec![HACKATHON]);
+		assert_eq!(ids(Buidl::hackathons(HACKATHON + 1, 1)), vec![HACKATHON + 2]);
+		assert!(Buidl::hackathons(HACKATHON + 3, 10).is_empty());
+		let unlisted = Buidl::hackathon(HACKATHON + 1).unwrap();
+		assert_eq!((unlisted.organizer, unlisted.public), (1, false));
+
+		assert_ok!(Buidl::set_hackathon_visibility(RuntimeOrigin::root(), HACKATHON + 1, true));
+		assert_eq!(ids(Buidl::hackathons(0, 10)), vec![HACKATHON, HACKATHON + 1, HACKATHON + 2]);
+	});
+}
+
+#[test]
+fn buidler_profile_lifecycle() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(Buidl::buidler_profile(1), None);
+		assert_noop!(
+			Buidl::update_buidler_profile(RuntimeOrigin::signed(1), H256::repeat_byte(2)),
+			Error::<Test>::BuidlerNotRegistered
+		);
+
+		assert_ok!(Buidl::register_buidler(RuntimeOrigin::signed(1), H256::repeat_byte(1)));
+		assert_eq!(Buidl::buidler_profile(1), Some(H256::repeat_byte(1)));
+		assert_noop!(
+			Buidl::register_buidler(RuntimeOrigin::signed(1), H256::repeat_byte(1)),
+			Error::<Test>::BuidlerAlreadyRegistered
+		);
+
+		assert_ok!(Buidl::update_buidler_profile(RuntimeOrigin::signed(1), H256::repeat_byte(2)));
+		assert_eq!(Buidl::buidler_profile(1), Some(H256::repeat_byte(2)));
+
+		assert_ok!(Buidl::deregister_buidler(RuntimeOrigin::signed(1)));
+		assert_eq!(Buidl::buidler_profile(1), None);
+		assert_noop!(
+			Buidl::deregister_buidler(RuntimeOrigin::signed(1)),
+			Error::<Test>::BuidlerNotRegistered
+		);
+	});
+}
+
+#[test]
+fn only_judges_can_request_info() {
+	new_test_ext().execute_with(|| {
+		set_periods();
+		assert_ok!(Buidl::create_challenge(
+			RuntimeOrigin::signed(1),
+			HACKATHON,
+			H256::repeat_byte(1),
+			10,
+			judges(vec![2]),
+			None
+		));
+		let team_id = team(3, vec![]);
+		assert_ok!(Buidl::submit_solution(RuntimeOrigin::signed(3), HACKATHON, 0, team_id, H256::repeat_byte(5), false));
+		assert_noop!(
+			Buidl::request_info(RuntimeOrigin::signed(3), HACKATHON, 0, 0, H256::repeat_byte(9)),
+			Error::<Test>::NotAJudge
+		);
+		assert_noop!(
+			Buidl::request_info(RuntimeOrigin::signed(2), HACKATHON, 1, 0, H256::repeat_byte(9)),
+			Error::<Test>::ChallengeDoesNotExist
+		);
+		assert_noop!(
+			Buidl::request_info(RuntimeOrigin::signed(2), HACKATHON, 0, 1, H256::repeat_byte(9)),
+			Error::<Test>::SolutionDoesNotExist
+		);
+
+		assert_ok!(Buidl::request_info(RuntimeOrigin::signed(2), HACKATHON, 0, 0, H256::repeat_byte(9)));
+		System::assert_last_event(
+			crate::Event::<Test>::InfoRequested {
+				challenge_id: 0,
+				solution_index: 0,
+				judge: 2,
+				note: H256::repeat_byte(9),
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn edits_are_capped_at_max_edits() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Buidl::create_challenge(RuntimeOrigin::signed(1), HACKATHON, H256::repeat_byte(1), 10, None, None));
+		assert_ok!(Buidl::edit_challenge(RuntimeOrigin::signed(1), HACKATHON, 0, H256::repeat_byte(2), None));
+		assert_ok!(Buidl::edit_challenge(RuntimeOrigin::signed(1), HACKATHON, 0, H256::repeat_byte(3), None));
+		assert_noop!(
+			Buidl::edit_challenge(RuntimeOrigin::signed(1), HACKATHON, 0, H256::repeat_byte(4), None),
+			Error::<Test>::TooManyEdits
+		);
+		assert_eq!(Challenges::<Test>::get(HACKATHON, 0).unwrap().edits, 2);
+	});
+}
+
+#[test]
+fn challenges_for_judge_lists_assignments() {
+	new_test_ext().execute_with(|| {
+		insert_challenge(0, vec![2, 3]);
+		insert_challenge(1, vec![3]);
+		insert_challenge(2, vec![2]);
+		assert_eq!(Buidl::challenges_for_judge(HACKATHON, &2), vec![0, 2]);
+		assert_eq!(Buidl::challenges_for_judge(HACKATHON, &3), vec![0, 1]);
+		assert!(Buidl::challenges_for_judge(HACKATHON, &4).is_empty());
+	});
+}
+
+#[test]
+fn submit_solution_stores_solution() {
+	new_test_ext().execute_with(|| {
+		set_periods();
+		assert_ok!(Buidl::create_challenge(RuntimeOrigin::signed(1), HACKATHON, H256::repeat_byte(1), 10, None, None));
+		let team_id = team(2, vec![3]);
+		assert_ok!(Buidl::submit_solution(RuntimeOrigin::signed(3), HACKATHON, 0, team_id, H256::repeat_byte(5), false));
+
+		let solution = ChallengeSolutions::<Test>::get(0, 0).unwrap();
+		assert_eq!(solution.solution, H256::repeat_byte(5));
+		assert_eq!(solution.team_id, team_id);
+		assert_eq!(solution.members.into_inner(), vec![2, 3]);
+		assert_eq!(Challenges::<Test>::get(HACKATHON, 0).unwrap().submissions, 1);
+	});
+}
+
+#[test]
+fn submit_solution_requires_a_registered_team_member() {
+	new_test_ext().execute_with(|| {
+		set_periods();
+		assert_ok!(Buidl::create_challenge(RuntimeOrigin::signed(1), HACKATHON, H256::repeat_byte(1), 10, None, None));
+		let team_id = team(2, vec![3]);
+		assert_noop!(
+			Buidl::submit_solution(RuntimeOrigin::signed(2), HACKATHON, 0, team_id + 1, H256::repeat_byte(5), false),
+			Error::<Test>::TeamDoesNotExist
+		);
+		assert_noop!(
+			Buidl::submit_solution(RuntimeOrigin::signed(4), HACKATHON, 0, team_id, H256::repeat_byte(5), false),
+			Error::<Test>::NotATeamMember
+		);
+	});
+}
+
+#[test]
+fn submit_solution_rejects_same_team_twice() {
+	new_test_ext().execute_with(|| {
+		set_periods();
+		assert_ok!(Buidl::create_challenge(RuntimeOrigin::signed(1), HACKATHON, H256::repeat_byte(1), 10, None, None));
+		let team_id = team(2, vec![3]);
+		assert_ok!(Buidl::submit_solution(RuntimeOrigin::signed(2), HACKATHON, 0, team_id, H256::repeat_byte(5), false));
+		assert_noop!(
+			Buidl::submit_solution(RuntimeOrigin::signed(3), HACKATHON, 0, team_id, H256::repeat_byte(6), false),
+			Error::<Test>::SolutionAlreadySubmitted
+		);
+		assert_eq!(Challenges::<Test>::get(HACKATHON, 0).unwrap().submissions, 1);
+	});
+}
+
+#[test]
+fn submit_solution_appends_solutions() {
+	new_test_ext().execute_with(|| {
+		set_periods();
+		assert_ok!(Buidl::create_challenge(RuntimeOrigin::signed(1), HACKATHON, H256::repeat_byte(1), 10, None, None));
+		let first = team(2, vec![]);
+		let second = team(3, vec![]);
+		assert_ok!(Buidl::submit_solution(RuntimeOrigin::signed(2), HACKATHON, 0, first, H256::repeat_byte(5), false));
+		assert_ok!(Buidl::submit_solution(RuntimeOrigin::signed(3), HACKATHON, 0, second, H256::repeat_byte(6), false));
+
+		assert_eq!(ChallengeSolutions::<Test>::get(0, 0).unwrap().solution, H256::repeat_byte(5));
+		assert_eq!(ChallengeSolutions::<Test>::get(0, 1).unwrap().solution, H256::repeat_byte(6));
+		assert_eq!(Challenges::<Test>::get(HACKATHON, 0).unwrap().submissions, 2);
+	});
+}
+
+#[test]
+fn submit_solution_rejects_beyond_max_solutions() {
+	new_test_ext().execute_with(|| {
+		set_periods();
+		assert_ok!(Buidl::create_challenge(RuntimeOrigin::signed(1), HACKATHON, H256::repeat_byte(1), 10, None, None));
+		for founder in 0..10u64 {
+			let team_id = team(founder, vec![]);
+			assert_ok!(Buidl::submit_solution(
+				RuntimeOrigin::signed(founder),
+				HACKATHON,
+				0,
+				team_id,
+				H256::repeat_byte(5),
+				false
+			));
+		}
+		let team_id = team(10, vec![]);
+		assert_noop!(
+			Buidl::submit_solution(RuntimeOrigin::signed(10), HACKATHON, 0, team_id, H256::repeat_byte(5), false),
+			Error::<Test>::TooManySolutions
+		);
+	});
+}
+
+#[test]
+fn create_team_registers_founder_and_invites_members() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Buidl::create_team(RuntimeOrigin::signed(1), vec![2, 3, 2, 1]));
+		let team = Teams::<Test>::get(0).unwrap();
+		assert_eq!(team.team_founder, 1);
+		assert_eq!(team.members.into_inner(), vec![1]);
+		assert_eq!(NextTeamId::<Test>::get(), 1);
+		System::assert_has_event(crate::Event::<Test>::TeamCreated { team_id: 0, founder: 1 }.into());
+		System::assert_last_event(crate::Event::<Test>::MemberInvited { team_id: 0, member: 3 }.into());
+		assert!(TeamInvites::<Test>::contains_key(0, 2) && TeamInvites::<Test>::contains_key(0, 3));
+
+		assert_noop!(Buidl::accept_team_invite(RuntimeOrigin::signed(4), 0), Error::<Test>::NoTeamInvite);
+		assert_ok!(Buidl::accept_team_invite(RuntimeOrigin::signed(3), 0));
+		System::assert_last_event(crate::Event::<Test>::MemberAdded { team_id: 0, member: 3 }.into());
+		assert!(!TeamInvites::<Test>::contains_key(0, 3));
+		assert_eq!(Teams::<Test>::get(0).unwrap().members.into_inner(), vec![1, 3]);
+		assert_noop!(Buidl::accept_team_invite(RuntimeOrigin::signed(3), 0), Error::<Test>::NoTeamInvite);
+
+		assert_noop!(
+			Buidl::create_team(RuntimeOrigin::signed(1), vec![2, 3, 4, 5, 6]),
+			Error::<Test>::TooManyMembers
+		);
+	});
+}
+
+#[test]
+fn add_member_works_for_members_only() {
+	new_test_ext().execute_with(|| {
+		team(1, vec![2]);
+		assert_noop!(
+			Buidl::add_member(RuntimeOrigin::signed(3), 0, 4),
+			Error::<Test>::NotATeamMember
+		);
+		assert_noop!(
+			Buidl::add_member(RuntimeOrigin::signed(1), 1, 4),
+			Error::<Test>::TeamDoesNotExist
+		);
+		assert_noop!(
+			Buidl::add_member(RuntimeOrigin::signed(1), 0, 2),
+			Error::<Test>::AlreadyTeamMember
+		);
+
+		assert_ok!(Buidl::add_member(RuntimeOrigin::signed(2), 0, 3));
+		System::assert_last_event(crate::Event::<Test>::MemberInvited { team_id: 0, member: 3 }.into());
+		assert_noop!(
+			Buidl::add_member(RuntimeOrigin::signed(1), 0, 3),
+			Error::<Test>::AlreadyTeamMember
+		);
+		assert_eq!(Teams::<Test>::get(0).unwrap().members.into_inner(), vec![1, 2]);
+		assert_ok!(Buidl::accept_team_invite(RuntimeOrigin::signed(3), 0));
+		assert_eq!(Teams::<Test>::get(0).unwrap().members.into_inner(), vec![1, 2, 3]);
+	});
+}
+
+#[test]
+fn add_member_rejects_full_team() {
+	new_test_ext().execute_with(|| {
+		// pending invites take up seats too
+		assert_ok!(Buidl::create_team(RuntimeOrigin::signed(1), vec![2, 3, 4, 5]));
+		assert_noop!(
+			Buidl::add_member(RuntimeOrigin::signed(1), 0, 6),
+			Error::<Test>::TooManyMembers
+		);
+	});
+}
+
+#[test]
+fn listing_an_account_on_teams_it_never_joined_cannot_use_up_its_coauthorships() {
+	new_test_ext().execute_with(|| {
+		set_periods();
+		for _ in 0..3 {
+			assert_ok!(Buidl::create_challenge(
+				RuntimeOrigin::signed(1),
+				HACKATHON,
+				H256::repeat_byte(1),
+				10,
+				None,
+				None
+			));
+		}
+		// throwaway teams invite 5, who never accepts
+		for (founder, challenge_id) in [(2, 0), (3, 1)] {
+			let team_id = NextTeamId::<Test>::get();
+			assert_ok!(Buidl::create_team(RuntimeOrigin::signed(founder), vec![5]));
+			assert_ok!(Buidl::submit_solution(
+				RuntimeOrigin::signed(founder),
+				HACKATHON,
+				challenge_id,
+				team_id,
+				H256::repeat_byte(5),
+				false
+			));
+			assert_eq!(ChallengeSolutions::<Test>::get(challenge_id, 0).unwrap().members.into_inner(), vec![founder]);
+		}
+		assert_eq!(Coauthorships::<Test>::get(HACKATHON, 5), 0);
+
+		let own = team(5, vec![]);
+		assert_ok!(Buidl::submit_solution(RuntimeOrigin::signed(5), HACKATHON, 2, own, H256::repeat_byte(6), false));
+	});
+}
+
+#[test]
+fn create_challenge_rejects_reward_above_free_balance() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Buidl::create_challenge(RuntimeOrigin::signed(1), HACKATHON, H256::repeat_byte(1), 2_000, None, None),
+			Error::<Test>::InsufficientBalance
+		);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert!(Challenges::<Test>::get(HACKATHON, 0).is_none());
+	});
+}
+
+#[test]
+fn judges_can_vote_and_revise() {
+	new_test_ext().execute_with(|| {
+		set_periods();
+		assert_ok!(Buidl::create_challenge(
+			RuntimeOrigin::signed(1),
+			HACKATHON,
+			H256::repeat_byte(1),
+			10,
+			judges(vec![2]),
+			None
+		));
+		let first = team(3, vec![]);
+		let second = team(4, vec![]);
+		assert_ok!(Buidl::submit_solution(RuntimeOrigin::signed(3), HACKATHON, 0, first, H256::repeat_byte(5), false));
+		assert_ok!(Buidl::submit_solution(RuntimeOrigin::signed(4), HACKATHON, 0, second, H256::repeat_byte(6), false));
+
+		System::set_block_number(VOTE_START);
+		assert_ok!(Buidl::vote(RuntimeOrigin::signed(2), HACKATHON, 0, 0));
+		assert_eq!(Votes::<Test>::get(0, 2), Some(0));
+		System::assert_last_event(
+			crate::Event::<Test>::VoteCast { challenge_id: 0, judge: 2, solution_id: 0 }.into(),
+		);
+
+		assert_ok!(Buidl::vote(RuntimeOrigin::signed(2), HACKATHON, 0, 1));
+		assert_eq!(Votes::<Test>::get(0, 2), Some(1));
+	});
+}
+
+#[test]
+fn vote_is_rejected_for_non_judges_and_unknown_solutions() {
+	new_test_ext().execute_with(|| {
+		set_periods();
+		assert_ok!(Buidl::create_challenge(
+			RuntimeOrigin::signed(1),
+			HACKATHON,
+			H256::repeat_byte(1),
+			10,
+			judges(vec![2]),
+			None
+		));
+		let team_id = team(3, vec![]);
+		assert_ok!(Buidl::submit_solution(RuntimeOrigin::signed(3), HACKATHON, 0, team_id, H256::repeat_byte(5), false));
+
+		System::set_block_number(VOTE_START);
+		assert_noop!(Buidl::vote(RuntimeOrigin::signed(4), HACKATHON, 0, 0), Error::<Test>::NotAJudge);
+		assert_noop!(Buidl::vote(RuntimeOrigin::signed(2), HACKATHON, 0, 1), Error::<Test>::SolutionDoesNotExist);
+		assert_noop!(Buidl::vote(RuntimeOrigin::signed(2), HACKATHON, 1, 0), Error::<Test>::ChallengeDoesNotExist);
+	});
+}
+
+#[test]
+fn add_judges_merges_and_deduplicates() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Buidl::create_challenge(RuntimeOrigin::signed(1), HACKATHON, H256::repeat_byte(1), 10, None, None));
+
+		assert_ok!(Buidl::add_judges(RuntimeOrigin::signed(1), HACKATHON, 0, vec![2, 3]));
+		assert_ok!(Buidl::add_judges(RuntimeOrigin::signed(1), HACKATHON, 0, vec![3, 4, 4]));
+		System::assert_last_event(
+			crate::Event::<Test>::JudgesInvited { challenge_id: 0, judges: vec![4] }.into(),
+		);
+		for judge in [2, 3, 4] {
+			assert_ok!(Buidl::accept_judge_role(RuntimeOrigin::signed(judge), HACKATHON, 0));
+		}
+		assert_eq!(Challenges::<Test>::get(HACKATHON, 0).unwrap().judges.unwrap().into_inner(), vec![2, 3, 4]);
+
+		assert_noop!(
+			Buidl::add_judges(RuntimeOrigin::signed(1), HACKATHON, 0, vec![2]),
+			Error::<Test>::DuplicateJudge
+		);
+	});
+}
+
+#[test]
+fn add_judges_is_author_only_and_bounded() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Buidl::create_challenge(RuntimeOrigin::signed(1), HACKATHON, H256::repeat_byte(1), 10, None, None));
+		assert_noop!(
+			Buidl::add_judges(RuntimeOrigin::signed(2), HACKATHON, 0, vec![2]),
+			Error::<Test>::NotChallengeAuthor
+		);
+		assert_noop!(
+			Buidl::add_judges(RuntimeOrigin::signed(1), HACKATHON, 0, vec![2, 3, 4, 5, 6, 7]),
+			Error::<Test>::TooManyMembers
+		);
+	});
+}
+
+#[test]
+fn declared_targets_are_queryable() {
+	new_test_ext().execute_with(|| {
+		insert_challenge(0, vec![]);
+		insert_challenge(1, vec![]);
+		let first = team(2, vec![3]);
+		let second = team(4, vec![]);
+
+		assert_ok!(Buidl::declare_targets(RuntimeOrigin::signed(3), first, HACKATHON, vec![0, 1].try_into().unwrap()));
+		assert_ok!(Buidl::declare_targets(RuntimeOrigin::signed(4), second, HACKATHON, vec![1].try_into().unwrap()));
+
+		assert_eq!(TeamTargets::<Test>::get(first).into_inner(), vec![0, 1]);
+		assert_eq!(Buidl::teams_targeting(0), vec![first]);
+		assert_eq!(Buidl::teams_targeting(1), vec![first, second]);
+	});
+}
+
+#[test]
+fn declared_targets_must_exist_and_come_from_members() {
+	new_test_ext().execute_with(|| {
+		insert_challenge(0, vec![]);
+		let team_id = team(2, vec![]);
+
+		assert_noop!(
+			Buidl::declare_targets(RuntimeOrigin::signed(2), team_id, HACKATHON, vec![0, 7].try_into().unwrap()),
+			Error::<Test>::ChallengeDoesNotExist
+		);
+		assert_noop!(
+			Buidl::declare_targets(RuntimeOrigin::signed(3), team_id, HACKATHON, vec![0].try_into().unwrap()),
+			Error::<Test>::NotATeamMember
+		);
+	});
+}
+
+#[test]
+fn edit_challenge_rejects_non_author() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Buidl::create_challenge(RuntimeOrigin::signed(1), HACKATHON, H256::repeat_byte(1), 10, None, None));
+		assert_eq!(Challenges::<Test>::get(HACKATHON, 0).unwrap().author, 1);
+		assert_noop!(
+			Buidl::edit_challenge(RuntimeOrigin::signed(2), HACKATHON, 0, H256::repeat_byte(2), None),
+			Error::<Test>::NotChallengeAuthor
+		);
+	});
+}
+
+#[test]
+fn edit_challenge_rejects_missing_challenge() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Buidl::edit_challenge(RuntimeOrigin::signed(1), HACKATHON, 0, H256::repeat_byte(2), None),
+			Error::<Test>::ChallengeDoesNotExist
+		);
+		assert_ok!(Buidl::create_challenge(RuntimeOrigin::signed(1), HACKATHON, H256::repeat_byte(1), 10, None, None));
+		assert_noop!(
+			Buidl::edit_challenge(RuntimeOrigin::signed(1), HACKATHON + 1, 0, H256::repeat_byte(2), None),
+			Error::<Test>::ChallengeDoesNotExist
+		);
+	});
+}
+
+#[test]
+fn challenge_ids_advance() {
+	new_test_ext().execute_with(|| {
+		for byte in 0..3u8 {
+			assert_ok!(Buidl::create_challenge(
+				RuntimeOrigin::signed(1),
+				HACKATHON,
+				H256::repeat_byte(byte),
+				10,
+				None,
+				None
+			));
+		}
+		for id in 0..3u16 {
+			assert_eq!(Challenges::<Test>::get(HACKATHON, id).unwrap().description, H256::repeat_byte(id as u8));
+		}
+		assert_eq!(NextChallengeId::<Test>::get(), 3);
+	});
+}
+
+#[test]
+fn challenge_id_overflow_is_rejected() {
+	new_test_ext().execute_with(|| {
+		NextChallengeId::<Test>::put(u16::MAX);
+		assert_noop!(
+			Buidl::create_challenge(RuntimeOrigin::signed(1), HACKATHON, H256::repeat_byte(1), 10, None, None),
+			Error::<Test>::ChallengeIdOverflow
+		);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), 1_000);
+	});
+}
+
+#[test]
+fn endorsements_are_counted_once_per_account() {
+	new_test_ext().execute_with(|| {
+		let team_id = team(1, vec![]);
+		assert_noop!(
+			Buidl::endorse_team(RuntimeOrigin::signed(2), team_id + 1),
+			Error::<Test>::TeamDoesNotExist
+		);
+
+		assert_ok!(Buidl::endorse_team(RuntimeOrigin::signed(2), team_id));
+		assert_ok!(Buidl::endorse_team(RuntimeOrigin::signed(2), team_id));
+		assert_ok!(Buidl::endorse_team(RuntimeOrigin::signed(3), team_id));
+		assert_eq!(Buidl::endorsement_count(team_id), 2);
+
+		assert_ok!(Buidl::unendorse_team(RuntimeOrigin::signed(2), team_id));
+		assert_ok!(Buidl::unendorse_team(RuntimeOrigin::signed(2), team_id));
+		assert_eq!(Buidl::endorsement_count(team_id), 1);
+		assert_ok!(Buidl::unendorse_team(RuntimeOrigin::signed(4), team_id));
+		assert_eq!(Buidl::endorsement_count(team_id), 1);
+	});
+}
+
+#[test]
+fn update_period_is_organizer_only_and_ordered() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Buidl::update_period(RuntimeOrigin::signed(1), HACKATHON, 1, 10, 10, 20),
+			Error::<Test>::NotHackathonOrganizer
+		);
+		assert_noop!(
+			Buidl::update_period(RuntimeOrigin::root(), HACKATHON + 1, 1, 10, 10, 20),
+			Error::<Test>::HackathonDoesNotExist
+		);
+		assert_noop!(
+			Buidl::update_period(RuntimeOrigin::signed(ORGANIZER), HACKATHON, 1, 10, 5, 20),
+			Error::<Test>::InvalidPeriod
+		);
+		set_periods();
+		assert_eq!(
+			Buidl::periods(HACKATHON),
+			Some(EventPeriods {
+				submission_start: SUBMISSION_START,
+				submission_end: SUBMISSION_END,
+				vote_start: VOTE_START,
+				vote_end: VOTE_END,
+			})
+		);
+	});
+}
+
+#[test]
+fn submissions_only_accepted_in_submission_window() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Buidl::create_challenge(RuntimeOrigin::signed(1), HACKATHON, H256::repeat_byte(1), 10, None, None));
+		let team_id = team(2, vec![]);
+		assert_noop!(
+			Buidl::submit_solution(RuntimeOrigin::signed(2), HACKATHON, 0, team_id, H256::repeat_byte(5), false),
+			Error::<Test>::SubmissionPeriodClosed
+		);
+
+		set_periods();
+		System::set_block_number(SUBMISSION_END);
+		assert_noop!(
+			Buidl::submit_solution(RuntimeOrigin::signed(2), HACKATHON, 0, team_id, H256::repeat_byte(5), false),
+			Error::<Test>::SubmissionPeriodClosed
+		);
+
+		System::set_block_number(SUBMISSION_END - 1);
+		assert_ok!(Buidl::submit_solution(RuntimeOrigin::signed(2), HACKATHON, 0, team_id, H256::repeat_byte(5), false));
+	});
+}
+
+#[test]
+fn votes_only_accepted_in_voting_window() {
+	new_test_ext().execute_with(|| {
+		set_periods();
+		assert_ok!(Buidl::create_challenge(
+			RuntimeOrigin::signed(1),
+			HACKATHON,
+			H256::repeat_byte(1),
+			10,
+			judges(vec![2]),
+			None
+		));
+		let team_id = team(3, vec![]);
+		assert_ok!(Buidl::submit_solution(RuntimeOrigin::signed(3), HACKATHON, 0, team_id, H256::repeat_byte(5), false));
+
+		assert_noop!(Buidl::vote(RuntimeOrigin::signed(2), HACKATHON, 0, 0), Error::<Test>::VotingPeriodClosed);
+		System::set_block_number(VOTE_END);
+		assert_noop!(Buidl::vote(RuntimeOrigin::signed(2), HACKATHON, 0, 0), Error::<Test>::VotingPeriodClosed);
+
+		System::set_block_number(VOTE_START);
+		assert_ok!(Buidl::vote(RuntimeOrigin::signed(2), HACKATHON, 0, 0));
+	});
+}
+
+#[test]
+fn total_rewards_tracks_outstanding_prizes() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(Buidl::total_rewards(HACKATHON), 0);
+		assert_ok!(Buidl::create_challenge(RuntimeOrigin::signed(1), HACKATHON, H256::repeat_byte(1), 10, None, None));
+		assert_ok!(Buidl::create_challenge(RuntimeOrigin::signed(2), HACKATHON, H256::repeat_byte(2), 25, None, None));
+		assert_eq!(Buidl::total_rewards(HACKATHON), 35);
+		assert_eq!(Buidl::total_rewards(HACKATHON + 1), 0);
+
+		assert_ok!(Buidl::cancel_challenge(RuntimeOrigin::signed(2), HACKATHON, 1));
+		assert_eq!(Buidl::total_rewards(HACKATHON), 10);
+
+		// a settled challenge no longer has money at stake
+		set_periods();
+		System::set_block_number(VOTE_END);
+		assert_ok!(Buidl::finalize_challenge(RuntimeOrigin::signed(1), HACKATHON, 0));
+		assert_eq!(Buidl::total_rewards(HACKATHON), 0);
+	});
+}
+
+#[test]
+fn leaderboard_ranks_teams_by_rewards_paid() {
+	new_test_ext().execute_with(|| {
+		set_periods();
+		for reward in [11, 31] {
+			assert_ok!(Buidl::create_challenge(
+				RuntimeOrigin::signed(1),
+				HACKATHON,
+				H256::repeat_byte(1),
+				reward,
+				judges(vec![4, 5, 6]),
+				None
+			));
+		}
+		let first = team(2, vec![3]);
+		let second = team(7, vec![]);
+		for challenge_id in [0, 1] {
+			for (founder, team_id) in [(2, first), (7, second)] {
+				assert_ok!(Buidl::submit_solution(
+					RuntimeOrigin::signed(founder),
+					HACKATHON,
+					challenge_id,
+					team_id,
+					H256::repeat_byte(5),
+					false
+				));
+			}
+		}
+		System::set_block_number(VOTE_START);
+		assert_ok!(Buidl::vote(RuntimeOrigin::signed(4), HACKATHON, 0, 0));
+		assert_ok!(Buidl::vote(RuntimeOrigin::signed(4), HACKATHON, 1, 1));
+		assert!(Buidl::leaderboard(HACKATHON).is_empty());
+
+		System::set_block_number(VOTE_END);
+		assert_ok!(Buidl::finalize_challenge(RuntimeOrigin::signed(1), HACKATHON, 0));
+		// the odd unit of the first reward goes to the founder, so the team is paid all of it
+		assert_eq!(Buidl::leaderboard(HACKATHON), vec![(first, 11)]);
+		assert_ok!(Buidl::finalize_challenge(RuntimeOrigin::signed(1), HACKATHON, 1));
+		assert_eq!(Buidl::leaderboard(HACKATHON), vec![(second, 31), (first, 10)]);
+		assert!(Buidl::leaderboard(HACKATHON + 1).is_empty());
+	});
+}
+
+#[test]
+fn reward_tier_respects_thresholds() {
+	new_test_ext().execute_with(|| {
+		for (author, reward) in [(1, 99), (2, 100), (3, 499), (4, 500)] {
+			assert_ok!(Buidl::create_challenge(
+				RuntimeOrigin::signed(author),
+				HACKATHON,
+				H256::repeat_byte(1),
+				reward,
+				None,
+				None
+			));
+		}
+		assert_eq!(Buidl::reward_tier(HACKATHON, 0), Some(RewardTier::Small));
+		assert_eq!(Buidl::reward_tier(HACKATHON, 1), Some(RewardTier::Medium));
+		assert_eq!(Buidl::reward_tier(HACKATHON, 2), Some(RewardTier::Medium));
+		assert_eq!(Buidl::reward_tier(HACKATHON, 3), Some(RewardTier::Large));
+		assert_eq!(Buidl::reward_tier(HACKATHON, 4), None);
+	});
+}
+
+#[test]
+fn only_the_founder_sets_a_team_category() {
+	new_test_ext().execute_with(|| {
+		let team_id = team(2, vec![3]);
+		assert_noop!(
+			Buidl::set_team_category(RuntimeOrigin::signed(3), team_id, Some(1)),
+			Error::<Test>::NotTeamFounder
+		);
+		assert_ok!(Buidl::set_team_category(RuntimeOrigin::signed(2), team_id, Some(1)));
+		System::assert_last_event(crate::Event::TeamCategorySet { team_id, category: Some(1) }.into());
+		assert_eq!(Teams::<Test>::get(team_id).unwrap().category, Some(1));
+		assert_noop!(
+			Buidl::set_team_category(RuntimeOrigin::signed(2), team_id + 1, None),
+			Error::<Test>::TeamDoesNotExist
+		);
+	});
+}
+
+#[test]
+fn members_can_claim_the_founder_role_only_after_the_founder_goes_inactive() {
+	new_test_ext().execute_with(|| {
+		TeamBond::set(10);
+		let team_id = team(1, vec![2, 3]);
+		// created at block 1, with a timeout of 50 blocks
+		System::set_block_number(50);
+		assert_noop!(Buidl::claim_founder(RuntimeOrigin::signed(2), team_id), Error::<Test>::FounderStillActive);
+		// acting for the team restarts the timeout
+		assert_ok!(Buidl::set_team_category(RuntimeOrigin::signed(1), team_id, Some(1)));
+		System::set_block_number(51);
+		assert_noop!(Buidl::claim_founder(RuntimeOrigin::signed(2), team_id), Error::<Test>::FounderStillActive);
+
+		System::set_block_number(100);
+		assert_noop!(Buidl::claim_founder(RuntimeOrigin::signed(4), team_id), Error::<Test>::NotATeamMember);
+		assert_noop!(Buidl::claim_founder(RuntimeOrigin::signed(1), team_id), Error::<Test>::FounderStillActive);
+		assert_ok!(Buidl::claim_founder(RuntimeOrigin::signed(2), team_id));
+		System::assert_last_event(crate::Event::FounderClaimed { team_id, founder: 2 }.into());
+		let team = Teams::<Test>::get(team_id).unwrap();
+		assert_eq!(team.team_founder, 2);
+		assert_eq!(team.members.into_inner(), vec![1, 2, 3]);
+		// the bond moves to the new founder
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::reserved_balance(2), 10);
+
+		assert_noop!(
+			Buidl::set_team_category(RuntimeOrigin::signed(1), team_id, None),
+			Error::<Test>::NotTeamFounder
+		);
+		assert_noop!(Buidl::claim_founder(RuntimeOrigin::signed(3), team_id), Error::<Test>::FounderStillActive);
+		assert_ok!(Buidl::disband_team(RuntimeOrigin::signed(2), team_id));
+		assert_eq!(Balances::reserved_balance(2), 0);
+	});
+}
+
+#[test]
+fn team_bond_is_reserved_on_creation_and_returned_on_disband() {
+	new_test_ext().execute_with(|| {
+		TeamBond::set(10);
+		assert_noop!(
+			Buidl::create_team(RuntimeOrigin::signed(6), vec![]),
+			Error::<Test>::InsufficientBalanceForTeamBond
+		);
+		let team_id = team(1, vec![2]);
+		assert_ok!(Buidl::add_member(RuntimeOrigin::signed(1), team_id, 3));
+		assert_eq!(Balances::reserved_balance(1), 10);
+		assert_eq!(Teams::<Test>::get(team_id).unwrap().bond, 10);
+
+		assert_ok!(Buidl::post_bounty(RuntimeOrigin::signed(1), team_id, HACKATHON, H256::repeat_byte(1), 5, 10, 0));
+		assert_noop!(
+			Buidl::disband_team(RuntimeOrigin::signed(1), team_id),
+			Error::<Test>::TeamHasPendingBounties
+		);
+		assert_ok!(Buidl::cancel_bounty(RuntimeOrigin::signed(1), 0));
+		assert_noop!(
+			Buidl::disband_team(RuntimeOrigin::signed(2), team_id),
+			Error::<Test>::NotTeamFounder
+		);
+
+		assert_ok!(Buidl::disband_team(RuntimeOrigin::signed(1), team_id));
+		System::assert_last_event(crate::Event::TeamDisbanded { team_id, bond: 10 }.into());
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), 1_000);
+		assert!(Teams::<Test>::get(team_id).is_none());
+		assert!(!TeamInvites::<Test>::contains_key(team_id, 3));
+	});
+}
+
+#[test]
+fn admin_sets_a_team_size_limit_within_max_members() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(Hackathons::<Test>::get(HACKATHON).unwrap().max_team_size, 5);
+		assert_noop!(
+			Buidl::update_team_size(RuntimeOrigin::signed(ORGANIZER), HACKATHON, 3),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_noop!(Buidl::update_team_size(RuntimeOrigin::root(), HACKATHON, 6), Error::<Test>::InvalidTeamSize);
+		assert_noop!(Buidl::update_team_size(RuntimeOrigin::root(), HACKATHON, 0), Error::<Test>::InvalidTeamSize);
+
+		assert_ok!(Buidl::update_team_size(RuntimeOrigin::root(), HACKATHON, 2));
+		assert_ok!(Buidl::update_team_size(RuntimeOrigin::root(), HACKATHON, 5));
+		System::assert_last_event(crate::Event::TeamSizeUpdated { hackathon_id: HACKATHON, max_team_size: 5 }.into());
+		assert_eq!(Hackathons::<Test>::get(HACKATHON).unwrap().max_team_size, 5);
+	});
+}
+
+#[test]
+fn teams_enter_within_the_size_limit_and_are_grandfathered() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Buidl::create_challenge(RuntimeOrigin::signed(1), HACKATHON, H256::repeat_byte(1), 10, None, None));
+		assert_ok!(Buidl::create_challenge(RuntimeOrigin::signed(1), HACKATHON, H256::repeat_byte(2), 10, None, None));
+		set_periods();
+		assert_ok!(Buidl::update_team_size(RuntimeOrigin::root(), HACKATHON, 2));
+		let pair = team(2, vec![3]);
+		let trio = team(4, vec![5, 6]);
+
+		assert_ok!(Buidl::submit_solution(RuntimeOrigin::signed(2), HACKATHON, 0, pair, H256::repeat_byte(5), false));
+		assert_noop!(
+			Buidl::submit_solution(RuntimeOrigin::signed(4), HACKATHON, 0, trio, H256::repeat_byte(6), false),
+			Error::<Test>::TeamTooLarge
+		);
+		assert_noop!(Buidl::add_member(RuntimeOrigin::signed(2), pair, 7), Error::<Test>::TeamTooLarge);
+
+		// lowering the limit does not push out a team that already entered
+		assert_ok!(Buidl::update_team_size(RuntimeOrigin::root(), HACKATHON, 1));
+		assert_ok!(Buidl::submit_solution(RuntimeOrigin::signed(3), HACKATHON, 1, pair, H256::repeat_byte(7), false));
+
+		assert_ok!(Buidl::update_team_size(RuntimeOrigin::root(), HACKATHON, 3));
+		assert_ok!(Buidl::add_member(RuntimeOrigin::signed(2), pair, 7));
+		assert_ok!(Buidl::submit_solution(RuntimeOrigin::signed(4), HACKATHON, 0, trio, H256::repeat_byte(6), false));
+	});
+}
+
+#[test]
+fn accounts_wait_out_a_cooldown_between_team_creations() {
+	new_test_ext().execute_with(|| {
+		TeamCreationCooldown::set(3);
+		assert_ok!(Buidl::create_team(RuntimeOrigin::signed(1), vec![]));
+		System::set_block_number(3);
+		assert_noop!(
+			Buidl::create_team(RuntimeOrigin::signed(1), vec![]),
+			Error::<Test>::TeamCreationCooldownActive
+		);
+		// the cooldown is per account
+		assert_ok!(Buidl::create_team(RuntimeOrigin::signed(2), vec![]));
+
+		System::set_block_number(4);
+		assert_ok!(Buidl::create_team(RuntimeOrigin::signed(1), vec![]));
+		assert_eq!(NextTeamId::<Test>::get(), 3);
+	});
+}
+
+#[test]
+fn admin_slashes_a_team_bond() {
+	new_test_ext().execute_with(|| {
+		TeamBond::set(10);
+		let team_id = team(1, vec![]);
+		assert_noop!(
+			Buidl::slash_team_bond(RuntimeOrigin::signed(1), team_id),
+			sp_runtime::DispatchError::BadOrigin
+		);
+
+		assert_ok!(Buidl::slash_team_bond(RuntimeOrigin::root(), team_id));
+		System::assert_last_event(crate::Event::TeamBondSlashed { team_id, amount: 10 }.into());
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), 990);
+		assert_eq!(Teams::<Test>::get(team_id).unwrap().bond, 0);
+
+		// the team carries on, but disbanding it has nothing left to return
+		assert_ok!(Buidl::disband_team(RuntimeOrigin::signed(1), team_id));
+		assert_eq!(Balances::free_balance(1), 990);
+	});
+}
+
+#[test]
+fn challenges_with_a_required_category_only_take_matching_teams() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Buidl::create_challenge(RuntimeOrigin::signed(1), HACKATHON, H256::repeat_byte(1), 10, None, None));
+		assert_ok!(Buidl::create_challenge(RuntimeOrigin::signed(1), HACKATHON, H256::repeat_byte(2), 10, None, None));
+		assert_noop!(
+			Buidl::set_required_category(RuntimeOrigin::signed(2), HACKATHON, 0, Some(1)),
+			Error::<Test>::NotChallengeAuthor
+		);
+		assert_ok!(Buidl::set_required_category(RuntimeOrigin::signed(1), HACKATHON, 0, Some(1)));
+		System::assert_last_event(
+			crate::Event::RequiredCategorySet { hackathon_id: HACKATHON, id: 0, category: Some(1) }.into(),
+		);
+
+		set_periods();
+		assert_noop!(
+			Buidl::set_required_category(RuntimeOrigin::signed(1), HACKATHON, 0, None),
+			Error::<Test>::ChallengeLocked
+		);
+		let untagged = team(2, vec![]);
+		let other_track = team(3, vec![]);
+		let matching = team(4, vec![]);
+		assert_ok!(Buidl::set_team_category(RuntimeOrigin::signed(3), other_track, Some(2)));
+		assert_ok!(Buidl::set_team_category(RuntimeOrigin::signed(4), matching, Some(1)));
+
+		for (founder, team_id) in [(2, untagged), (3, other_track)] {
+			assert_noop!(
+				Buidl::submit_solution(RuntimeOrigin::signed(founder), HACKATHON, 0, team_id, H256::repeat_byte(5), false),
+				Error::<Test>::CategoryMismatch
+			);
+		}
+		assert_ok!(Buidl::submit_solution(RuntimeOrigin::signed(4), HACKATHON, 0, matching, H256::repeat_byte(5), false));
+		// an unrestricted challenge takes any team
+		assert_ok!(Buidl::submit_solution(RuntimeOrigin::signed(2), HACKATHON, 1, untagged, H256::repeat_byte(6), false));
+	});
+}
+
+/// A funded challenge with open submissions and a team of 2 and 3 that could submit to it.
+fn submittable() -> u32 {
+	assert_ok!(Buidl::create_challenge(RuntimeOrigin::signed(1), HACKATHON, H256::repeat_byte(1), 10, None, None));
+	set_periods();
+	team(2, vec![3])
+}
+
+#[test]
+fn can_submit_when_every_check_passes() {
+	new_test_ext().execute_with(|| {
+		let team_id = submittable();
+		assert!(Buidl::can_submit(HACKATHON, 0, team_id, &2));
+		assert!(Buidl::can_submit(HACKATHON, 0, team_id, &3));
+		assert_ok!(Buidl::submit_solution(RuntimeOrigin::signed(3), HACKATHON, 0, team_id, H256::repeat_byte(5), false));
+	});
+}
+
+#[test]
+fn can_submit_rejects_unknown_challenges_and_teams() {
+	new_test_ext().execute_with(|| {
+		let team_id = submittable();
+		assert!(!Buidl::can_submit(HACKATHON, 1, team_id, &2));
+		assert!(!Buidl::can_submit(HACKATHON + 1, 0, team_id, &2));
+		assert!(!Buidl::can_submit(HACKATHON, 0, team_id + 1, &2));
+	});
+}
+
+#[test]
+fn can_submit_rejects_non_members() {
+	new_test_ext().execute_with(|| {
+		let team_id = submittable();
+		assert!(!Buidl::can_submit(HACKATHON, 0, team_id, &4));
+	});
+}
+
+#[test]
+fn can_submit_rejects_teams_outside_the_required_category() {
+	new_test_ext().execute_with(|| {
+		let team_id = submittable();
+		Challenges::<Test>::mutate(HACKATHON, 0, |challenge| {
+			challenge.as_mut().unwrap().required_category = Some(1)
+		});
+		assert!(!Buidl::can_submit(HACKATHON, 0, team_id, &2));
+		assert_ok!(Buidl::set_team_category(RuntimeOrigin::signed(2), team_id, Some(1)));
+		assert!(Buidl::can_submit(HACKATHON, 0, team_id, &2));
+	});
+}
+
+#[test]
+fn can_submit_rejects_teams_over_the_size_limit() {
+	new_test_ext().execute_with(|| {
+		let team_id = submittable();
+		Hackathons::<Test>::mutate(HACKATHON, |hackathon| hackathon.as_mut().unwrap().max_team_size = 1);
+		assert!(!Buidl::can_submit(HACKATHON, 0, team_id, &2));
+	});
+}
+
+#[test]
+fn can_submit_rejects_outside_the_submission_window() {
+	new_test_ext().execute_with(|| {
+		let team_id = submittable();
+		System::set_block_number(SUBMISSION_END);
+		assert!(!Buidl::can_submit(HACKATHON, 0, team_id, &2));
+	});
+}
+
+#[test]
+fn can_submit_rejects_challenges_not_yet_funded() {
+	new_test_ext().execute_with(|| {
+		let team_id = submittable();
+		Challenges::<Test>::mutate(HACKATHON, 0, |challenge| challenge.as_mut().unwrap().outstanding = 5);
+		assert!(!Buidl::can_submit(HACKATHON, 0, team_id, &2));
+	});
+}
+
+#[test]
+fn can_submit_rejects_full_challenges() {
+	new_test_ext().execute_with(|| {
+		let team_id = submittable();
+		Challenges::<Test>::mutate(HACKATHON, 0, |challenge| {
+			challenge.as_mut().unwrap().submissions = <Test as crate::Config>::MaxSolutions::get()
+		});
+		assert!(!Buidl::can_submit(HACKATHON, 0, team_id, &2));
+	});
+}
+
+#[test]
+fn can_submit_rejects_teams_that_already_submitted() {
+	new_test_ext().execute_with(|| {
+		let team_id = submittable();
+		assert_ok!(Buidl::submit_solution(RuntimeOrigin::signed(2), HACKATHON, 0, team_id, H256::repeat_byte(5), false));
+		assert!(!Buidl::can_submit(HACKATHON, 0, team_id, &2));
+		assert!(!Buidl::can_submit(HACKATHON, 0, team_id, &3));
+	});
+}
+
+#[test]
+fn can_submit_rejects_members_at_the_coauthorship_cap() {
+	new_test_ext().execute_with(|| {
+		let team_id = submittable();
+		// the cap applies to every member, not only the one submitting
+		Coauthorships::<Test>::insert(HACKATHON, 3, <Test as crate::Config>::MaxSolutionsPerAccount::get());
+		assert!(!Buidl::can_submit(HACKATHON, 0, team_id, &2));
+	});
+}
+
+#[test]
+fn rejected_submissions_write_nothing() {
+	new_test_ext().execute_with(|| {
+		let team_id = submittable();
+		SubmissionBond::set(10);
+		// the solution id check runs last, after every eligibility check has passed
+		crate::NextSolutionId::<Test>::insert(0, u16::MAX);
+		assert_noop!(
+			Buidl::submit_solution(RuntimeOrigin::signed(2), HACKATHON, 0, team_id, H256::repeat_byte(5), false),
+			Error::<Test>::TooManySolutions
+		);
+		assert_eq!(Challenges::<Test>::get(HACKATHON, 0).unwrap().submissions, 0);
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_eq!(Coauthorships::<Test>::get(HACKATHON, 2), 0);
+		assert!(!crate::TeamHackathons::<Test>::contains_key(team_id, HACKATHON));
+		assert!(ChallengeSolutions::<Test>::get(0, u16::MAX).is_none());
+
+		crate::NextSolutionId::<Test>::remove(0);
+		assert_ok!(Buidl::submit_solution(RuntimeOrigin::signed(2), HACKATHON, 0, team_id, H256::repeat_byte(5), false));
+		assert_eq!(Challenges::<Test>::get(HACKATHON, 0).unwrap().submissions, 1);
+		assert_eq!(Balances::reserved_balance(2), 10);
+	});
+}
+
+#[test]
+fn can_submit_rejects_submitters_who_cannot_cover_the_bond() {
+	new_test_ext().execute_with(|| {
+		let team_id = submittable();
+		SubmissionBond::set(2_000);
+		assert!(!Buidl::can_submit(HACKATHON, 0, team_id, &2));
+	});
+}
+
+/// Challenge 0 by account 1 with a reward of `reward`, judged by 4, 5 and 6. Team 0 (2 and 3)
+/// submits solution 0 and team 1 (account 7 alone) submits solution 1.
+fn challenge_with_two_solutions(reward: u64) {
+	set_periods();
+	assert_ok!(Buidl::create_challenge(
+		RuntimeOrigin::signed(1),
+		HACKATHON,
+		H256::repeat_byte(1),
+		reward,
+		judges(vec![4, 5, 6]),
+		None
+	));
+	let first = team(2, vec![3]);
+	let second = team(7, vec![]);
+	assert_ok!(Buidl::submit_solution(RuntimeOrigin::signed(2), HACKATHON, 0, first, H256::repeat_byte(5), false));
+	assert_ok!(Buidl::submit_solution(RuntimeOrigin::signed(7), HACKATHON, 0, second, H256::repeat_byte(6), false));
+	System::set_block_number(VOTE_START);
+}
+
+#[test]
+fn finalize_challenge_pays_the_plurality_winner() {
+	new_test_ext().execute_with(|| {
+		challenge_with_two_solutions(10);
+		assert_ok!(Buidl::vote(RuntimeOrigin::signed(4), HACKATHON, 0, 0));
+		assert_ok!(Buidl::vote(RuntimeOrigin::signed(5), HACKATHON, 0, 0));
+		assert_ok!(Buidl::vote(RuntimeOrigin::signed(6), HACKATHON, 0, 1));
+
+		assert_noop!(
+			Buidl::finalize_challenge(RuntimeOrigin::signed(1), HACKATHON, 0),
+			Error::<Test>::VotingNotEnded
+		);
+
+		System::set_block_number(VOTE_END);
+		assert!(!Buidl::is_finalized(0));
+		assert_ok!(Buidl::finalize_challenge(RuntimeOrigin::signed(1), HACKATHON, 0));
+		assert!(Buidl::is_finalized(0));
+		System::assert_last_event(
+			crate::Event::<Test>::ChallengeFinalized {
+				challenge_id: 0,
+				winning_solution: 0,
+				winners: vec![2, 3].try_into().unwrap(),
+			}
+			.into(),
+		);
+		assert_eq!(ChallengeWinners::<Test>::get(0), Some(0));
+		assert_eq!(Balances::free_balance(2), 1_005);
+		assert_eq!(Balances::free_balance(3), 1_005);
+		assert_eq!(Balances::free_balance(1), 990);
+		assert_eq!(Balances::reserved_balance(1), 0);
+
+		// the per-judge votes are dropped but the result and who voted for what stay queryable
+		assert_eq!(Votes::<Test>::iter_prefix(0).count(), 0);
+		assert_eq!(Buidl::current_leader(HACKATHON, 0), Some(0));
+		assert_eq!(Buidl::tally_votes(HACKATHON, 0), Some((0, 2)));
+		assert_eq!(Buidl::solution_voters(0, 0), vec![4, 5]);
+		assert_eq!(Buidl::solution_voters(0, 1), vec![6]);
+		assert!(Buidl::is_finalized(0));
+		assert_eq!(ChallengeWinners::<Test>::get(0), Some(0));
+
+		assert_noop!(
+			Buidl::finalize_challenge(RuntimeOrigin::signed(1), HACKATHON, 0),
+			Error::<Test>::ChallengeAlreadyFinalized
+		);
+	});
+}
+
+#[test]
+fn winners_shares_vest_linearly_after_finalization() {
+	new_test_ext().execute_with(|| {
+		VestingDuration::set(10);
+		challenge_with_two_solutions(100);
+		assert_ok!(Buidl::vote(RuntimeOrigin::signed(4), HACKATHON, 0, 0));
+		assert_ok!(Buidl::vote(RuntimeOrigin::signed(5), HACKATHON, 0, 0));
+		System::set_block_number(VOTE_END);
+		assert_ok!(Buidl::finalize_challenge(RuntimeOrigin::signed(1), HACKATHON, 0));
+
+		// each winner's share is theirs but held until it vests
+		assert_eq!(Balances::free_balance(3), 1_000);
+		assert_eq!(Balances::reserved_balance(3), 50);
+		assert_noop!(
+			Buidl::claim_vested(RuntimeOrigin::signed(3), 0, None),
+			Error::<Test>::NothingVested
+		);
+		assert_noop!(
+			Buidl::claim_vested(RuntimeOrigin::signed(6), 0, None),
+			Error::<Test>::NoVestingSchedule
+		);
+
+		System::set_block_number(VOTE_END + 4);
+		assert_ok!(Buidl::claim_vested(RuntimeOrigin::signed(3), 0, None));
+		System::assert_last_event(
+			crate::Event::<Test>::VestedRewardReleased {
+				who: 3,
+				challenge_id: 0,
+				prize_id: None,
+				amount: 20,
+			}
+			.into(),
+		);
+		assert_eq!(Balances::free_balance(3), 1_020);
+		assert_eq!(Balances::reserved_balance(3), 30);
+
+		System::set_block_number(VOTE_END + 7);
+		assert_ok!(Buidl::claim_vested(RuntimeOrigin::signed(3), 0, None));
+		assert_eq!(Balances::free_balance(3), 1_035);
+
+		// past the duration whatever is left is released and the schedule dropped
+		System::set_block_number(VOTE_END + 30);
+		assert_ok!(Buidl::claim_vested(RuntimeOrigin::signed(3), 0, None));
+		assert_eq!(Balances::free_balance(3), 1_050);
+		assert_eq!(Balances::reserved_balance(3), 0);
+		assert_eq!(VestingSchedules::<Test>::get(3, (0, None)), None);
+		assert_noop!(
+			Buidl::claim_vested(RuntimeOrigin::signed(3), 0, None),
+			Error::<Test>::NoVestingSchedule
+		);
+
+		// the other winner's share keeps vesting until they claim it
+		assert_eq!(Balances::free_balance(2), 1_000);
+		assert_eq!(Balances::reserved_balance(2), 50);
+	});
+}
+
+#[test]
+fn prizes_with_separate_panels_finalize_independently() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Buidl::create_challenge(RuntimeOrigin::signed(1), HACKATHON, H256::repeat_byte(1), 10, None, None));
+		assert_noop!(
+			Buidl::add_prize(RuntimeOrigin::signed(2), HACKATHON, 0, H256::repeat_byte(2), 20, vec![4].try_into().unwrap()),
+			Error::<Test>::NotChallengeAuthor
+		);
+		assert_ok!(Buidl::add_prize(RuntimeOrigin::signed(1), HACKATHON, 0, H256::repeat_byte(2), 20, vec![4, 5].try_into().unwrap()));
+		assert_ok!(Buidl::add_prize(RuntimeOrigin::signed(1), HACKATHON, 0, H256::repeat_byte(3), 30, vec![6].try_into().unwrap()));
+		assert_noop!(
+			Buidl::add_prize(RuntimeOrigin::signed(1), HACKATHON, 0, H256::repeat_byte(4), 5, vec![6].try_into().unwrap()),
+			Error::<Test>::TooManyPrizes
+		);
+		assert_eq!(<Balances as InspectHold<u64>>::balance_on_hold(&1), 60);
+
+		set_periods();
+		assert_noop!(
+			Buidl::add_prize(RuntimeOrigin::signed(1), HACKATHON, 0, H256::repeat_byte(4), 5, vec![6].try_into().unwrap()),
+			Error::<Test>::ChallengeLocked
+		);
+		let first = team(2, vec![3]);
+		let second = team(7, vec![]);
+		assert_ok!(Buidl::submit_solution(RuntimeOrigin::signed(2), HACKATHON, 0, first, H256::repeat_byte(5), false));
+		assert_ok!(Buidl::submit_solution(RuntimeOrigin::signed(7), HACKATHON, 0, second, H256::repeat_byte(6), false));
+
+		System::set_block_number(VOTE_START);
+		// each prize is only judged by its own panel
+		assert_noop!(
+			Buidl::vote_prize(RuntimeOrigin::signed(6), HACKATHON, 0, 0, 1),
+			Error::<Test>::NotAJudge
+		);
+		assert_ok!(Buidl::vote_prize(RuntimeOrigin::signed(4), HACKATHON, 0, 0, 0));
+		assert_ok!(Buidl::vote_prize(RuntimeOrigin::signed(5), HACKATHON, 0, 0, 0));
+		assert_ok!(Buidl::vote_prize(RuntimeOrigin::signed(6), HACKATHON, 0, 1, 1));
+		assert_noop!(
+			Buidl::finalize_prize(RuntimeOrigin::signed(1), HACKATHON, 0, 0),
+			Error::<Test>::VotingNotEnded
+		);
+
+		System::set_block_number(VOTE_END);
+		assert_ok!(Buidl::finalize_prize(RuntimeOrigin::signed(1), HACKATHON, 0, 0));
+		System::assert_last_event(
+			crate::Event::PrizeFinalized { challenge_id: 0, prize_id: 0, winning_solution: Some(0) }.into(),
+		);
+		assert_eq!(Balances::free_balance(2), 1_010);
+		assert_eq!(Balances::free_balance(3), 1_010);
+		assert!(!ChallengePrizes::<Test>::get(0, 1).unwrap().settled);
+		assert_eq!(Balances::free_balance(7), 0);
+		assert_noop!(
+			Buidl::finalize_prize(RuntimeOrigin::signed(1), HACKATHON, 0, 0),
+			Error::<Test>::PrizeAlreadyFinalized
+		);
+
+		assert_ok!(Buidl::finalize_prize(RuntimeOrigin::signed(1), HACKATHON, 0, 1));
+		assert_eq!(ChallengePrizes::<Test>::get(0, 1).unwrap().winner, Some(1));
+		assert_eq!(Balances::free_balance(7), 30);
+		// the main reward is untouched until the challenge itself is settled
+		assert!(!Buidl::is_finalized(0));
+		assert_eq!(<Balances as InspectHold<u64>>::balance_on_hold(&1), 10);
+	});
+}
+
+#[test]
+fn cancelling_a_challenge_releases_its_prizes() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Buidl::create_challenge(RuntimeOrigin::signed(1), HACKATHON, H256::repeat_byte(1), 10, None, None));
+		assert_ok!(Buidl::add_prize(RuntimeOrigin::signed(1), HACKATHON, 0, H256::repeat_byte(2), 20, vec![4].try_into().unwrap()));
+		assert_ok!(Buidl::cancel_challenge(RuntimeOrigin::signed(1), HACKATHON, 0));
+		assert_eq!(<Balances as InspectHold<u64>>::balance_on_hold(&1), 0);
+		assert_eq!(Balances::free_balance(1), 1_000);
+		assert!(ChallengePrizes::<Test>::get(0, 0).is_none());
+	});
+}
+
+#[test]
+fn preview_distribution_matches_the_payout() {
+	new_test_ext().execute_with(|| {
+		challenge_with_two_solutions(11);
+		assert!(Buidl::preview_distribution(0).is_empty());
+		assert!(Buidl::preview_distribution(1).is_empty());
+
+		assert_ok!(Buidl::vote(RuntimeOrigin::signed(4), HACKATHON, 0, 1));
+		assert_eq!(Buidl::preview_distribution(0), vec![(7, 11)]);
+		assert_ok!(Buidl::vote(RuntimeOrigin::signed(5), HACKATHON, 0, 0));
+		assert_ok!(Buidl::vote(RuntimeOrigin::signed(6), HACKATHON, 0, 0));
+		let preview = Buidl::preview_distribution(0);
+		assert_eq!(preview, vec![(2, 6), (3, 5)]);
+
+		System::set_block_number(VOTE_END);
+		let before: Vec<u64> = preview.iter().map(|(who, _)| Balances::free_balance(who)).collect();
+		assert_ok!(Buidl::finalize_challenge(RuntimeOrigin::signed(1), HACKATHON, 0));
+		for ((who, share), before) in preview.into_iter().zip(before) {
+			assert_eq!(Balances::free_balance(who), before + share);
+		}
+	});
+}
+
+#[test]
+fn current_leader_follows_votes_and_revisions() {
+	new_test_ext().execute_with(|| {
+		challenge_with_two_solutions(10);
+		assert_eq!(Buidl::current_leader(HACKATHON, 0), None);
+
+		assert_ok!(Buidl::vote(RuntimeOrigin::signed(4), HACKATHON, 0, 1));
+		assert_eq!(Buidl::current_leader(HACKATHON, 0), Some(1));
+		assert_ok!(Buidl::vote(RuntimeOrigin::signed(5), HACKATHON, 0, 0));
+		assert_ok!(Buidl::vote(RuntimeOrigin::signed(6), HACKATHON, 0, 0));
+		assert_eq!(Buidl::current_leader(HACKATHON, 0), Some(0));
+
+		// judges changing their minds move the lead back
+		assert_ok!(Buidl::vote(RuntimeOrigin::signed(5), HACKATHON, 0, 1));
+		assert_eq!(Buidl::current_leader(HACKATHON, 0), Some(1));
+	});
+}
+
+#[test]
+fn votes_past_the_cap_are_ignored() {
+	new_test_ext().execute_with(|| {
+		MaxCountedVotes::set(2);
+		challenge_with_two_solutions(10);
+		assert_ok!(Buidl::vote(RuntimeOrigin::signed(4), HACKATHON, 0, 0));
+		assert_ok!(Buidl::vote(RuntimeOrigin::signed(5), HACKATHON, 0, 1));
+		// the third vote cast would break the tie, but only the first two count
+		assert_ok!(Buidl::vote(RuntimeOrigin::signed(6), HACKATHON, 0, 1));
+		assert_eq!(Buidl::current_leader(HACKATHON, 0), Some(0));
+		assert_eq!(Buidl::solution_voters(0, 1), vec![5]);
+
+		// revising a vote keeps its place in the order
+		assert_ok!(Buidl::vote(RuntimeOrigin::signed(4), HACKATHON, 0, 1));
+		assert_eq!(Buidl::current_leader(HACKATHON, 0), Some(1));
+		assert_eq!(Buidl::solution_voters(0, 1), vec![4, 5]);
+
+		System::set_block_number(VOTE_END);
+		assert_ok!(Buidl::finalize_challenge(RuntimeOrigin::signed(1), HACKATHON, 0));
+		assert_eq!(ChallengeWinners::<Test>::get(0), Some(1));
+		assert_eq!(Buidl::solution_voters(0, 1), vec![4, 5]);
+	});
+}
+
+#[test]
+fn finalize_challenge_breaks_ties_on_lowest_solution_id() {
+	new_test_ext().execute_with(|| {
+		challenge_with_two_solutions(10);
+		assert_ok!(Buidl::vote(RuntimeOrigin::signed(4), HACKATHON, 0, 1));
+		assert_ok!(Buidl::vote(RuntimeOrigin::signed(5), HACKATHON, 0, 0));
+
+		System::set_block_number(VOTE_END);
+		assert_ok!(Buidl::finalize_challenge(RuntimeOrigin::signed(1), HACKATHON, 0));
+		assert_eq!(ChallengeWinners::<Test>::get(0), Some(0));
+	});
+}
+
+#[test]
+fn finalize_challenge_settles_the_undivided_remainder_per_rounding_policy() {
+	for (policy, shares, burned) in [
+		(RoundingPolicy::RemainderToFounder, [3, 4, 3], 0),
+		(RoundingPolicy::RemainderToFirst, [4, 3, 3], 0),
+		(RoundingPolicy::BurnRemainder, [3, 3, 3], 1),
+	] {
+		new_test_ext().execute_with(|| {
+			Rounding::set(policy);
+			assert_ok!(Buidl::create_challenge(RuntimeOrigin::signed(1), HACKATHON, H256::repeat_byte(1), 10, judges(vec![4]), None));
+			set_periods();
+			let team_id = team(2, vec![3, 5]);
+			assert_ok!(Buidl::submit_solution(RuntimeOrigin::signed(2), HACKATHON, 0, team_id, H256::repeat_byte(5), false));
+			// a founder need not be the first member listed
+			Teams::<Test>::mutate(team_id, |team| team.as_mut().unwrap().team_founder = 3);
+			System::set_block_number(VOTE_START);
+			assert_ok!(Buidl::vote(RuntimeOrigin::signed(4), HACKATHON, 0, 0));
+
+			System::set_block_number(VOTE_END);
+			let issuance = Balances::total_issuance();
+			assert_ok!(Buidl::finalize_challenge(RuntimeOrigin::signed(1), HACKATHON, 0));
+			for (member, share) in [2, 3, 5].into_iter().zip(shares) {
+				assert_eq!(Balances::free_balance(member), 1_000 + share);
+			}
+			assert_eq!(Balances::free_balance(1), 990);
+			assert_eq!(Balances::reserved_balance(1), 0);
+			assert_eq!(Balances::total_issuance(), issuance - burned);
+		});
+	}
+}
+
+#[test]
+fn finalize_challenge_without_votes_refunds_the_reward() {
+	new_test_ext().execute_with(|| {
+		challenge_with_two_solutions(10);
+		System::set_block_number(VOTE_END);
+		assert!(!Buidl::is_finalized(0));
+		assert_ok!(Buidl::finalize_challenge(RuntimeOrigin::signed(1), HACKATHON, 0));
+		assert!(Buidl::is_finalized(0));
+		System::assert_last_event(
+			crate::Event::ChallengeFinalizationFailed { challenge_id: 0, reason: FinalizationFailure::NoVotes }
+				.into(),
+		);
+		assert_eq!(UnawardedChallenges::<Test>::get(0), Some(FinalizationFailure::NoVotes));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), 1_000);
+
+		assert_noop!(
+			Buidl::finalize_challenge(RuntimeOrigin::signed(2), HACKATHON, 0),
+			Error::<Test>::ChallengeAlreadyFinalized
+		);
+	});
+}
+
+#[test]
+fn finalize_challenge_rejected_during_submissions() {
+	new_test_ext().execute_with(|| {
+		challenge_with_two_solutions(10);
+		System::set_block_number(SUBMISSION_END - 1);
+		assert_noop!(
+			Buidl::finalize_challenge(RuntimeOrigin::signed(1), HACKATHON, 0),
+			Error::<Test>::SubmissionsStillOpen
+		);
+	});
+}
+
+#[test]
+fn bounty_is_posted_claimed_and_approved() {
+	new_test_ext().execute_with(|| {
+		let team_id = team(1, vec![2]);
+		assert_ok!(Buidl::post_bounty(
+			RuntimeOrigin::signed(1),
+			team_id,
+			HACKATHON,
+			H256::repeat_byte(1),
+			50,
+			10,
+			20
+		));
+		assert_eq!(Balances::reserved_balance(1), 50);
+		assert_eq!(Bounties::<Test>::get(0).unwrap().status, BountyStatus::Open);
+
+		assert_noop!(Buidl::approve_bounty(RuntimeOrigin::signed(1), 0), Error::<Test>::BountyNotClaimed);
+
+		assert_ok!(Buidl::claim_bounty(RuntimeOrigin::signed(3), 0, H256::repeat_byte(7)));
+		let bounty = Bounties::<Test>::get(0).unwrap();
+		assert_eq!(bounty.claimant, Some(3));
+		assert_eq!(bounty.solution, Some(H256::repeat_byte(7)));
+		assert_eq!(bounty.status, BountyStatus::Claimed);
+
+		assert_noop!(Buidl::approve_bounty(RuntimeOrigin::signed(4), 0), Error::<Test>::NotATeamMember);
+		assert_ok!(Buidl::approve_bounty(RuntimeOrigin::signed(1), 0));
+		System::assert_last_event(
+			crate::Event::<Test>::BountyApproved { bounty_id: 0, claimant: 3, amount: 50 }.into(),
+		);
+		assert_eq!(Bounties::<Test>::get(0).unwrap().status, BountyStatus::Approved);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), 950);
+		assert_eq!(Balances::free_balance(3), 1_050);
+	});
+}
+
+#[test]
+fn only_the_founder_and_captain_post_and_approve_bounties() {
+	new_test_ext().execute_with(|| {
+		let team_id = team(1, vec![2, 4]);
+		assert_noop!(
+			Buidl::post_bounty(RuntimeOrigin::signed(2), team_id, HACKATHON, H256::repeat_byte(1), 50, 10, 20),
+			Error::<Test>::NotBountyManager
+		);
+
+		assert_noop!(
+			Buidl::set_team_captain(RuntimeOrigin::signed(2), team_id, Some(2)),
+			Error::<Test>::NotTeamFounder
+		);
+		assert_noop!(
+			Buidl::set_team_captain(RuntimeOrigin::signed(1), team_id, Some(3)),
+			Error::<Test>::NotATeamMember
+		);
+		assert_ok!(Buidl::set_team_captain(RuntimeOrigin::signed(1), team_id, Some(2)));
+		System::assert_last_event(crate::Event::TeamCaptainSet { team_id, captain: Some(2) }.into());
+
+		assert_ok!(Buidl::post_bounty(RuntimeOrigin::signed(2), team_id, HACKATHON, H256::repeat_byte(1), 50, 10, 20));
+		assert_ok!(Buidl::claim_bounty(RuntimeOrigin::signed(3), 0, H256::repeat_byte(7)));
+		assert_noop!(Buidl::approve_bounty(RuntimeOrigin::signed(4), 0), Error::<Test>::NotBountyManager);
+		assert_ok!(Buidl::approve_bounty(RuntimeOrigin::signed(2), 0));
+		assert_eq!(Balances::free_balance(3), 1_050);
+
+		// clearing the role leaves the founder in charge
+		assert_ok!(Buidl::set_team_captain(RuntimeOrigin::signed(1), team_id, None));
+		assert_noop!(
+			Buidl::post_bounty(RuntimeOrigin::signed(2), team_id, HACKATHON, H256::repeat_byte(2), 50, 10, 20),
+			Error::<Test>::NotBountyManager
+		);
+		assert_ok!(Buidl::post_bounty(RuntimeOrigin::signed(1), team_id, HACKATHON, H256::repeat_byte(2), 50, 10, 20));
+	});
+}
+
+#[test]
+fn private_rosters_are_hidden_until_voting_ends() {
+	new_test_ext().execute_with(|| {
+		set_periods();
+		let hidden = team(2, vec![3]);
+		let public = team(4, vec![]);
+		assert_noop!(Buidl::set_private_roster(RuntimeOrigin::signed(3), hidden, true), Error::<Test>::NotTeamFounder);
+		assert_ok!(Buidl::set_private_roster(RuntimeOrigin::signed(2), hidden, true));
+		System::assert_last_event(crate::Event::RosterPrivacySet { team_id: hidden, private: true }.into());
+
+		assert_eq!(Buidl::team_members(HACKATHON, hidden), Vec::<u64>::new());
+		assert_eq!(Buidl::team_members(HACKATHON, public), vec![4]);
+		System::set_block_number(VOTE_END - 1);
+		assert_eq!(Buidl::team_members(HACKATHON, hidden), Vec::<u64>::new());
+
+		System::set_block_number(VOTE_END);
+		assert_eq!(Buidl::team_members(HACKATHON, hidden), vec![2, 3]);
+	});
+}
+
+#[test]
+fn bounties_are_only_paid_once_enough_managers_approve() {
+	new_test_ext().execute_with(|| {
+		let team_id = team(1, vec![2, 4]);
+		assert_ok!(Buidl::set_team_captain(RuntimeOrigin::signed(1), team_id, Some(2)));
+		assert_noop!(
+			Buidl::set_required_bounty_approvals(RuntimeOrigin::signed(2), team_id, 2),
+			Error::<Test>::NotTeamFounder
+		);
+		assert_ok!(Buidl::set_required_bounty_approvals(RuntimeOrigin::signed(1), team_id, 2));
+		System::assert_last_event(crate::Event::RequiredBountyApprovalsSet { team_id, approvals: 2 }.into());
+		assert_ok!(Buidl::post_bounty(RuntimeOrigin::signed(1), team_id, HACKATHON, H256::repeat_byte(1), 50, 10, 20));
+		assert_ok!(Buidl::claim_bounty(RuntimeOrigin::signed(3), 0, H256::repeat_byte(7)));
+
+		assert_ok!(Buidl::approve_bounty(RuntimeOrigin::signed(1), 0));
+		System::assert_last_event(
+			crate::Event::BountyApprovalRecorded { bounty_id: 0, approver: 1, milestone: None, approvals: 1 }.into(),
+		);
+		assert_eq!(Bounties::<Test>::get(0).unwrap().status, BountyStatus::Claimed);
+		assert_eq!(Balances::free_balance(3), 1_000);
+		assert_noop!(Buidl::approve_bounty(RuntimeOrigin::signed(1), 0), Error::<Test>::AlreadyApproved);
+		assert_noop!(Buidl::approve_bounty(RuntimeOrigin::signed(4), 0), Error::<Test>::NotBountyManager);
+
+		assert_ok!(Buidl::approve_bounty(RuntimeOrigin::signed(2), 0));
+		assert_eq!(Bounties::<Test>::get(0).unwrap().status, BountyStatus::Approved);
+		assert_eq!(Balances::free_balance(3), 1_050);
+
+		// without a captain the founder is the only manager left, and approves alone
+		assert_ok!(Buidl::set_team_captain(RuntimeOrigin::signed(1), team_id, None));
+		assert_ok!(Buidl::post_bounty(RuntimeOrigin::signed(1), team_id, HACKATHON, H256::repeat_byte(2), 50, 10, 20));
+		assert_ok!(Buidl::claim_bounty(RuntimeOrigin::signed(3), 1, H256::repeat_byte(7)));
+		assert_ok!(Buidl::approve_bounty(RuntimeOrigin::signed(1), 1));
+		assert_eq!(Bounties::<Test>::get(1).unwrap().status, BountyStatus::Approved);
+	});
+}
+
+#[test]
+fn bounty_cannot_be_claimed_by_its_own_team() {
+	new_test_ext().execute_with(|| {
+		let team_id = team(1, vec![2]);
+		assert_ok!(Buidl::post_bounty(RuntimeOrigin::signed(1), team_id, HACKATHON, H256::repeat_byte(1), 50, 10, 20));
+		for member in [1, 2] {
+			assert_noop!(
+				Buidl::claim_bounty(RuntimeOrigin::signed(member), 0, H256::repeat_byte(7)),
+				Error::<Test>::ClaimantIsTeamMember
+			);
+		}
+		assert_ok!(Buidl::claim_bounty(RuntimeOrigin::signed(3), 0, H256::repeat_byte(7)));
+	});
+}
+
+#[test]
+fn bounty_claims_need_a_proof_the_verifier_accepts() {
+	new_test_ext().execute_with(|| {
+		let team_id = team(1, vec![]);
+		assert_ok!(Buidl::post_bounty(RuntimeOrigin::signed(1), team_id, HACKATHON, H256::repeat_byte(1), 50, 10, 20));
+		// the mock verifier rejects an all-0xff solution
+		assert_noop!(
+			Buidl::claim_bounty(RuntimeOrigin::signed(3), 0, H256::repeat_byte(0xff)),
+			Error::<Test>::ProofInvalid
+		);
+		assert_ok!(Buidl::claim_bounty(RuntimeOrigin::signed(3), 0, H256::repeat_byte(7)));
+		assert_noop!(
+			Buidl::update_claim_solution(RuntimeOrigin::signed(3), 0, H256::repeat_byte(0xff)),
+			Error::<Test>::ProofInvalid
+		);
+		assert_eq!(Bounties::<Test>::get(0).unwrap().solution, Some(H256::repeat_byte(7)));
+	});
+}
+
+#[test]
+fn post_bounty_validates_input() {
+	new_test_ext().execute_with(|| {
+		let team_id = team(1, vec![]);
+		assert_noop!(
+			Buidl::post_bounty(RuntimeOrigin::signed(2), team_id, HACKATHON, H256::repeat_byte(1), 50, 10, 20),
+			Error::<Test>::NotATeamMember
+		);
+		assert_noop!(
+			Buidl::post_bounty(RuntimeOrigin::signed(1), team_id, HACKATHON, H256::repeat_byte(1), 50, 10, 101),
+			Error::<Test>::InvalidPercentage
+		);
+		assert_noop!(
+			Buidl::post_bounty(RuntimeOrigin::signed(1), team_id, HACKATHON, H256::repeat_byte(1), 50, 1, 20),
+			Error::<Test>::ExpiryNotInFuture
+		);
+		assert_noop!(
+			Buidl::post_bounty(RuntimeOrigin::signed(1), team_id, HACKATHON, H256::repeat_byte(1), 5_000, 10, 20),
+			Error::<Test>::InsufficientBalance
+		);
+	});
+}
+
+#[test]
+fn bounty_percentages_follow_the_configured_step() {
+	new_test_ext().execute_with(|| {
+		let team_id = team(1, vec![]);
+		assert_noop!(
+			Buidl::post_bounty(RuntimeOrigin::signed(1), team_id, HACKATHON, H256::repeat_byte(1), 50, 10, 7),
+			Error::<Test>::InvalidPercentageStep
+		);
+		assert_ok!(Buidl::post_bounty(RuntimeOrigin::signed(1), team_id, HACKATHON, H256::repeat_byte(1), 50, 10, 10));
+		assert_eq!(Bounties::<Test>::get(0).unwrap().percentage, 10);
+	});
+}
+
+#[test]
+fn bounty_must_offer_funds_or_ownership() {
+	new_test_ext().execute_with(|| {
+		let team_id = team(1, vec![]);
+		assert_noop!(
+			Buidl::post_bounty(RuntimeOrigin::signed(1), team_id, HACKATHON, H256::repeat_byte(1), 0, 10, 0),
+			Error::<Test>::EmptyBounty
+		);
+		assert_ok!(Buidl::post_bounty(RuntimeOrigin::signed(1), team_id, HACKATHON, H256::repeat_byte(1), 0, 10, 20));
+		assert_ok!(Buidl::post_bounty(RuntimeOrigin::signed(1), team_id, HACKATHON, H256::repeat_byte(2), 50, 10, 0));
+	});
+}
+
+#[test]
+fn events_can_forbid_bounties_offering_ownership() {
+	new_test_ext().execute_with(|| {
+		let members_only = NextHackathonId::<Test>::get();
+		assert_ok!(Buidl::register(RuntimeOrigin::signed(ORGANIZER), H256::repeat_byte(9)));
+		assert!(Hackathons::<Test>::get(members_only).unwrap().allow_ownership_bounties);
+		assert_noop!(
+			Buidl::set_allow_ownership_bounties(RuntimeOrigin::signed(1), members_only, false),
+			Error::<Test>::NotHackathonOrganizer
+		);
+		assert_ok!(Buidl::set_allow_ownership_bounties(RuntimeOrigin::signed(ORGANIZER), members_only, false));
+		System::assert_last_event(
+			crate::Event::OwnershipBountiesSet { hackathon_id: members_only, allowed: false }.into(),
+		);
+
+		let team_id = team(1, vec![]);
+		assert_noop!(
+			Buidl::post_bounty(RuntimeOrigin::signed(1), team_id, members_only, H256::repeat_byte(1), 50, 10, 20),
+			Error::<Test>::OwnershipBountiesDisabled
+		);
+		assert_noop!(
+			Buidl::post_bounty(RuntimeOrigin::signed(1), team_id, 7, H256::repeat_byte(1), 50, 10, 20),
+			Error::<Test>::HackathonDoesNotExist
+		);
+		// funds can still be offered, and an event permitting ownership takes a percentage
+		assert_ok!(Buidl::post_bounty(RuntimeOrigin::signed(1), team_id, members_only, H256::repeat_byte(1), 50, 10, 0));
+		assert_eq!(Bounties::<Test>::get(0).unwrap().hackathon_id, members_only);
+		assert_ok!(Buidl::post_bounty(RuntimeOrigin::signed(1), team_id, HACKATHON, H256::repeat_byte(2), 50, 10, 20));
+		assert_eq!(Bounties::<Test>::get(1).unwrap().percentage, 20);
+
+		assert_ok!(Buidl::set_allow_ownership_bounties(RuntimeOrigin::root(), members_only, true));
+		assert_ok!(Buidl::post_bounty(RuntimeOrigin::signed(1), team_id, members_only, H256::repeat_byte(3), 50, 10, 20));
+	});
+}
+
+#[test]
+fn claimant_can_update_their_solution_until_approval() {
+	new_test_ext().execute_with(|| {
+		let team_id = team(1, vec![2]);
+		assert_ok!(Buidl::post_bounty(RuntimeOrigin::signed(1), team_id, HACKATHON, H256::repeat_byte(1), 50, 10, 20));
+		assert_noop!(
+			Buidl::update_claim_solution(RuntimeOrigin::signed(3), 0, H256::repeat_byte(8)),
+			Error::<Test>::BountyNotClaimed
+		);
+
+		assert_ok!(Buidl::claim_bounty(RuntimeOrigin::signed(3), 0, H256::repeat_byte(7)));
+		assert_noop!(
+			Buidl::update_claim_solution(RuntimeOrigin::signed(4), 0, H256::repeat_byte(8)),
+			Error::<Test>::NotBountyClaimant
+		);
+		assert_ok!(Buidl::update_claim_solution(RuntimeOrigin::signed(3), 0, H256::repeat_byte(8)));
+		System::assert_last_event(
+			crate::Event::<Test>::ClaimSolutionUpdated {
+				bounty_id: 0,
+				claimant: 3,
+				solution: H256::repeat_byte(8),
+			}
+			.into(),
+		);
+		assert_eq!(Bounties::<Test>::get(0).unwrap().solution, Some(H256::repeat_byte(8)));
+
+		assert_ok!(Buidl::approve_bounty(RuntimeOrigin::signed(1), 0));
+		assert_noop!(
+			Buidl::update_claim_solution(RuntimeOrigin::signed(3), 0, H256::repeat_byte(9)),
+			Error::<Test>::BountyNotClaimed
+		);
+	});
+}
+
+#[test]
+fn resolved_shares_leave_the_founder_the_rest_of_the_prize() {
+	new_test_ext().execute_with(|| {
+		assert!(Buidl::resolved_shares(0).is_empty());
+		let team_id = team(1, vec![2]);
+		let total = || Buidl::resolved_shares(team_id).iter().map(|(_, share)| *share as u32).sum::<u32>();
+		assert_eq!(Buidl::resolved_shares(team_id), vec![(1, 100)]);
+
+		for (percentage, claimant) in [(20, 3), (30, 4), (10, 3), (0, 5)] {
+			let bounty_id = Bounties::<Test>::iter().count() as u32;
+			assert_ok!(Buidl::post_bounty(RuntimeOrigin::signed(1), team_id, HACKATHON, H256::repeat_byte(1), 10, 10, percentage));
+			assert_ok!(Buidl::claim_bounty(RuntimeOrigin::signed(claimant), bounty_id, H256::repeat_byte(7)));
+			assert_ok!(Buidl::approve_bounty(RuntimeOrigin::signed(1), bounty_id));
+			assert_eq!(total(), 100);
+		}
+		// a claimant's bounties add up, and funds-only bounties grant no share
+		assert_eq!(Buidl::resolved_shares(team_id), vec![(1, 40), (3, 30), (4, 30)]);
+
+		// claimed but unapproved bounties grant nothing yet
+		assert_ok!(Buidl::post_bounty(RuntimeOrigin::signed(1), team_id, HACKATHON, H256::repeat_byte(2), 10, 10, 40));
+		assert_ok!(Buidl::claim_bounty(RuntimeOrigin::signed(5), 4, H256::repeat_byte(8)));
+		assert_eq!(Buidl::resolved_shares(team_id), vec![(1, 40), (3, 30), (4, 30)]);
+		assert_ok!(Buidl::approve_bounty(RuntimeOrigin::signed(1), 4));
+		assert_eq!(Buidl::resolved_shares(team_id), vec![(1, 0), (3, 30), (4, 30), (5, 40)]);
+		assert_eq!(total(), 100);
+	});
+}
+
+#[test]
+fn approvals_cannot_grant_more_than_the_whole_prize() {
+	new_test_ext().execute_with(|| {
+		let team_id = team(1, vec![2]);
+		assert_ok!(Buidl::post_bounty(RuntimeOrigin::signed(1), team_id, HACKATHON, H256::repeat_byte(1), 10, 10, 60));
+		assert_ok!(Buidl::post_bounty(RuntimeOrigin::signed(1), team_id, HACKATHON, H256::repeat_byte(2), 10, 10, 60));
+		assert_ok!(Buidl::claim_bounty(RuntimeOrigin::signed(3), 0, H256::repeat_byte(7)));
+		assert_ok!(Buidl::claim_bounty(RuntimeOrigin::signed(4), 1, H256::repeat_byte(8)));
+
+		assert_ok!(Buidl::approve_bounty(RuntimeOrigin::signed(1), 0));
+		assert_eq!(GrantedOwnership::<Test>::get(team_id), 60);
+		assert_noop!(Buidl::approve_bounty(RuntimeOrigin::signed(1), 1), Error::<Test>::OwnershipExceeds100);
+		assert_eq!(Bounties::<Test>::get(1).unwrap().status, BountyStatus::Claimed);
+	});
+}
+
+#[test]
+fn milestones_are_paid_in_order_until_the_bounty_is_approved() {
+	new_test_ext().execute_with(|| {
+		let team_id = team(1, vec![2]);
+		assert_ok!(Buidl::post_bounty(RuntimeOrigin::signed(1), team_id, HACKATHON, H256::repeat_byte(1), 50, 10, 20));
+		let milestones = |amounts: Vec<u64>| -> BoundedVec<(H256, u64), <Test as crate::Config>::MaxMilestones> {
+			amounts.into_iter().map(|amount| (H256::repeat_byte(amount as u8), amount)).collect::<Vec<_>>().try_into().unwrap()
+		};
+		assert_noop!(
+			Buidl::set_bounty_milestones(RuntimeOrigin::signed(2), 0, milestones(vec![20, 30])),
+			Error::<Test>::NotBountyPoster
+		);
+		assert_noop!(
+			Buidl::set_bounty_milestones(RuntimeOrigin::signed(1), 0, milestones(vec![20, 20])),
+			Error::<Test>::MilestonesMismatch
+		);
+		assert_ok!(Buidl::set_bounty_milestones(RuntimeOrigin::signed(1), 0, milestones(vec![20, 30])));
+		assert_ok!(Buidl::claim_bounty(RuntimeOrigin::signed(3), 0, H256::repeat_byte(7)));
+
+		assert_noop!(Buidl::approve_milestone(RuntimeOrigin::signed(1), 0, 1), Error::<Test>::MilestoneNotNext);
+		assert_noop!(Buidl::approve_milestone(RuntimeOrigin::signed(1), 0, 2), Error::<Test>::MilestoneDoesNotExist);
+		assert_noop!(Buidl::approve_milestone(RuntimeOrigin::signed(3), 0, 0), Error::<Test>::NotATeamMember);
+
+		assert_ok!(Buidl::approve_milestone(RuntimeOrigin::signed(1), 0, 0));
+		System::assert_last_event(
+			crate::Event::MilestoneApproved { bounty_id: 0, milestone_index: 0, claimant: 3, amount: 20 }.into(),
+		);
+		assert_eq!(Balances::free_balance(3), 1_020);
+		assert_eq!(Buidl::bounty_escrow(0), Some(30));
+		assert_eq!(Bounties::<Test>::get(0).unwrap().status, BountyStatus::Claimed);
+		assert_eq!(GrantedOwnership::<Test>::get(team_id), 0);
+
+		assert_ok!(Buidl::approve_milestone(RuntimeOrigin::signed(1), 0, 1));
+		System::assert_last_event(crate::Event::BountyApproved { bounty_id: 0, claimant: 3, amount: 30 }.into());
+		assert_eq!(Balances::free_balance(3), 1_050);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Bounties::<Test>::get(0).unwrap().status, BountyStatus::Approved);
+		assert_eq!(GrantedOwnership::<Test>::get(team_id), 20);
+	});
+}
+
+#[test]
+fn bounty_escrow_tracks_reserved_funds_across_statuses() {
+	new_test_ext().execute_with(|| {
+		let team_id = team(1, vec![2]);
+		assert_ok!(Buidl::post_bounty(RuntimeOrigin::signed(1), team_id, HACKATHON, H256::repeat_byte(1), 50, 10, 20));
+		assert_ok!(Buidl::post_bounty(RuntimeOrigin::signed(1), team_id, HACKATHON, H256::repeat_byte(2), 30, 10, 20));
+		assert_eq!(Buidl::bounty_escrow(0), Some(50));
+		assert_eq!(Buidl::bounty_escrow(2), None);
+
+		assert_ok!(Buidl::claim_bounty(RuntimeOrigin::signed(3), 0, H256::repeat_byte(7)));
+		assert_eq!(Buidl::bounty_escrow(0), Some(50));
+		assert_ok!(Buidl::approve_bounty(RuntimeOrigin::signed(1), 0));
+		assert_eq!(Buidl::bounty_escrow(0), Some(0));
+
+		// an expired bounty is no longer in escrow, though its funds stay reserved until the
+		// poster cancels
+		System::set_block_number(10);
+		assert_eq!(Buidl::bounty_escrow(1), Some(0));
+		assert_eq!(Balances::reserved_balance(1), 30);
+		assert_ok!(Buidl::cancel_bounty(RuntimeOrigin::signed(1), 1));
+		assert_eq!(Buidl::bounty_escrow(1), None);
+	});
+}
+
+#[test]
+fn bounty_can_only_be_claimed_once_and_before_expiry() {
+	new_test_ext().execute_with(|| {
+		let team_id = team(1, vec![]);
+		assert_ok!(Buidl::post_bounty(
+			RuntimeOrigin::signed(1),
+			team_id,
+			HACKATHON,
+			H256::repeat_byte(1),
+			50,
+			10,
+			20
+		));
+		assert_ok!(Buidl::post_bounty(
+			RuntimeOrigin::signed(1),
+			team_id,
+			HACKATHON,
+			H256::repeat_byte(2),
+			50,
+			10,
+			20
+		));
+
+		assert_ok!(Buidl::claim_bounty(RuntimeOrigin::signed(3), 0, H256::repeat_byte(7)));
+		assert_noop!(
+			Buidl::claim_bounty(RuntimeOrigin::signed(4), 0, H256::repeat_byte(8)),
+			Error::<Test>::BountyAlreadyClaimed
+		);
+
+		System::set_block_number(10);
+		assert_noop!(
+			Buidl::claim_bounty(RuntimeOrigin::signed(4), 1, H256::repeat_byte(8)),
+			Error::<Test>::BountyExpired
+		);
+		assert_noop!(
+			Buidl::claim_bounty(RuntimeOrigin::signed(4), 2, H256::repeat_byte(8)),
+			Error::<Test>::BountyDoesNotExist
+		);
+	});
+}
+
+#[test]
+fn extend_bounty_expiry_requires_member_and_later_expiry() {
+	new_test_ext().execute_with(|| {
+		let team_id = team(1, vec![2]);
+		assert_ok!(Buidl::post_bounty(
+			RuntimeOrigin::signed(1),
+			team_id,
+			HACKATHON,
+			H256::repeat_byte(1),
+			50,
+			10,
+			20
+		));
+		assert_noop!(
+			Buidl::extend_bounty_expiry(RuntimeOrigin::signed(3), 0, 20),
+			Error::<Test>::NotATeamMember
+		);
+		assert_noop!(
+			Buidl::extend_bounty_expiry(RuntimeOrigin::signed(2), 0, 10),
+			Error::<Test>::ExpiryNotInFuture
+		);
+
+		assert_ok!(Buidl::extend_bounty_expiry(RuntimeOrigin::signed(2), 0, 20));
+		System::assert_last_event(
+			crate::Event::<Test>::BountyExtended { bounty_id: 0, new_expiry: 20 }.into(),
+		);
+		assert_eq!(Bounties::<Test>::get(0).unwrap().expiry, 20);
+
+		// the extension keeps it claimable past the original expiry
+		System::set_block_number(15);
+		assert_ok!(Buidl::claim_bounty(RuntimeOrigin::signed(3), 0, H256::repeat_byte(7)));
+	});
+}
+
+#[test]
+fn poster_can_cancel_an_unclaimed_bounty() {
+	new_test_ext().execute_with(|| {
+		let team_id = team(1, vec![2]);
+		assert_ok!(Buidl::post_bounty(RuntimeOrigin::signed(1), team_id, HACKATHON, H256::repeat_byte(1), 50, 10, 20));
+		assert_ok!(Buidl::post_bounty(RuntimeOrigin::signed(1), team_id, HACKATHON, H256::repeat_byte(2), 30, 10, 20));
+		assert_eq!(Balances::reserved_balance(1), 80);
+
+		assert_noop!(Buidl::cancel_bounty(RuntimeOrigin::signed(2), 0), Error::<Test>::NotBountyPoster);
+		assert_ok!(Buidl::cancel_bounty(RuntimeOrigin::signed(1), 0));
+		System::assert_last_event(
+			crate::Event::<Test>::BountyCancelled { bounty_id: 0, poster: 1, amount: 50 }.into(),
+		);
+		assert!(Bounties::<Test>::get(0).is_none());
+		assert_eq!(Balances::reserved_balance(1), 30);
+		assert_eq!(Balances::free_balance(1), 970);
+
+		// a claimed bounty is left to the team's approve or reject decision
+		assert_ok!(Buidl::claim_bounty(RuntimeOrigin::signed(3), 1, H256::repeat_byte(7)));
+		assert_noop!(Buidl::cancel_bounty(RuntimeOrigin::signed(1), 1), Error::<Test>::BountyAlreadyClaimed);
+		assert_noop!(Buidl::cancel_bounty(RuntimeOrigin::signed(1), 0), Error::<Test>::BountyDoesNotExist);
+	});
+}
+
+#[test]
+fn majority_rejection_resets_the_bounty() {
+	new_test_ext().execute_with(|| {
+		let team_id = team(1, vec![2, 4]);
+		assert_ok!(Buidl::post_bounty(
+			RuntimeOrigin::signed(1),
+			team_id,
+			HACKATHON,
+			H256::repeat_byte(1),
+			50,
+			10,
+			20
+		));
+		assert_ok!(Buidl::claim_bounty(RuntimeOrigin::signed(3), 0, H256::repeat_byte(7)));
+
+		assert_ok!(Buidl::reject_bounty(RuntimeOrigin::signed(1), 0));
+		let bounty = Bounties::<Test>::get(0).unwrap();
+		assert!(bounty.pending_reject);
+		assert_eq!(bounty.claimant, Some(3));
+		assert_noop!(Buidl::reject_bounty(RuntimeOrigin::signed(1), 0), Error::<Test>::AlreadyRejected);
+		assert_noop!(
+			Buidl::approve_bounty(RuntimeOrigin::signed(1), 0),
+			Error::<Test>::BountyRejectionPending
+		);
+
+		System::set_block_number(5);
+		assert_ok!(Buidl::reject_bounty(RuntimeOrigin::signed(2), 0));
+		System::assert_last_event(crate::Event::<Test>::BountyReset { bounty_id: 0, expiry: 14 }.into());
+		let bounty = Bounties::<Test>::get(0).unwrap();
+		assert_eq!(bounty.status, BountyStatus::Open);
+		assert_eq!(bounty.claimant, None);
+		assert_eq!(bounty.solution, None);
+		assert!(!bounty.pending_reject);
+		assert_eq!(bounty.expiry, 14);
+
+		// the bounty can be claimed again by someone else
+		assert_ok!(Buidl::claim_bounty(RuntimeOrigin::signed(5), 0, H256::repeat_byte(8)));
+	});
+}
+
+#[test]
+fn expired_bounty_can_be_redirected_to_a_challenge() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Buidl::create_challenge(RuntimeOrigin::signed(1), HACKATHON, H256::repeat_byte(1), 100, None, None));
+		let team_id = team(2, vec![3]);
+		assert_ok!(Buidl::post_bounty(
+			RuntimeOrigin::signed(2),
+			team_id,
+			HACKATHON,
+			H256::repeat_byte(2),
+			40,
+			10,
+			0
+		));
+
+		assert_noop!(
+			Buidl::redirect_bounty_to_challenge(RuntimeOrigin::signed(3), 0, HACKATHON, 0),
+			Error::<Test>::BountyNotExpired
+		);
+
+		System::set_block_number(10);
+		assert_noop!(
+			Buidl::redirect_bounty_to_challenge(RuntimeOrigin::signed(4), 0, HACKATHON, 0),
+			Error::<Test>::NotATeamMember
+		);
+		assert_ok!(Buidl::redirect_bounty_to_challenge(RuntimeOrigin::signed(3), 0, HACKATHON, 0));
+
+		assert!(Bounties::<Test>::get(0).is_none());
+		assert_eq!(Challenges::<Test>::get(HACKATHON, 0).unwrap().reward, 140);
+		assert_eq!(Balances::reserved_balance(1), 140);
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_eq!(Balances::free_balance(2), 960);
+	});
+}
+
+#[test]
+fn challenge_contributors_are_capped() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Buidl::create_challenge(RuntimeOrigin::signed(1), HACKATHON, H256::repeat_byte(1), 100, None, None));
+		for poster in [2, 3, 4, 2] {
+			let team_id = team(poster, vec![]);
+			assert_ok!(Buidl::post_bounty(RuntimeOrigin::signed(poster), team_id, HACKATHON, H256::repeat_byte(2), 10, 5, 0));
+		}
+		System::set_block_number(5);
+		assert_ok!(Buidl::redirect_bounty_to_challenge(RuntimeOrigin::signed(2), 0, HACKATHON, 0));
+		assert_ok!(Buidl::redirect_bounty_to_challenge(RuntimeOrigin::signed(3), 1, HACKATHON, 0));
+		assert_noop!(
+			Buidl::redirect_bounty_to_challenge(RuntimeOrigin::signed(4), 2, HACKATHON, 0),
+			Error::<Test>::TooManyContributors
+		);
+		// an existing contributor can still add more
+		assert_ok!(Buidl::redirect_bounty_to_challenge(RuntimeOrigin::signed(2), 3, HACKATHON, 0));
+		assert_eq!(ChallengeContributions::<Test>::get(0, 2), 20);
+		assert_eq!(Challenges::<Test>::get(HACKATHON, 0).unwrap().reward, 130);
+	});
+}
+
+#[test]
+fn reputation_rewards_wins_and_completed_bounties() {
+	new_test_ext().execute_with(|| {
+		challenge_with_two_solutions(10);
+		assert_ok!(Buidl::vote(RuntimeOrigin::signed(4), HACKATHON, 0, 0));
+		System::set_block_number(VOTE_END);
+		assert_ok!(Buidl::finalize_challenge(RuntimeOrigin::signed(1), HACKATHON, 0));
+		assert_eq!(Buidl::reputation(2), WIN_REPUTATION);
+		assert_eq!(Buidl::reputation(3), WIN_REPUTATION);
+		assert_eq!(Buidl::reputation(7), 0);
+
+		let bounty_team = team(5, vec![]);
+		assert_ok!(Buidl::post_bounty(
+			RuntimeOrigin::signed(5),
+			bounty_team,
+			HACKATHON,
+			H256::repeat_byte(2),
+			10,
+			VOTE_END + 10,
+			0
+		));
+		assert_ok!(Buidl::claim_bounty(RuntimeOrigin::signed(3), 0, H256::repeat_byte(7)));
+		assert_ok!(Buidl::approve_bounty(RuntimeOrigin::signed(5), 0));
+		assert_eq!(Buidl::reputation(3), WIN_REPUTATION + BOUNTY_REPUTATION);
+	});
+}
+
+#[test]
+fn coauthorship_is_capped_across_teams() {
+	new_test_ext().execute_with(|| {
+		set_periods();
+		for _ in 0..3 {
+			assert_ok!(Buidl::create_challenge(
+				RuntimeOrigin::signed(1),
+				HACKATHON,
+				H256::repeat_byte(1),
+				10,
+				None,
+				None
+			));
+		}
+		let first = team(2, vec![5]);
+		let second = team(3, vec![5]);
+		let third = team(4, vec![5]);
+		assert_ok!(Buidl::submit_solution(RuntimeOrigin::signed(2), HACKATHON, 0, first, H256::repeat_byte(5), false));
+		assert_ok!(Buidl::submit_solution(RuntimeOrigin::signed(3), HACKATHON, 1, second, H256::repeat_byte(5), false));
+		assert_noop!(
+			Buidl::submit_solution(RuntimeOrigin::signed(4), HACKATHON, 2, third, H256::repeat_byte(5), false),
+			Error::<Test>::TooManyCoauthorships
+		);
+	});
+}
+
+#[test]
+fn one_team_competes_in_two_hackathons_independently() {
+	new_test_ext().execute_with(|| {
+		let second = NextHackathonId::<Test>::get();
+		assert_ok!(Buidl::register(RuntimeOrigin::signed(ORGANIZER), H256::repeat_byte(2)));
+		assert_ok!(Buidl::create_challenge(RuntimeOrigin::signed(1), HACKATHON, H256::repeat_byte(1), 10, None, None));
+		assert_ok!(Buidl::create_challenge(RuntimeOrigin::signed(1), second, H256::repeat_byte(2), 10, None, None));
+		set_periods();
+		assert_ok!(Buidl::update_period(RuntimeOrigin::root(), second, 1, 10, 10, 20));
+		let team_id = team(2, vec![3]);
+
+		assert_noop!(Buidl::enter_hackathon(RuntimeOrigin::signed(3), team_id, HACKATHON), Error::<Test>::NotTeamFounder);
+		Hackathons::<Test>::mutate(HACKATHON, |hackathon| hackathon.as_mut().unwrap().max_team_size = 1);
+		assert_noop!(Buidl::enter_hackathon(RuntimeOrigin::signed(2), team_id, HACKATHON), Error::<Test>::TeamTooLarge);
+		Hackathons::<Test>::mutate(HACKATHON, |hackathon| hackathon.as_mut().unwrap().max_team_size = 5);
+		assert_ok!(Buidl::set_event_judges(RuntimeOrigin::signed(ORGANIZER), second, vec![3].try_into().unwrap()));
+		assert_noop!(Buidl::enter_hackathon(RuntimeOrigin::signed(2), team_id, second), Error::<Test>::JudgeIsParticipant);
+		assert_ok!(Buidl::set_event_judges(RuntimeOrigin::signed(ORGANIZER), second, vec![4].try_into().unwrap()));
+
+		for hackathon_id in [HACKATHON, second] {
+			assert_ok!(Buidl::enter_hackathon(RuntimeOrigin::signed(2), team_id, hackathon_id));
+			System::assert_last_event(crate::Event::TeamEntered { team_id, hackathon_id }.into());
+		}
+		assert_noop!(Buidl::enter_hackathon(RuntimeOrigin::signed(2), team_id, second), Error::<Test>::AlreadyEntered);
+
+		// the same roster submits to each event, and a ruling in one leaves the other alone
+		assert_ok!(Buidl::submit_solution(RuntimeOrigin::signed(2), HACKATHON, 0, team_id, H256::repeat_byte(5), false));
+		assert_ok!(Buidl::submit_solution(RuntimeOrigin::signed(3), second, 1, team_id, H256::repeat_byte(6), false));
+		assert_ok!(Buidl::disqualify_solution(RuntimeOrigin::signed(ORGANIZER), HACKATHON, 0, 0));
+		assert!(ChallengeSolutions::<Test>::get(0, 0).unwrap().disqualified);
+		assert!(!ChallengeSolutions::<Test>::get(1, 0).unwrap().disqualified);
+		assert_eq!(Coauthorships::<Test>::get(HACKATHON, 2), 1);
+		assert_eq!(Coauthorships::<Test>::get(second, 2), 1);
+
+		System::set_block_number(SUBMISSION_END);
+		let late = team(4, vec![]);
+		assert_noop!(
+			Buidl::enter_hackathon(RuntimeOrigin::signed(4), late, HACKATHON),
+			Error::<Test>::SubmissionsAlreadyEnded
+		);
+	});
+}
+
+#[test]
+fn coauthorship_cap_is_per_hackathon() {
+	new_test_ext().execute_with(|| {
+		set_periods();
+		for _ in 0..2 {
+			assert_ok!(Buidl::create_challenge(
+				RuntimeOrigin::signed(1),
+				HACKATHON,
+				H256::repeat_byte(1),
+				10,
+				None,
+				None
+			));
+		}
+		let first = team(2, vec![5]);
+		let second = team(3, vec![5]);
+		assert_ok!(Buidl::submit_solution(RuntimeOrigin::signed(2), HACKATHON, 0, first, H256::repeat_byte(5), false));
+		assert_ok!(Buidl::submit_solution(RuntimeOrigin::signed(3), HACKATHON, 1, second, H256::repeat_byte(5), false));
+
+		// 5 is at the cap in the first hackathon but may still submit to another
+		let next = NextHackathonId::<Test>::get();
+		assert_ok!(Buidl::register(RuntimeOrigin::signed(ORGANIZER), H256::repeat_byte(2)));
+		assert_ok!(Buidl::update_period(RuntimeOrigin::root(), next, 1, 10, 10, 20));
+		let challenge_id = NextChallengeId::<Test>::get();
+		assert_ok!(Buidl::create_challenge(RuntimeOrigin::signed(1), next, H256::repeat_byte(1), 10, None, None));
+		let third = team(4, vec![5]);
+		assert_ok!(Buidl::submit_solution(
+			RuntimeOrigin::signed(4),
+			next,
+			challenge_id,
+			third,
+			H256::repeat_byte(5),
+			false
+		));
+	});
+}
+
+fn run_to_block(n: u64) {
+	while System::block_number() < n {
+		System::set_block_number(System::block_number() + 1);
+		Buidl::on_initialize(System::block_number());
+	}
+}
+
+fn count_events(event: crate::Event<Test>) -> usize {
+	let event: RuntimeEvent = event.into();
+	System::events().into_iter().filter(|record| record.event == event).count()
+}
+
+#[test]
+fn phase_end_events_fire_once_at_each_boundary() {
+	new_test_ext().execute_with(|| {
+		set_periods();
+
+		run_to_block(SUBMISSION_END - 1);
+		assert_eq!(count_events(crate::Event::SubmissionPhaseEnded { hackathon_id: HACKATHON }), 0);
+		run_to_block(SUBMISSION_END);
+		assert_eq!(count_events(crate::Event::SubmissionPhaseEnded { hackathon_id: HACKATHON }), 1);
+
+		run_to_block(VOTE_END - 1);
+		assert_eq!(count_events(crate::Event::VotingPhaseEnded { hackathon_id: HACKATHON }), 0);
+		run_to_block(VOTE_END + 5);
+		assert_eq!(count_events(crate::Event::SubmissionPhaseEnded { hackathon_id: HACKATHON }), 1);
+		assert_eq!(count_events(crate::Event::VotingPhaseEnded { hackathon_id: HACKATHON }), 1);
+	});
+}
+
+#[test]
+fn default_periods_apply_when_update_period_is_skipped() {
+	new_test_ext().execute_with(|| {
+		let submission_start = 1 + <Test as crate::Config>::DefaultCreationDuration::get();
+		let submission_end = submission_start + <Test as crate::Config>::DefaultSubmissionDuration::get();
+		let vote_end = submission_end + <Test as crate::Config>::DefaultVotingDuration::get();
+		assert_eq!(
+			Buidl::periods(HACKATHON),
+			Some(EventPeriods { submission_start, submission_end, vote_start: submission_end, vote_end })
+		);
+
+		// the creation window leaves time to post and refine challenges
+		assert_ok!(Buidl::create_challenge(
+			RuntimeOrigin::signed(1),
+			HACKATHON,
+			H256::repeat_byte(1),
+			10,
+			judges(vec![4]),
+			None
+		));
+		assert_ok!(Buidl::edit_challenge(RuntimeOrigin::signed(1), HACKATHON, 0, H256::repeat_byte(2), None));
+		assert_ok!(Buidl::set_early_bonus(
+			RuntimeOrigin::signed(1),
+			HACKATHON,
+			0,
+			Some(EarlyBonus { cutoff: submission_start + 1, votes: 1 })
+		));
+		let team_id = team(2, vec![]);
+		assert_noop!(
+			Buidl::submit_solution(RuntimeOrigin::signed(2), HACKATHON, 0, team_id, H256::repeat_byte(5), false),
+			Error::<Test>::SubmissionPeriodClosed
+		);
+
+		run_to_block(submission_start);
+		assert_noop!(
+			Buidl::edit_challenge(RuntimeOrigin::signed(1), HACKATHON, 0, H256::repeat_byte(3), None),
+			Error::<Test>::ChallengeLocked
+		);
+		assert_ok!(Buidl::submit_solution(RuntimeOrigin::signed(2), HACKATHON, 0, team_id, H256::repeat_byte(5), false));
+
+		run_to_block(submission_end);
+		assert_eq!(count_events(crate::Event::SubmissionPhaseEnded { hackathon_id: HACKATHON }), 1);
+		assert_eq!(Buidl::hackathon_schedule(HACKATHON).unwrap().phase, Phase::Voting);
+		assert_ok!(Buidl::vote(RuntimeOrigin::signed(4), HACKATHON, 0, 0));
+
+		run_to_block(vote_end);
+		assert_eq!(count_events(crate::Event::VotingPhaseEnded { hackathon_id: HACKATHON }), 1);
+		assert_ok!(Buidl::finalize_challenge(RuntimeOrigin::signed(1), HACKATHON, 0));
+		assert_eq!(ChallengeWinners::<Test>::get(0), Some(0));
+	});
+}
+
+#[test]
+fn closing_submissions_early_opens_voting_straight_away() {
+	new_test_ext().execute_with(|| {
+		set_periods();
+		assert_ok!(Buidl::create_challenge(
+			RuntimeOrigin::signed(1),
+			HACKATHON,
+			H256::repeat_byte(1),
+			10,
+			judges(vec![4]),
+			None
+		));
+		let first = team(2, vec![]);
+		assert_ok!(Buidl::submit_solution(RuntimeOrigin::signed(2), HACKATHON, 0, first, H256::repeat_byte(5), false));
+		assert_noop!(Buidl::vote(RuntimeOrigin::signed(4), HACKATHON, 0, 0), Error::<Test>::VotingPeriodClosed);
+
+		run_to_block(5);
+		assert_noop!(
+			Buidl::close_submissions_early(RuntimeOrigin::signed(1), HACKATHON),
+			Error::<Test>::NotHackathonOrganizer
+		);
+		assert_ok!(Buidl::close_submissions_early(RuntimeOrigin::signed(ORGANIZER), HACKATHON));
+		System::assert_last_event(
+			crate::Event::SubmissionsClosedEarly { hackathon_id: HACKATHON, submission_end: 5 }.into(),
+		);
+		assert_eq!(count_events(crate::Event::SubmissionPhaseEnded { hackathon_id: HACKATHON }), 1);
+		let periods = Buidl::periods(HACKATHON).unwrap();
+		assert_eq!((periods.submission_end, periods.vote_start, periods.vote_end), (5, 5, VOTE_END));
+
+		let second = team(3, vec![]);
+		assert_noop!(
+			Buidl::submit_solution(RuntimeOrigin::signed(3), HACKATHON, 0, second, H256::repeat_byte(6), false),
+			Error::<Test>::SubmissionPeriodClosed
+		);
+		assert_ok!(Buidl::vote(RuntimeOrigin::signed(4), HACKATHON, 0, 0));
+		assert_noop!(
+			Buidl::close_submissions_early(RuntimeOrigin::root(), HACKATHON),
+			Error::<Test>::SubmissionPeriodClosed
+		);
+
+		// the original end is no longer announced, the voting end still is
+		run_to_block(VOTE_END);
+		assert_eq!(count_events(crate::Event::SubmissionPhaseEnded { hackathon_id: HACKATHON }), 1);
+		assert_eq!(count_events(crate::Event::VotingPhaseEnded { hackathon_id: HACKATHON }), 1);
+	});
+}
+
+#[test]
+fn moved_periods_announce_only_their_new_ends() {
+	new_test_ext().execute_with(|| {
+		set_periods();
+		assert_ok!(Buidl::update_period(RuntimeOrigin::root(), HACKATHON, 1, 12, 12, 25));
+		assert!(SubmissionEnds::<Test>::get(SUBMISSION_END).is_empty());
+		assert_eq!(SubmissionEnds::<Test>::get(12).into_inner(), vec![HACKATHON]);
+
+		run_to_block(SUBMISSION_END);
+		assert_eq!(count_events(crate::Event::SubmissionPhaseEnded { hackathon_id: HACKATHON }), 0);
+		run_to_block(12);
+		assert_eq!(count_events(crate::Event::SubmissionPhaseEnded { hackathon_id: HACKATHON }), 1);
+
+		// once announced, an end cannot be moved and announced again
+		assert_noop!(
+			Buidl::update_period(RuntimeOrigin::root(), HACKATHON, 1, 15, 15, 25),
+			Error::<Test>::SubmissionsAlreadyEnded
+		);
+		run_to_block(VOTE_END);
+		assert_eq!(count_events(crate::Event::VotingPhaseEnded { hackathon_id: HACKATHON }), 0);
+		run_to_block(25);
+		assert_eq!(count_events(crate::Event::VotingPhaseEnded { hackathon_id: HACKATHON }), 1);
+	});
+}
+
+#[test]
+fn update_period_rejects_ends_that_would_never_be_announced() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(5);
+		assert_noop!(
+			Buidl::update_period(RuntimeOrigin::root(), HACKATHON, 1, 5, 5, 20),
+			Error::<Test>::PeriodEndNotInFuture
+		);
+		assert_ok!(Buidl::update_period(RuntimeOrigin::root(), HACKATHON, 1, 6, 6, 20));
+	});
+}
+
+#[test]
+fn phase_ends_per_block_are_capped() {
+	new_test_ext().execute_with(|| {
+		set_periods();
+		let second = NextHackathonId::<Test>::get();
+		assert_ok!(Buidl::register(RuntimeOrigin::signed(ORGANIZER), H256::repeat_byte(2)));
+		assert_ok!(Buidl::update_period(RuntimeOrigin::root(), second, 1, 10, 10, 20));
+		// a third registration in the same block still succeeds, with its ends left unindexed
+		let third = NextHackathonId::<Test>::get();
+		assert_ok!(Buidl::register(RuntimeOrigin::signed(ORGANIZER), H256::repeat_byte(3)));
+		assert!(Buidl::periods(third).is_some());
+		assert_eq!(SubmissionEnds::<Test>::get(SUBMISSION_END).into_inner(), vec![HACKATHON, second]);
+		assert_noop!(
+			Buidl::update_period(RuntimeOrigin::root(), third, 2, 10, 10, 20),
+			Error::<Test>::TooManyPhaseEnds
+		);
+		// a full voting end block rejects the periods without indexing their submission end
+		assert_noop!(
+			Buidl::update_period(RuntimeOrigin::root(), third, 2, 11, 11, 20),
+			Error::<Test>::TooManyPhaseEnds
+		);
+		assert_ok!(Buidl::update_period(RuntimeOrigin::root(), third, 2, 11, 11, 21));
+
+		run_to_block(SUBMISSION_END);
+		assert_eq!(count_events(crate::Event::SubmissionPhaseEnded { hackathon_id: HACKATHON }), 1);
+		assert_eq!(count_events(crate::Event::SubmissionPhaseEnded { hackathon_id: second }), 1);
+		assert_eq!(count_events(crate::Event::SubmissionPhaseEnded { hackathon_id: third }), 0);
+		assert!(!SubmissionEnds::<Test>::contains_key(SUBMISSION_END));
+	});
+}
+
+#[test]
+fn active_hackathons_follow_registration_and_vote_end() {
+	new_test_ext().execute_with(|| {
+		set_periods();
+		assert_eq!(Buidl::active_hackathons(), vec![HACKATHON]);
+		let second = NextHackathonId::<Test>::get();
+		assert_ok!(Buidl::register(RuntimeOrigin::signed(ORGANIZER), H256::repeat_byte(2)));
+		assert_ok!(Buidl::update_period(RuntimeOrigin::root(), second, 1, 10, 10, 25));
+		// the submission end block is full, so this one's ends are never announced
+		let third = NextHackathonId::<Test>::get();
+		assert_ok!(Buidl::register(RuntimeOrigin::signed(ORGANIZER), H256::repeat_byte(3)));
+		assert_eq!(Buidl::active_hackathons(), vec![HACKATHON, second, third]);
+		assert_noop!(
+			Buidl::register(RuntimeOrigin::signed(ORGANIZER), H256::repeat_byte(4)),
+			Error::<Test>::TooManyActiveHackathons
+		);
+
+		run_to_block(VOTE_END);
+		assert_eq!(crate::ActiveHackathons::<Test>::get().into_inner(), vec![second, third]);
+		assert_eq!(Buidl::active_hackathons(), vec![second]);
+		let fourth = NextHackathonId::<Test>::get();
+		assert_ok!(Buidl::register(RuntimeOrigin::signed(ORGANIZER), H256::repeat_byte(4)));
+		assert_eq!(crate::ActiveHackathons::<Test>::get().into_inner(), vec![second, fourth]);
+
+		run_to_block(25);
+		assert_eq!(Buidl::active_hackathons(), vec![fourth]);
+	});
+}
+
+#[test]
+fn register_reserves_the_bond() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(Balances::reserved_balance(ORGANIZER), 50);
+		assert_ok!(Buidl::register(RuntimeOrigin::signed(1), H256::repeat_byte(7)));
+		System::assert_last_event(
+			crate::Event::HackathonRegistered { hackathon_id: HACKATHON + 1, organizer: 1 }.into(),
+		);
+
+		let hackathon = Hackathons::<Test>::get(HACKATHON + 1).unwrap();
+		assert_eq!(hackathon.organizer, 1);
+		assert_eq!(hackathon.name, H256::repeat_byte(7));
+		assert_eq!(
+			hackathon.periods,
+			Some(EventPeriods {
+				submission_start: 5,
+				submission_end: SUBMISSION_END,
+				vote_start: VOTE_START,
+				vote_end: VOTE_END,
+			})
+		);
+		assert_eq!(NextHackathonId::<Test>::get(), HACKATHON + 2);
+		assert_eq!(Balances::reserved_balance(1), 50);
+
+		assert_noop!(
+			Buidl::register(RuntimeOrigin::signed(6), H256::repeat_byte(8)),
+			Error::<Test>::InsufficientBalance
+		);
+	});
+}
+
+#[test]
+fn challenges_are_isolated_per_hackathon() {
+	new_test_ext().execute_with(|| {
+		let other = NextHackathonId::<Test>::get();
+		assert_ok!(Buidl::register(RuntimeOrigin::signed(1), H256::repeat_byte(7)));
+		assert_ok!(Buidl::update_period(RuntimeOrigin::root(), other, 5, 10, 10, 20));
+		assert_noop!(
+			Buidl::create_challenge(RuntimeOrigin::signed(1), other + 1, H256::repeat_byte(1), 10, None, None),
+			Error::<Test>::HackathonDoesNotExist
+		);
+
+		assert_ok!(Buidl::create_challenge(RuntimeOrigin::signed(1), HACKATHON, H256::repeat_byte(1), 10, None, None));
+		assert!(Challenges::<Test>::get(other, 0).is_none());
+
+		// only `HACKATHON` has its submission window open
+		set_periods();
+		let team_id = team(2, vec![]);
+		assert_noop!(
+			Buidl::submit_solution(RuntimeOrigin::signed(2), other, 0, team_id, H256::repeat_byte(5), false),
+			Error::<Test>::ChallengeDoesNotExist
+		);
+		assert_ok!(Buidl::create_challenge(RuntimeOrigin::signed(1), other, H256::repeat_byte(1), 10, None, None));
+		assert_noop!(
+			Buidl::submit_solution(RuntimeOrigin::signed(2), other, 1, team_id, H256::repeat_byte(5), false),
+			Error::<Test>::SubmissionPeriodClosed
+		);
+		assert_ok!(Buidl::submit_solution(RuntimeOrigin::signed(2), HACKATHON, 0, team_id, H256::repeat_byte(5), false));
+	});
+}
+
+#[test]
+fn cancel_challenge_refunds_the_author() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Buidl::create_challenge(RuntimeOrigin::signed(1), HACKATHON, H256::repeat_byte(1), 100, None, None));
+		assert_noop!(
+			Buidl::cancel_challenge(RuntimeOrigin::signed(2), HACKATHON, 0),
+			Error::<Test>::NotChallengeAuthor
+		);
+
+		assert_ok!(Buidl::cancel_challenge(RuntimeOrigin::signed(1), HACKATHON, 0));
+		System::assert_last_event(
+			crate::Event::ChallengeCancelled { hackathon_id: HACKATHON, challenge_id: 0 }.into(),
+		);
+		assert!(Challenges::<Test>::get(HACKATHON, 0).is_none());
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), 1_000);
+		assert_noop!(
+			Buidl::cancel_challenge(RuntimeOrigin::signed(1), HACKATHON, 0),
+			Error::<Test>::ChallengeDoesNotExist
+		);
+	});
+}
+
+#[test]
+fn cancel_challenge_rejected_with_submissions_or_once_voting_starts() {
+	new_test_ext().execute_with(|| {
+		set_periods();
+		assert_ok!(Buidl::create_challenge(RuntimeOrigin::signed(1), HACKATHON, H256::repeat_byte(1), 10, None, None));
+		assert_ok!(Buidl::create_challenge(RuntimeOrigin::signed(1), HACKATHON, H256::repeat_byte(2), 10, None, None));
+		let team_id = team(2, vec![]);
+		assert_ok!(Buidl::submit_solution(RuntimeOrigin::signed(2), HACKATHON, 0, team_id, H256::repeat_byte(5), false));
+
+		assert_noop!(
+			Buidl::cancel_challenge(RuntimeOrigin::signed(1), HACKATHON, 0),
+			Error::<Test>::ChallengeHasSubmissions
+		);
+
+		System::set_block_number(VOTE_START);
+		assert_noop!(
+			Buidl::cancel_challenge(RuntimeOrigin::signed(1), HACKATHON, 1),
+			Error::<Test>::VotingAlreadyStarted
+		);
+	});
+}
+
+#[test]
+fn submission_format_is_set_on_create_and_edit() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Buidl::create_challenge(
+			RuntimeOrigin::signed(1),
+			HACKATHON,
+			H256::repeat_byte(1),
+			10,
+			None,
+			Some(H256::repeat_byte(3))
+		));
+		assert_eq!(
+			Buidl::challenge_detail(HACKATHON, 0).unwrap().submission_format,
+			Some(H256::repeat_byte(3))
+		);
+
+		assert_ok!(Buidl::edit_challenge(
+			RuntimeOrigin::signed(1),
+			HACKATHON,
+			0,
+			H256::repeat_byte(1),
+			Some(H256::repeat_byte(4))
+		));
+		assert_eq!(
+			Buidl::challenge_detail(HACKATHON, 0).unwrap().submission_format,
+			Some(H256::repeat_byte(4))
+		);
+		assert!(Buidl::challenge_detail(HACKATHON, 1).is_none());
+	});
+}
+
+#[test]
+fn challenge_events_carry_reward_and_editor() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Buidl::create_challenge(
+			RuntimeOrigin::signed(1),
+			HACKATHON,
+			H256::repeat_byte(1),
+			25,
+			judges(vec![2]),
+			None
+		));
+		System::assert_last_event(
+			crate::Event::ChallengeCreated {
+				hackathon_id: HACKATHON,
+				id: 0,
+				creator: 1,
+				reward: 25,
+				judges_supplied: true,
+			}
+			.into(),
+		);
+
+		assert_ok!(Buidl::create_challenge(RuntimeOrigin::signed(1), HACKATHON, H256::repeat_byte(1), 5, None, None));
+		System::assert_last_event(
+			crate::Event::ChallengeCreated {
+				hackathon_id: HACKATHON,
+				id: 1,
+				creator: 1,
+				reward: 5,
+				judges_supplied: false,
+			}
+			.into(),
+		);
+
+		assert_ok!(Buidl::edit_challenge(RuntimeOrigin::signed(1), HACKATHON, 1, H256::repeat_byte(2), None));
+		System::assert_last_event(
+			crate::Event::ChallengeEdited { hackathon_id: HACKATHON, id: 1, editor: 1 }.into(),
+		);
+	});
+}
+
+#[test]
+fn cancelled_treasury_challenge_refunds_the_treasury_and_contributors() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Buidl::create_treasury_challenge(
+				RuntimeOrigin::signed(1),
+				HACKATHON,
+				1,
+				H256::repeat_byte(1),
+				100,
+				None,
+				None
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(Buidl::create_treasury_challenge(
+			RuntimeOrigin::root(),
+			HACKATHON,
+			1,
+			H256::repeat_byte(1),
+			100,
+			None,
+			None
+		));
+		let challenge = Challenges::<Test>::get(HACKATHON, 0).unwrap();
+		assert_eq!((challenge.author, challenge.funder), (1, TREASURY));
+		assert_eq!(Balances::reserved_balance(TREASURY), 100);
+		assert_eq!(Balances::reserved_balance(1), 0);
+
+		let team_id = team(2, vec![]);
+		assert_ok!(Buidl::post_bounty(RuntimeOrigin::signed(2), team_id, HACKATHON, H256::repeat_byte(2), 40, 5, 0));
+		System::set_block_number(5);
+		assert_ok!(Buidl::redirect_bounty_to_challenge(RuntimeOrigin::signed(2), 0, HACKATHON, 0));
+		assert_eq!(Balances::reserved_balance(TREASURY), 140);
+
+		assert_ok!(Buidl::cancel_challenge(RuntimeOrigin::signed(1), HACKATHON, 0));
+		assert_eq!(Balances::reserved_balance(TREASURY), 0);
+		assert_eq!(Balances::free_balance(TREASURY), 1_000);
+		assert_eq!(Balances::free_balance(2), 1_000);
+		assert_eq!(Balances::free_balance(1), 1_000);
+	});
+}
+
+#[test]
+fn drafts_are_hidden_until_made_official() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Buidl::create_challenge(RuntimeOrigin::signed(1), HACKATHON, H256::repeat_byte(1), 10, judges(vec![4]), None));
+		set_periods();
+		let drafting = team(2, vec![3]);
+		let official = team(5, vec![]);
+		let staging = team(6, vec![]);
+
+		assert_ok!(Buidl::draft_solution(RuntimeOrigin::signed(2), HACKATHON, 0, drafting, H256::repeat_byte(5), false));
+		System::assert_last_event(crate::Event::SolutionDrafted { challenge_id: 0, solution_id: 0 }.into());
+		assert_noop!(
+			Buidl::submit_solution(RuntimeOrigin::signed(2), HACKATHON, 0, drafting, H256::repeat_byte(5), false),
+			Error::<Test>::SolutionAlreadySubmitted
+		);
+		assert_ok!(Buidl::submit_solution(RuntimeOrigin::signed(5), HACKATHON, 0, official, H256::repeat_byte(6), false));
+		assert_ok!(Buidl::draft_solution(RuntimeOrigin::signed(6), HACKATHON, 0, staging, H256::repeat_byte(7), false));
+		let (_, solutions) = Buidl::challenge_with_solutions(HACKATHON, 0).unwrap();
+		assert_eq!(solutions, vec![ChallengeSolutions::<Test>::get(0, 1).unwrap().into()]);
+
+		assert_noop!(
+			Buidl::set_official_solution(RuntimeOrigin::signed(4), HACKATHON, 0, 0),
+			Error::<Test>::NotATeamMember
+		);
+		assert_ok!(Buidl::set_official_solution(RuntimeOrigin::signed(3), HACKATHON, 0, 0));
+		System::assert_last_event(crate::Event::SolutionSubmitted { id: 0, member: Some(3) }.into());
+		assert_noop!(
+			Buidl::set_official_solution(RuntimeOrigin::signed(3), HACKATHON, 0, 0),
+			Error::<Test>::SolutionNotDraft
+		);
+		let (_, solutions) = Buidl::challenge_with_solutions(HACKATHON, 0).unwrap();
+		assert_eq!(solutions.len(), 2);
+
+		// a draft left unofficial when submissions close never reaches the judges
+		System::set_block_number(VOTE_START);
+		assert_noop!(
+			Buidl::set_official_solution(RuntimeOrigin::signed(6), HACKATHON, 0, 2),
+			Error::<Test>::SubmissionPeriodClosed
+		);
+		assert_noop!(Buidl::vote(RuntimeOrigin::signed(4), HACKATHON, 0, 2), Error::<Test>::SolutionDoesNotExist);
+		assert_ok!(Buidl::vote(RuntimeOrigin::signed(4), HACKATHON, 0, 1));
+		assert_eq!(Buidl::tally_votes(HACKATHON, 0), Some((1, 1)));
+		let (_, solutions) = Buidl::challenge_with_solutions(HACKATHON, 0).unwrap();
+		assert_eq!(solutions.len(), 2);
+	});
+}
+
+#[test]
+fn submission_bonds_are_returned_on_settlement_unless_disqualified() {
+	new_test_ext().execute_with(|| {
+		SubmissionBond::set(10);
+		assert_ok!(Buidl::create_challenge(RuntimeOrigin::signed(1), HACKATHON, H256::repeat_byte(1), 10, judges(vec![4]), None));
+		set_periods();
+		let winning = team(2, vec![]);
+		let losing = team(3, vec![]);
+		let disqualified = team(5, vec![]);
+		let broke = team(6, vec![]);
+		assert_noop!(
+			Buidl::submit_solution(RuntimeOrigin::signed(6), HACKATHON, 0, broke, H256::repeat_byte(9), false),
+			Error::<Test>::InsufficientBalanceForSubmissionBond
+		);
+		for (account, team_id) in [(2, winning), (3, losing), (5, disqualified)] {
+			assert_ok!(Buidl::submit_solution(RuntimeOrigin::signed(account), HACKATHON, 0, team_id, H256::repeat_byte(5), false));
+			assert_eq!(Balances::reserved_balance(account), 10);
+		}
+		// withdrawing hands the bond straight back
+		assert_ok!(Buidl::withdraw_solution(RuntimeOrigin::signed(3), HACKATHON, 0, 1));
+		assert_eq!(Balances::reserved_balance(3), 0);
+		assert_ok!(Buidl::submit_solution(RuntimeOrigin::signed(3), HACKATHON, 0, losing, H256::repeat_byte(6), false));
+
+		assert_noop!(
+			Buidl::disqualify_solution(RuntimeOrigin::signed(2), HACKATHON, 0, 2),
+			Error::<Test>::NotHackathonOrganizer
+		);
+		assert_ok!(Buidl::disqualify_solution(RuntimeOrigin::signed(ORGANIZER), HACKATHON, 0, 2));
+		System::assert_last_event(crate::Event::SolutionDisqualified { challenge_id: 0, solution_id: 2 }.into());
+		assert_noop!(
+			Buidl::disqualify_solution(RuntimeOrigin::signed(ORGANIZER), HACKATHON, 0, 2),
+			Error::<Test>::SolutionAlreadyDisqualified
+		);
+
+		System::set_block_number(VOTE_START);
+		assert_noop!(Buidl::vote(RuntimeOrigin::signed(4), HACKATHON, 0, 2), Error::<Test>::SolutionDoesNotExist);
+		assert_ok!(Buidl::vote(RuntimeOrigin::signed(4), HACKATHON, 0, 0));
+
+		System::set_block_number(VOTE_END);
+		assert_ok!(Buidl::finalize_challenge(RuntimeOrigin::signed(1), HACKATHON, 0));
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_eq!(Balances::free_balance(2), 1_010);
+		// the losing team submitted in good faith and is made whole
+		assert_eq!(Balances::reserved_balance(3), 0);
+		assert_eq!(Balances::free_balance(3), 1_000);
+		// the disqualified one loses its bond
+		assert_eq!(Balances::reserved_balance(5), 0);
+		assert_eq!(Balances::free_balance(5), 990);
+		assert_noop!(
+			Buidl::disqualify_solution(RuntimeOrigin::signed(ORGANIZER), HACKATHON, 0, 3),
+			Error::<Test>::ChallengeAlreadyFinalized
+		);
+	});
+}
+
+#[test]
+fn archiving_a_settled_challenge_keeps_only_its_result() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Buidl::create_challenge(RuntimeOrigin::signed(1), HACKATHON, H256::repeat_byte(1), 10, judges(vec![4]), None));
+		assert_ok!(Buidl::add_prize(RuntimeOrigin::signed(1), HACKATHON, 0, H256::repeat_byte(2), 20, vec![5].try_into().unwrap()));
+		set_periods();
+		let team_id = team(2, vec![3]);
+		assert_ok!(Buidl::submit_solution(RuntimeOrigin::signed(2), HACKATHON, 0, team_id, H256::repeat_byte(5), false));
+		System::set_block_number(VOTE_START);
+		assert_ok!(Buidl::vote(RuntimeOrigin::signed(4), HACKATHON, 0, 0));
+		assert_ok!(Buidl::vote_prize(RuntimeOrigin::signed(5), HACKATHON, 0, 0, 0));
+
+		System::set_block_number(VOTE_END);
+		assert_noop!(Buidl::archive_challenge(RuntimeOrigin::root(), HACKATHON, 0), Error::<Test>::ChallengeNotSettled);
+		assert_ok!(Buidl::finalize_challenge(RuntimeOrigin::signed(1), HACKATHON, 0));
+		// the prize is still to be paid out
+		assert_noop!(Buidl::archive_challenge(RuntimeOrigin::root(), HACKATHON, 0), Error::<Test>::ChallengeNotSettled);
+		assert_ok!(Buidl::finalize_prize(RuntimeOrigin::signed(1), HACKATHON, 0, 0));
+		assert_noop!(
+			Buidl::archive_challenge(RuntimeOrigin::signed(ORGANIZER), HACKATHON, 0),
+			sp_runtime::DispatchError::BadOrigin
+		);
+
+		assert_ok!(Buidl::archive_challenge(RuntimeOrigin::root(), HACKATHON, 0));
+		System::assert_last_event(crate::Event::ChallengeArchived { hackathon_id: HACKATHON, challenge_id: 0 }.into());
+		assert!(Challenges::<Test>::get(HACKATHON, 0).is_none());
+		assert_eq!(ChallengeSolutions::<Test>::iter_prefix(0).count(), 0);
+		assert_eq!(ChallengePrizes::<Test>::iter_prefix(0).count(), 0);
+		assert!(ChallengeWinners::<Test>::get(0).is_none());
+		assert_eq!(
+			Buidl::challenge_archive(0),
+			Some(ChallengeArchive { winner: Some(team_id), reward: 10, hackathon_id: HACKATHON })
+		);
+		assert!(Buidl::is_finalized(0));
+		assert_eq!(Buidl::leaderboard(HACKATHON), vec![(team_id, 10)]);
+		assert_noop!(
+			Buidl::finalize_challenge(RuntimeOrigin::signed(1), HACKATHON, 0),
+			Error::<Test>::ChallengeDoesNotExist
+		);
+	});
+}
+
+#[test]
+fn only_the_winning_team_acknowledges_off_chain_contributors() {
+	new_test_ext().execute_with(|| {
+		let contributors = |list: Vec<(H256, u8)>| -> BoundedVec<(H256, u8), <Test as crate::Config>::MaxMembers> {
+			list.try_into().unwrap()
+		};
+		challenge_with_two_solutions(10);
+		assert_ok!(Buidl::vote(RuntimeOrigin::signed(4), HACKATHON, 0, 0));
+		assert_ok!(Buidl::vote(RuntimeOrigin::signed(5), HACKATHON, 0, 0));
+		let credited = vec![(H256::repeat_byte(8), 30), (H256::repeat_byte(9), 20)];
+		assert_noop!(
+			Buidl::acknowledge_contributors(RuntimeOrigin::signed(2), 0, contributors(credited.clone())),
+			Error::<Test>::NoWinningTeam
+		);
+
+		System::set_block_number(VOTE_END);
+		assert_ok!(Buidl::finalize_challenge(RuntimeOrigin::signed(1), HACKATHON, 0));
+		// the losing team and the judges cannot
+		for outsider in [7, 4] {
+			assert_noop!(
+				Buidl::acknowledge_contributors(RuntimeOrigin::signed(outsider), 0, contributors(credited.clone())),
+				Error::<Test>::NotATeamMember
+			);
+		}
+		assert_noop!(
+			Buidl::acknowledge_contributors(
+				RuntimeOrigin::signed(2),
+				0,
+				contributors(vec![(H256::repeat_byte(8), 60), (H256::repeat_byte(9), 50)])
+			),
+			Error::<Test>::InvalidPercentage
+		);
+
+		let free = Balances::free_balance(2);
+		assert_ok!(Buidl::acknowledge_contributors(RuntimeOrigin::signed(3), 0, contributors(credited.clone())));
+		System::assert_last_event(
+			crate::Event::ContributorsAcknowledged { challenge_id: 0, team_id: 0, contributors: credited }.into(),
+		);
+		assert_eq!(Balances::free_balance(2), free);
+	});
+}
+
+#[test]
+fn judges_reaching_the_strike_limit_are_barred_and_slashed() {
+	new_test_ext().execute_with(|| {
+		JudgeDeposit::set(10);
+		for challenge in 0..2 {
+			assert_ok!(Buidl::create_challenge(
+				RuntimeOrigin::signed(1),
+				HACKATHON,
+				H256::repeat_byte(challenge),
+				10,
+				judges(vec![4, 5]),
+				None
+			));
+		}
+		set_periods();
+		let copied = team(2, vec![]);
+		let original = team(3, vec![]);
+		for challenge_id in 0..2 {
+			assert_ok!(Buidl::submit_solution(RuntimeOrigin::signed(2), HACKATHON, challenge_id, copied, H256::repeat_byte(5), false));
+			assert_ok!(Buidl::submit_solution(RuntimeOrigin::signed(3), HACKATHON, challenge_id, original, H256::repeat_byte(6), false));
+		}
+		System::set_block_number(VOTE_START);
+		assert_ok!(Buidl::vote(RuntimeOrigin::signed(4), HACKATHON, 0, 0));
+		assert_ok!(Buidl::vote(RuntimeOrigin::signed(4), HACKATHON, 1, 0));
+		assert_ok!(Buidl::vote(RuntimeOrigin::signed(5), HACKATHON, 0, 1));
+		// a deposit on each challenge voted on
+		assert_eq!(Balances::reserved_balance(4), 20);
+		assert_eq!(Balances::reserved_balance(5), 10);
+
+		assert_ok!(Buidl::disqualify_solution(RuntimeOrigin::signed(ORGANIZER), HACKATHON, 0, 0));
+		System::assert_last_event(crate::Event::JudgeStruck { judge: 4, strikes: 1 }.into());
+		assert_eq!(Buidl::judge_strikes(4), 1);
+		assert_eq!(Buidl::judge_strikes(5), 0);
+
+		assert_ok!(Buidl::disqualify_solution(RuntimeOrigin::signed(ORGANIZER), HACKATHON, 1, 0));
+		System::assert_last_event(crate::Event::JudgeBanned { judge: 4, slashed: 10 }.into());
+		assert!(crate::BannedJudges::<Test>::contains_key(4));
+		assert_eq!(Balances::reserved_balance(4), 10);
+		assert_eq!(Balances::free_balance(4), 980);
+
+		// barred from the panels they sit on and from any new one
+		assert_noop!(Buidl::vote(RuntimeOrigin::signed(4), HACKATHON, 1, 1), Error::<Test>::NotAJudge);
+		assert_noop!(
+			Buidl::set_event_judges(RuntimeOrigin::signed(ORGANIZER), HACKATHON, vec![4].try_into().unwrap()),
+			Error::<Test>::JudgeBanned
+		);
+
+		// the deposits left are returned on settlement
+		System::set_block_number(VOTE_END);
+		assert_ok!(Buidl::finalize_challenge(RuntimeOrigin::signed(1), HACKATHON, 0));
+		assert_eq!(Balances::reserved_balance(4), 0);
+		assert_eq!(Balances::reserved_balance(5), 0);
+		assert_eq!(Balances::free_balance(4), 990);
+	});
+}
+
+#[test]
+fn escalated_disputes_freeze_settlement_until_root_rules() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Buidl::create_challenge(RuntimeOrigin::signed(1), HACKATHON, H256::repeat_byte(1), 10, judges(vec![4]), None));
+		set_periods();
+		let first = team(2, vec![]);
+		let second = team(3, vec![]);
+		assert_ok!(Buidl::submit_solution(RuntimeOrigin::signed(2), HACKATHON, 0, first, H256::repeat_byte(5), false));
+		assert_ok!(Buidl::submit_solution(RuntimeOrigin::signed(3), HACKATHON, 0, second, H256::repeat_byte(6), false));
+		assert_ok!(Buidl::disqualify_solution(RuntimeOrigin::signed(ORGANIZER), HACKATHON, 0, 1));
+
+		assert_noop!(
+			Buidl::escalate_dispute(RuntimeOrigin::signed(5), 0),
+			Error::<Test>::NotChallengeParticipant
+		);
+		assert_ok!(Buidl::escalate_dispute(RuntimeOrigin::signed(3), 0));
+		System::assert_last_event(crate::Event::DisputeEscalated { challenge_id: 0, team_id: second }.into());
+		assert_noop!(
+			Buidl::escalate_dispute(RuntimeOrigin::signed(2), 0),
+			Error::<Test>::DisputeAlreadyEscalated
+		);
+		// the organizer can no longer rule on the challenge either
+		assert_noop!(
+			Buidl::disqualify_solution(RuntimeOrigin::signed(ORGANIZER), HACKATHON, 0, 0),
+			Error::<Test>::PayoutFrozen
+		);
+
+		System::set_block_number(VOTE_START);
+		assert_ok!(Buidl::vote(RuntimeOrigin::signed(4), HACKATHON, 0, 0));
+		System::set_block_number(VOTE_END + 10);
+		assert_noop!(
+			Buidl::finalize_challenge(RuntimeOrigin::signed(1), HACKATHON, 0),
+			Error::<Test>::PayoutFrozen
+		);
+
+		assert_noop!(
+			Buidl::resolve_dispute(RuntimeOrigin::signed(ORGANIZER), 0, true),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(Buidl::resolve_dispute(RuntimeOrigin::root(), 0, true));
+		System::assert_last_event(crate::Event::DisputeResolved { challenge_id: 0, reinstated: true }.into());
+		assert!(!ChallengeSolutions::<Test>::get(0, 1).unwrap().disqualified);
+		assert_noop!(
+			Buidl::resolve_dispute(RuntimeOrigin::root(), 0, false),
+			Error::<Test>::DisputeNotEscalated
+		);
+
+		assert_ok!(Buidl::finalize_challenge(RuntimeOrigin::signed(1), HACKATHON, 0));
+		assert_eq!(ChallengeWinners::<Test>::get(0), Some(0));
+		assert_noop!(
+			Buidl::escalate_dispute(RuntimeOrigin::signed(3), 0),
+			Error::<Test>::ChallengeAlreadyFinalized
+		);
+	});
+}
+
+#[test]
+fn challenges_only_accept_solutions_once_fully_funded() {
+	new_test_ext().execute_with(|| {
+		DepositFraction::set(Permill::from_percent(40));
+		assert_ok!(Buidl::create_challenge(RuntimeOrigin::signed(1), HACKATHON, H256::repeat_byte(1), 100, None, None));
+		assert_eq!(<Balances as InspectHold<u64>>::balance_on_hold(&1), 40);
+		assert_eq!(Challenges::<Test>::get(HACKATHON, 0).unwrap().outstanding, 60);
+		// the treasury cannot top up later, so it holds everything straight away
+		assert_ok!(Buidl::create_treasury_challenge(RuntimeOrigin::root(), HACKATHON, 2, H256::repeat_byte(2), 50, None, None));
+		assert_eq!(<Balances as InspectHold<u64>>::balance_on_hold(&TREASURY), 50);
+
+		assert_noop!(
+			Buidl::fund_challenge(RuntimeOrigin::signed(2), HACKATHON, 0, 20),
+			Error::<Test>::NotChallengeFunder
+		);
+		assert_ok!(Buidl::fund_challenge(RuntimeOrigin::signed(1), HACKATHON, 0, 20));
+		System::assert_last_event(
+			crate::Event::ChallengeFunded { hackathon_id: HACKATHON, challenge_id: 0, amount: 20, outstanding: 40 }.into(),
+		);
+
+		set_periods();
+		let team_id = team(2, vec![]);
+		assert_noop!(
+			Buidl::submit_solution(RuntimeOrigin::signed(2), HACKATHON, 0, team_id, H256::repeat_byte(5), false),
+			Error::<Test>::ChallengeNotFunded
+		);
+		assert_ok!(Buidl::submit_solution(RuntimeOrigin::signed(2), HACKATHON, 1, team_id, H256::repeat_byte(5), false));
+
+		// topping up more than is outstanding only holds what is owed
+		assert_ok!(Buidl::fund_challenge(RuntimeOrigin::signed(1), HACKATHON, 0, 100));
+		assert_eq!(<Balances as InspectHold<u64>>::balance_on_hold(&1), 100);
+		assert_noop!(
+			Buidl::fund_challenge(RuntimeOrigin::signed(1), HACKATHON, 0, 1),
+			Error::<Test>::ChallengeFullyFunded
+		);
+		assert_ok!(Buidl::submit_solution(RuntimeOrigin::signed(2), HACKATHON, 0, team_id, H256::repeat_byte(5), false));
+	});
+}
+
+#[test]
+fn cancelling_every_challenge_frees_all_of_the_funders_held_balance() {
+	new_test_ext().execute_with(|| {
+		for reward in [10, 20, 30] {
+			assert_ok!(Buidl::create_challenge(RuntimeOrigin::signed(1), HACKATHON, H256::repeat_byte(1), reward, None, None));
+		}
+		assert_ok!(Buidl::add_prize(RuntimeOrigin::signed(1), HACKATHON, 1, H256::repeat_byte(2), 15, vec![4].try_into().unwrap()));
+		// a challenge that has only held its deposit so far
+		DepositFraction::set(Permill::from_percent(50));
+		assert_ok!(Buidl::create_challenge(RuntimeOrigin::signed(1), HACKATHON, H256::repeat_byte(3), 40, None, None));
+		assert_eq!(<Balances as InspectHold<u64>>::balance_on_hold(&1), 95);
+
+		assert_ok!(Buidl::cancel_challenge(RuntimeOrigin::signed(1), HACKATHON, 0));
+		assert_ok!(Buidl::cancel_challenge(RuntimeOrigin::signed(1), HACKATHON, 1));
+		// only what the remaining challenges hold is still locked
+		assert_eq!(<Balances as InspectHold<u64>>::balance_on_hold(&1), 50);
+		assert_ok!(Buidl::cancel_challenge(RuntimeOrigin::signed(1), HACKATHON, 2));
+		assert_ok!(Buidl::cancel_challenge(RuntimeOrigin::signed(1), HACKATHON, 3));
+		assert_eq!(<Balances as InspectHold<u64>>::balance_on_hold(&1), 0);
+		assert_eq!(Balances::free_balance(1), 1_000);
+	});
+}
+
+#[test]
+fn cancelling_a_partly_funded_challenge_only_releases_its_deposit() {
+	new_test_ext().execute_with(|| {
+		DepositFraction::set(Permill::from_percent(40));
+		assert_ok!(Buidl::create_challenge(RuntimeOrigin::signed(1), HACKATHON, H256::repeat_byte(1), 100, None, None));
+		assert_ok!(Buidl::create_challenge(RuntimeOrigin::signed(1), HACKATHON, H256::repeat_byte(2), 50, None, None));
+		assert_ok!(Buidl::cancel_challenge(RuntimeOrigin::signed(1), HACKATHON, 0));
+		// the other challenge's deposit stays on hold
+		assert_eq!(<Balances as InspectHold<u64>>::balance_on_hold(&1), 20);
+		assert_eq!(Balances::free_balance(1), 980);
+	});
+}
+
+#[test]
+fn challenge_board_queries_return_everything_in_id_order() {
+	new_test_ext().execute_with(|| {
+		challenge_with_two_solutions(10);
+		insert_challenge(1, vec![]);
+
+		let (challenge, solutions) = Buidl::challenge_with_solutions(HACKATHON, 0).unwrap();
+		assert_eq!(challenge, Challenges::<Test>::get(HACKATHON, 0).unwrap());
+		assert_eq!(
+			solutions,
+			vec![
+				ChallengeSolutions::<Test>::get(0, 0).unwrap().into(),
+				ChallengeSolutions::<Test>::get(0, 1).unwrap().into(),
+			]
+		);
+		assert!(Buidl::challenge_with_solutions(HACKATHON + 1, 0).is_none());
+
+		let ids: Vec<u16> = Buidl::list_challenges(HACKATHON).into_iter().map(|(id, _)| id).collect();
+		assert_eq!(ids, vec![0, 1]);
+	});
+}
+
+#[test]
+fn challenges_are_only_accepted_during_the_creation_window() {
+	new_test_ext().execute_with(|| {
+		// registered at block 1, so submissions open and the window closes at block 5
+		assert_eq!(Hackathons::<Test>::get(HACKATHON).unwrap().challenge_deadline, 5);
+		assert_ok!(Buidl::update_period(RuntimeOrigin::signed(ORGANIZER), HACKATHON, 20, 30, 30, 40));
+		System::set_block_number(6);
+		assert_noop!(
+			Buidl::create_challenge(RuntimeOrigin::signed(1), HACKATHON, H256::repeat_byte(1), 10, None, None),
+			Error::<Test>::ChallengeWindowClosed
+		);
+
+		assert_noop!(
+			Buidl::extend_challenge_window(RuntimeOrigin::signed(1), HACKATHON, 10),
+			Error::<Test>::NotHackathonOrganizer
+		);
+		for deadline in [5, 20] {
+			assert_noop!(
+				Buidl::extend_challenge_window(RuntimeOrigin::signed(ORGANIZER), HACKATHON, deadline),
+				Error::<Test>::InvalidChallengeDeadline
+			);
+		}
+		assert_ok!(Buidl::extend_challenge_window(RuntimeOrigin::signed(ORGANIZER), HACKATHON, 10));
+		System::assert_last_event(
+			crate::Event::ChallengeWindowExtended { hackathon_id: HACKATHON, deadline: 10 }.into(),
+		);
+		assert_ok!(Buidl::create_challenge(RuntimeOrigin::signed(1), HACKATHON, H256::repeat_byte(1), 10, None, None));
+		// the admin origin can extend it too
+		assert_ok!(Buidl::extend_challenge_window(RuntimeOrigin::root(), HACKATHON, 15));
+
+		System::set_block_number(15);
+		assert_noop!(
+			Buidl::create_challenge(RuntimeOrigin::signed(1), HACKATHON, H256::repeat_byte(2), 10, None, None),
+			Error::<Test>::ChallengeWindowClosed
+		);
+	});
+}
+
+#[test]
+fn organizer_sets_a_deduplicated_event_judge_pool() {
+	new_test_ext().execute_with(|| {
+		let pool: BoundedVec<u64, <Test as crate::Config>::MaxEventJudges> = vec![4, 5, 4].try_into().unwrap();
+		assert_noop!(
+			Buidl::set_event_judges(RuntimeOrigin::signed(1), HACKATHON, pool.clone()),
+			Error::<Test>::NotHackathonOrganizer
+		);
+		assert_ok!(Buidl::set_event_judges(RuntimeOrigin::signed(ORGANIZER), HACKATHON, pool));
+		assert_eq!(EventJudges::<Test>::get(HACKATHON).into_inner(), vec![4, 5]);
+	});
+}
+
+#[test]
+fn hackathon_judges_lists_the_pool_and_every_panel_once() {
+	new_test_ext().execute_with(|| {
+		let pool: BoundedVec<u64, <Test as crate::Config>::MaxEventJudges> = vec![4, 5].try_into().unwrap();
+		assert_ok!(Buidl::set_event_judges(RuntimeOrigin::signed(ORGANIZER), HACKATHON, pool));
+		assert_ok!(Buidl::create_challenge(
+			RuntimeOrigin::signed(1),
+			HACKATHON,
+			H256::repeat_byte(1),
+			10,
+			judges(vec![5, 6]),
+			None
+		));
+		assert_ok!(Buidl::create_challenge(RuntimeOrigin::signed(2), HACKATHON, H256::repeat_byte(2), 10, None, None));
+		assert_ok!(Buidl::add_prize(RuntimeOrigin::signed(1), HACKATHON, 0, H256::repeat_byte(3), 10, vec![7, 4].try_into().unwrap()));
+
+		assert_eq!(Buidl::hackathon_judges(HACKATHON), vec![4, 5, 6, 7]);
+		assert!(Buidl::hackathon_judges(HACKATHON + 1).is_empty());
+	});
+}
+
+#[test]
+fn auto_assign_judges_rotates_the_pool_across_challenges() {
+	new_test_ext().execute_with(|| {
+		for author in [1, 2, 3] {
+			assert_ok!(Buidl::create_challenge(
+				RuntimeOrigin::signed(author),
+				HACKATHON,
+				H256::repeat_byte(1),
+				10,
+				None,
+				None
+			));
+		}
+		assert_noop!(
+			Buidl::auto_assign_judges(RuntimeOrigin::root(), HACKATHON, 2),
+			Error::<Test>::NoEventJudges
+		);
+		let pool: BoundedVec<u64, <Test as crate::Config>::MaxEventJudges> =
+			vec![10, 11, 12, 13].try_into().unwrap();
+		assert_ok!(Buidl::set_event_judges(RuntimeOrigin::signed(ORGANIZER), HACKATHON, pool));
+		assert_noop!(
+			Buidl::auto_assign_judges(RuntimeOrigin::signed(ORGANIZER), HACKATHON, 2),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_noop!(
+			Buidl::auto_assign_judges(RuntimeOrigin::root(), HACKATHON, 6),
+			Error::<Test>::TooManyMembers
+		);
+
+		assert_ok!(Buidl::auto_assign_judges(RuntimeOrigin::root(), HACKATHON, 2));
+		let panel = |id| Challenges::<Test>::get(HACKATHON, id).unwrap().judges.unwrap().into_inner();
+		assert_eq!(panel(0), vec![10, 11]);
+		assert_eq!(panel(1), vec![12, 13]);
+		assert_eq!(panel(2), vec![10, 11]);
+		// six seats over four judges: nobody judges more than one challenge above anyone else
+		let loads: Vec<usize> = [10, 11, 12, 13]
+			.iter()
+			.map(|judge| (0..3).filter(|id| panel(*id).contains(judge)).count())
+			.collect();
+		assert!(loads.iter().max().unwrap() - loads.iter().min().unwrap() <= 1);
+
+		// full panels are left alone and voting locks the panels
+		assert_ok!(Buidl::auto_assign_judges(RuntimeOrigin::root(), HACKATHON, 2));
+		assert_eq!(panel(1), vec![12, 13]);
+		set_periods();
+		System::set_block_number(VOTE_START);
+		assert_noop!(
+			Buidl::auto_assign_judges(RuntimeOrigin::root(), HACKATHON, 3),
+			Error::<Test>::VotingAlreadyStarted
+		);
+	});
+}
+
+#[test]
+fn a_majority_of_the_judge_pool_requests_an_extension() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Buidl::set_event_judges(RuntimeOrigin::signed(ORGANIZER), HACKATHON, vec![4, 5, 6, 7].try_into().unwrap()));
+		assert_noop!(
+			Buidl::judges_request_extension(RuntimeOrigin::signed(1), HACKATHON, 10),
+			Error::<Test>::NotEventJudge
+		);
+		assert_noop!(
+			Buidl::judges_request_extension(RuntimeOrigin::signed(4), HACKATHON, 0),
+			Error::<Test>::InvalidPeriod
+		);
+
+		// half the pool is not a majority, and only backing for the same extension adds up
+		assert_ok!(Buidl::judges_request_extension(RuntimeOrigin::signed(4), HACKATHON, 10));
+		System::assert_last_event(
+			crate::Event::ExtensionSupportRecorded { hackathon_id: HACKATHON, judge: 4, blocks: 10, supporters: 1 }.into(),
+		);
+		assert_ok!(Buidl::judges_request_extension(RuntimeOrigin::signed(5), HACKATHON, 20));
+		System::assert_last_event(
+			crate::Event::ExtensionSupportRecorded { hackathon_id: HACKATHON, judge: 5, blocks: 20, supporters: 1 }.into(),
+		);
+		assert_ok!(Buidl::judges_request_extension(RuntimeOrigin::signed(5), HACKATHON, 10));
+		System::assert_last_event(
+			crate::Event::ExtensionSupportRecorded { hackathon_id: HACKATHON, judge: 5, blocks: 10, supporters: 2 }.into(),
+		);
+		assert_eq!(count_events(crate::Event::ExtensionRequested { hackathon_id: HACKATHON, blocks: 10, supporters: 2 }), 0);
+
+		assert_ok!(Buidl::judges_request_extension(RuntimeOrigin::signed(6), HACKATHON, 10));
+		System::assert_last_event(
+			crate::Event::ExtensionRequested { hackathon_id: HACKATHON, blocks: 10, supporters: 3 }.into(),
+		);
+		// the request starts over once made
+		assert_eq!(ExtensionSupport::<Test>::iter_prefix(HACKATHON).count(), 0);
+
+		System::set_block_number(SUBMISSION_END);
+		assert_noop!(
+			Buidl::judges_request_extension(RuntimeOrigin::signed(7), HACKATHON, 10),
+			Error::<Test>::SubmissionsAlreadyEnded
+		);
+	});
+}
+
+#[test]
+fn next_ids_advance_after_creation() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(Buidl::next_challenge_id(), 0);
+		assert_eq!(Buidl::next_team_id(), 0);
+
+		assert_ok!(Buidl::create_challenge(RuntimeOrigin::signed(1), HACKATHON, H256::repeat_byte(1), 10, None, None));
+		team(2, vec![]);
+
+		assert_eq!(Buidl::next_challenge_id(), 1);
+		assert_eq!(Buidl::next_team_id(), 1);
+	});
+}
+
+#[test]
+fn update_period_caps_the_hackathon_duration() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Buidl::update_period(RuntimeOrigin::signed(ORGANIZER), HACKATHON, 1, 50, 50, 101));
+		assert_noop!(
+			Buidl::update_period(RuntimeOrigin::signed(ORGANIZER), HACKATHON, 1, 50, 50, 102),
+			Error::<Test>::HackathonTooLong
+		);
+	});
+}
+
+#[test]
+fn create_challenge_rejects_an_empty_judge_list() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Buidl::create_challenge(RuntimeOrigin::signed(1), HACKATHON, H256::repeat_byte(1), 10, judges(vec![]), None),
+			Error::<Test>::NoJudgesProvided
+		);
+		assert_eq!(Balances::reserved_balance(1), 0);
+	});
+}
+
+#[test]
+fn a_plurality_short_of_the_required_agreement_is_refunded() {
+	new_test_ext().execute_with(|| {
+		for description in [1, 2] {
+			assert_ok!(Buidl::create_challenge(
+				RuntimeOrigin::signed(1),
+				HACKATHON,
+				H256::repeat_byte(description),
+				10,
+				judges(vec![4, 5, 6, 8, 9]),
+				None
+			));
+		}
+		assert_noop!(
+			Buidl::set_required_agreement(RuntimeOrigin::signed(2), HACKATHON, 0, Permill::from_percent(60)),
+			Error::<Test>::NotChallengeAuthor
+		);
+		assert_ok!(Buidl::set_required_agreement(RuntimeOrigin::signed(1), HACKATHON, 0, Permill::from_percent(60)));
+		System::assert_last_event(
+			crate::Event::RequiredAgreementSet { hackathon_id: HACKATHON, id: 0, agreement: Permill::from_percent(60) }
+				.into(),
+		);
+		assert_ok!(Buidl::set_required_agreement(RuntimeOrigin::signed(1), HACKATHON, 1, Permill::from_percent(50)));
+		set_periods();
+		assert_noop!(
+			Buidl::set_required_agreement(RuntimeOrigin::signed(1), HACKATHON, 0, Permill::zero()),
+			Error::<Test>::ChallengeLocked
+		);
+
+		for founder in [2, 3, 7] {
+			let team_id = team(founder, vec![]);
+			for challenge_id in [0, 1] {
+				assert_ok!(Buidl::submit_solution(
+					RuntimeOrigin::signed(founder),
+					HACKATHON,
+					challenge_id,
+					team_id,
+					H256::repeat_byte(5),
+					false
+				));
+			}
+		}
+		// the leader has two of the four votes cast on either challenge
+		System::set_block_number(VOTE_START);
+		for challenge_id in [0, 1] {
+			for (judge, solution_id) in [(4, 0), (5, 0), (6, 1), (8, 2)] {
+				assert_ok!(Buidl::vote(RuntimeOrigin::signed(judge), HACKATHON, challenge_id, solution_id));
+			}
+		}
+		System::set_block_number(VOTE_END);
+
+		assert_ok!(Buidl::finalize_challenge(RuntimeOrigin::signed(1), HACKATHON, 0));
+		assert_eq!(
+			UnawardedChallenges::<Test>::get(0),
+			Some(FinalizationFailure::AgreementNotMet { votes: 2, required: 3 })
+		);
+		assert_eq!(Balances::free_balance(1), 990);
+		assert_ok!(Buidl::finalize_challenge(RuntimeOrigin::signed(1), HACKATHON, 1));
+		assert_eq!(ChallengeWinners::<Test>::get(1), Some(0));
+		assert_eq!(Balances::free_balance(2), 1_010);
+		assert_eq!(Balances::reserved_balance(1), 0);
+	});
+}
+
+#[test]
+fn finalize_challenge_requires_a_quorum_of_judges() {
+	new_test_ext().execute_with(|| {
+		set_periods();
+		for description in [1, 2] {
+			assert_ok!(Buidl::create_challenge(
+				RuntimeOrigin::signed(1),
+				HACKATHON,
+				H256::repeat_byte(description),
+				10,
+				judges(vec![4, 5, 6, 8, 9]),
+				None
+			));
+		}
+		let team_id = team(2, vec![3]);
+		assert_ok!(Buidl::submit_solution(RuntimeOrigin::signed(2), HACKATHON, 0, team_id, H256::repeat_byte(5), false));
+		assert_ok!(Buidl::submit_solution(RuntimeOrigin::signed(2), HACKATHON, 1, team_id, H256::repeat_byte(5), false));
+
+		// 30% of five judges rounds up to two votes
+		System::set_block_number(VOTE_START);
+		assert_ok!(Buidl::vote(RuntimeOrigin::signed(4), HACKATHON, 0, 0));
+		assert_ok!(Buidl::vote(RuntimeOrigin::signed(4), HACKATHON, 1, 0));
+		assert_ok!(Buidl::vote(RuntimeOrigin::signed(5), HACKATHON, 1, 0));
+		System::set_block_number(VOTE_END);
+
+		assert_ok!(Buidl::finalize_challenge(RuntimeOrigin::signed(1), HACKATHON, 0));
+		System::assert_last_event(
+			crate::Event::ChallengeFinalizationFailed {
+				challenge_id: 0,
+				reason: FinalizationFailure::QuorumNotMet { votes: 1, required: 2 },
+			}
+			.into(),
+		);
+		assert!(ChallengeWinners::<Test>::get(0).is_none());
+		assert_eq!(
+			UnawardedChallenges::<Test>::get(0),
+			Some(FinalizationFailure::QuorumNotMet { votes: 1, required: 2 })
+		);
+		// the failed challenge's reward is released rather than stranded behind closed votes
+		assert_eq!(Balances::reserved_balance(1), 10);
+		assert_eq!(Balances::free_balance(1), 990);
+		assert_noop!(
+			Buidl::finalize_challenge(RuntimeOrigin::signed(1), HACKATHON, 0),
+			Error::<Test>::ChallengeAlreadyFinalized
+		);
+
+		assert_ok!(Buidl::finalize_challenge(RuntimeOrigin::signed(1), HACKATHON, 1));
+		assert_eq!(ChallengeWinners::<Test>::get(1), Some(0));
+		assert_eq!(Balances::reserved_balance(1), 0);
+	});
+}
+
+#[test]
+fn invited_judges_only_vote_once_they_accept() {
+	new_test_ext().execute_with(|| {
+		challenge_with_two_solutions(10);
+		assert_ok!(Buidl::add_judges(RuntimeOrigin::signed(1), HACKATHON, 0, vec![8]));
+		assert!(JudgeInvites::<Test>::contains_key(0, 8));
+
+		assert_noop!(
+			Buidl::vote(RuntimeOrigin::signed(8), HACKATHON, 0, 0),
+			Error::<Test>::JudgeInvitePending
+		);
+		assert_noop!(
+			Buidl::accept_judge_role(RuntimeOrigin::signed(9), HACKATHON, 0),
+			Error::<Test>::NoJudgeInvite
+		);
+
+		assert_ok!(Buidl::accept_judge_role(RuntimeOrigin::signed(8), HACKATHON, 0));
+		System::assert_last_event(crate::Event::JudgeAccepted { challenge_id: 0, judge: 8 }.into());
+		assert!(!JudgeInvites::<Test>::contains_key(0, 8));
+		assert_ok!(Buidl::vote(RuntimeOrigin::signed(8), HACKATHON, 0, 0));
+	});
+}
+
+#[test]
+fn hackathon_schedule_reports_periods_and_phase() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(Buidl::hackathon_schedule(HACKATHON).unwrap().phase, Phase::Upcoming);
+		assert_eq!(Buidl::hackathon_schedule(HACKATHON + 1), None);
+
+		assert_ok!(Buidl::update_period(RuntimeOrigin::root(), HACKATHON, 5, 10, 12, 20));
+		assert_eq!(
+			Buidl::hackathon_schedule(HACKATHON),
+			Some(Schedule {
+				submission_start: 5,
+				submission_end: 10,
+				vote_end: 20,
+				now: 1,
+				phase: Phase::Upcoming,
+			})
+		);
+
+		for (block, phase) in [
+			(5, Phase::Submission),
+			(10, Phase::Review),
+			(12, Phase::Voting),
+			(20, Phase::Ended),
+		] {
+			System::set_block_number(block);
+			let schedule = Buidl::hackathon_schedule(HACKATHON).unwrap();
+			assert_eq!(schedule.now, block);
+			assert_eq!(schedule.phase, phase);
+		}
+	});
+}
+
+#[test]
+fn judges_on_a_submitting_team_cannot_vote() {
+	new_test_ext().execute_with(|| {
+		set_periods();
+		assert_ok!(Buidl::create_challenge(
+			RuntimeOrigin::signed(1),
+			HACKATHON,
+			H256::repeat_byte(1),
+			10,
+			judges(vec![2, 3, 4, 6]),
+			None
+		));
+		// 2 founds the team and 3 submits for it; 4 joins a second team without submitting
+		let team_id = team(2, vec![3]);
+		assert_ok!(Buidl::submit_solution(RuntimeOrigin::signed(3), HACKATHON, 0, team_id, H256::repeat_byte(5), false));
+		let other_team = team(5, vec![4]);
+		assert_ok!(Buidl::submit_solution(RuntimeOrigin::signed(5), HACKATHON, 0, other_team, H256::repeat_byte(6), false));
+		// 6 is invited onto a third team but never accepts
+		let unaccepted = NextTeamId::<Test>::get();
+		assert_ok!(Buidl::create_team(RuntimeOrigin::signed(7), vec![6]));
+		assert_ok!(Buidl::submit_solution(RuntimeOrigin::signed(7), HACKATHON, 0, unaccepted, H256::repeat_byte(7), false));
+		System::set_block_number(VOTE_START);
+
+		for judge in [2, 3, 4] {
+			assert!(Buidl::is_judge_conflicted(0, &judge));
+			assert_noop!(
+				Buidl::vote(RuntimeOrigin::signed(judge), HACKATHON, 0, 0),
+				Error::<Test>::JudgeIsParticipant
+			);
+		}
+		// an invite cannot be used to knock a judge off the panel
+		assert!(!Buidl::is_judge_conflicted(0, &6));
+		assert_ok!(Buidl::vote(RuntimeOrigin::signed(6), HACKATHON, 0, 1));
+	});
+}
+
+#[test]
+fn anonymous_solutions_hide_members_until_voting_ends() {
+	new_test_ext().execute_with(|| {
+		set_periods();
+		assert_ok!(Buidl::create_challenge(RuntimeOrigin::signed(1), HACKATHON, H256::repeat_byte(1), 10, None, None));
+		let hidden = team(2, vec![3]);
+		let open = team(4, vec![]);
+		let withdrawn = team(5, vec![]);
+		assert_ok!(Buidl::submit_solution(RuntimeOrigin::signed(2), HACKATHON, 0, hidden, H256::repeat_byte(5), true));
+		System::assert_last_event(crate::Event::SolutionSubmitted { id: 0, member: None }.into());
+		assert_ok!(Buidl::submit_solution(RuntimeOrigin::signed(4), HACKATHON, 0, open, H256::repeat_byte(6), false));
+		System::assert_last_event(crate::Event::SolutionSubmitted { id: 0, member: Some(4) }.into());
+		assert_ok!(Buidl::submit_solution(RuntimeOrigin::signed(5), HACKATHON, 0, withdrawn, H256::repeat_byte(7), true));
+		assert_ok!(Buidl::withdraw_solution(RuntimeOrigin::signed(5), HACKATHON, 0, 2));
+		System::assert_last_event(
+			crate::Event::SolutionWithdrawn { challenge_id: 0, solution_id: 2, member: None }.into(),
+		);
+
+		// storage keeps the roster, only the listing leaves it out
+		assert_eq!(ChallengeSolutions::<Test>::get(0, 0).unwrap().members.into_inner(), vec![2, 3]);
+		let listed = || -> Vec<(Option<u32>, Option<u64>, Option<Vec<u64>>)> {
+			let (_, solutions) = Buidl::challenge_with_solutions(HACKATHON, 0).unwrap();
+			solutions
+				.into_iter()
+				.map(|solution| {
+					(solution.team_id, solution.submitter, solution.members.map(|m| m.into_inner()))
+				})
+				.collect()
+		};
+		let masked = vec![(None, None, None), (Some(open), Some(4), Some(vec![4]))];
+		assert_eq!(listed(), masked);
+
+		System::set_block_number(VOTE_START);
+		assert_eq!(listed(), masked);
+
+		System::set_block_number(VOTE_END);
+		assert_eq!(
+			listed(),
+			vec![(Some(hidden), Some(2), Some(vec![2, 3])), (Some(open), Some(4), Some(vec![4]))]
+		);
+	});
+}
+
+#[test]
+fn edit_challenge_locked_once_submissions_open() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Buidl::create_challenge(RuntimeOrigin::signed(1), HACKATHON, H256::repeat_byte(1), 10, None, None));
+		assert_ok!(Buidl::update_period(RuntimeOrigin::root(), HACKATHON, 5, 10, 10, 20));
+		assert_ok!(Buidl::edit_challenge(RuntimeOrigin::signed(1), HACKATHON, 0, H256::repeat_byte(2), None));
+
+		System::set_block_number(5);
+		assert_noop!(
+			Buidl::edit_challenge(RuntimeOrigin::signed(1), HACKATHON, 0, H256::repeat_byte(3), None),
+			Error::<Test>::ChallengeLocked
+		);
+		assert_eq!(Challenges::<Test>::get(HACKATHON, 0).unwrap().description, H256::repeat_byte(2));
+	});
+}
+
+#[test]
+fn withdraw_solution_is_member_only_and_frees_the_slot() {
+	new_test_ext().execute_with(|| {
+		set_periods();
+		assert_ok!(Buidl::create_challenge(RuntimeOrigin::signed(1), HACKATHON, H256::repeat_byte(1), 10, None, None));
+		let team_id = team(2, vec![3]);
+		assert_ok!(Buidl::submit_solution(RuntimeOrigin::signed(2), HACKATHON, 0, team_id, H256::repeat_byte(5), false));
+
+		assert_noop!(
+			Buidl::withdraw_solution(RuntimeOrigin::signed(4), HACKATHON, 0, 0),
+			Error::<Test>::NotATeamMember
+		);
+		assert_noop!(
+			Buidl::withdraw_solution(RuntimeOrigin::signed(3), HACKATHON, 0, 1),
+			Error::<Test>::SolutionDoesNotExist
+		);
+
+		assert_ok!(Buidl::withdraw_solution(RuntimeOrigin::signed(3), HACKATHON, 0, 0));
+		System::assert_last_event(
+			crate::Event::SolutionWithdrawn { challenge_id: 0, solution_id: 0, member: Some(3) }.into(),
+		);
+		assert!(ChallengeSolutions::<Test>::get(0, 0).is_none());
+		assert_eq!(Challenges::<Test>::get(HACKATHON, 0).unwrap().submissions, 0);
+
+		// the team can submit a replacement
+		assert_ok!(Buidl::submit_solution(RuntimeOrigin::signed(2), HACKATHON, 0, team_id, H256::repeat_byte(6), false));
+		assert_eq!(ChallengeSolutions::<Test>::get(0, 1).unwrap().solution, H256::repeat_byte(6));
+	});
+}
+
+#[test]
+fn withdraw_solution_rejected_once_voting_starts() {
+	new_test_ext().execute_with(|| {
+		challenge_with_two_solutions(10);
+		assert_noop!(
+			Buidl::withdraw_solution(RuntimeOrigin::signed(2), HACKATHON, 0, 0),
+			Error::<Test>::VotingAlreadyStarted
+		);
+	});
+}
+
+#[test]
+fn early_bonus_breaks_ties_in_favour_of_early_submissions() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Buidl::create_challenge(
+			RuntimeOrigin::signed(1),
+			HACKATHON,
+			H256::repeat_byte(1),
+			10,
+			judges(vec![4, 5, 6]),
+			None
+		));
+		let bonus = EarlyBonus { cutoff: 5, votes: 1 };
+		assert_noop!(
+			Buidl::set_early_bonus(RuntimeOrigin::signed(2), HACKATHON, 0, Some(bonus.clone())),
+			Error::<Test>::NotChallengeAuthor
+		);
+		assert_ok!(Buidl::set_early_bonus(RuntimeOrigin::signed(1), HACKATHON, 0, Some(bonus.clone())));
+
+		set_periods();
+		assert_noop!(
+			Buidl::set_early_bonus(RuntimeOrigin::signed(1), HACKATHON, 0, None),
+			Error::<Test>::ChallengeLocked
+		);
+
+		let early = team(2, vec![]);
+		assert_ok!(Buidl::submit_solution(RuntimeOrigin::signed(2), HACKATHON, 0, early, H256::repeat_byte(5), false));
+		System::set_block_number(6);
+		let late = team(3, vec![]);
+		assert_ok!(Buidl::submit_solution(RuntimeOrigin::signed(3), HACKATHON, 0, late, H256::repeat_byte(6), false));
+		assert_eq!(ChallengeSolutions::<Test>::get(0, 1).unwrap().submitted_at, 6);
+
+		// the late solution has more votes, but the bonus levels the early one with it
+		System::set_block_number(VOTE_START);
+		assert_ok!(Buidl::vote(RuntimeOrigin::signed(4), HACKATHON, 0, 0));
+		assert_ok!(Buidl::vote(RuntimeOrigin::signed(5), HACKATHON, 0, 1));
+		assert_ok!(Buidl::vote(RuntimeOrigin::signed(6), HACKATHON, 0, 1));
+		assert_eq!(Buidl::tally_votes(HACKATHON, 0), Some((0, 1)));
+
+		System::set_block_number(VOTE_END);
+		assert_ok!(Buidl::finalize_challenge(RuntimeOrigin::signed(1), HACKATHON, 0));
+		assert_eq!(ChallengeWinners::<Test>::get(0), Some(0));
+	});
+}
+
+#[test]
+fn delegated_votes_count_for_the_delegator() {
+	new_test_ext().execute_with(|| {
+		challenge_with_two_solutions(10);
+		assert_noop!(
+			Buidl::delegate_vote(RuntimeOrigin::signed(4), HACKATHON, 0, 8),
+			Error::<Test>::NotAJudge
+		);
+		assert_ok!(Buidl::delegate_vote(RuntimeOrigin::signed(4), HACKATHON, 0, 5));
+		System::assert_last_event(
+			crate::Event::VoteDelegated { challenge_id: 0, judge: 4, delegate: 5 }.into(),
+		);
+		assert_noop!(
+			Buidl::vote(RuntimeOrigin::signed(4), HACKATHON, 0, 0),
+			Error::<Test>::JudgeHasDelegated
+		);
+
+		assert_ok!(Buidl::vote(RuntimeOrigin::signed(5), HACKATHON, 0, 1));
+		assert_ok!(Buidl::vote(RuntimeOrigin::signed(6), HACKATHON, 0, 0));
+		assert_eq!(Buidl::tally_votes(HACKATHON, 0), Some((1, 2)));
+
+		// once revoked only the delegate's own vote counts
+		assert_ok!(Buidl::revoke_delegation(RuntimeOrigin::signed(4), HACKATHON, 0));
+		assert_noop!(
+			Buidl::revoke_delegation(RuntimeOrigin::signed(4), HACKATHON, 0),
+			Error::<Test>::NoDelegation
+		);
+		assert_eq!(Buidl::tally_votes(HACKATHON, 0), Some((0, 1)));
+		assert_noop!(
+			Buidl::delegate_vote(RuntimeOrigin::signed(6), HACKATHON, 0, 5),
+			Error::<Test>::AlreadyVoted
+		);
+	});
+}
+
+#[test]
+fn votes_can_only_be_delegated_once_rosters_are_final() {
+	new_test_ext().execute_with(|| {
+		set_periods();
+		assert_ok!(Buidl::create_challenge(
+			RuntimeOrigin::signed(1),
+			HACKATHON,
+			H256::repeat_byte(1),
+			10,
+			judges(vec![4, 5, 6]),
+			None
+		));
+		// a delegation made now could outlive the delegator joining a submitting team
+		assert_noop!(
+			Buidl::delegate_vote(RuntimeOrigin::signed(4), HACKATHON, 0, 5),
+			Error::<Test>::SubmissionsStillOpen
+		);
+		let team_id = team(2, vec![4]);
+		assert_ok!(Buidl::submit_solution(RuntimeOrigin::signed(2), HACKATHON, 0, team_id, H256::repeat_byte(5), false));
+		let other = team(7, vec![]);
+		assert_ok!(Buidl::submit_solution(RuntimeOrigin::signed(7), HACKATHON, 0, other, H256::repeat_byte(6), false));
+
+		System::set_block_number(VOTE_START);
+		assert_noop!(
+			Buidl::delegate_vote(RuntimeOrigin::signed(4), HACKATHON, 0, 5),
+			Error::<Test>::JudgeIsParticipant
+		);
+		assert_ok!(Buidl::delegate_vote(RuntimeOrigin::signed(6), HACKATHON, 0, 5));
+		assert_ok!(Buidl::vote(RuntimeOrigin::signed(5), HACKATHON, 0, 1));
+		assert_eq!(Buidl::tally_votes(HACKATHON, 0), Some((1, 2)));
+		assert_eq!(Buidl::solution_voters(0, 1), vec![5, 6]);
+	});
+}
 
 #[test]
-fn it_works_for_default_value() {
+fn solution_voters_lists_direct_and_delegated_votes() {
 	new_test_ext().execute_with(|| {
-		
+		challenge_with_two_solutions(10);
+		assert_ok!(Buidl::vote(RuntimeOrigin::signed(6), HACKATHON, 0, 1));
+		assert_ok!(Buidl::vote(RuntimeOrigin::signed(4), HACKATHON, 0, 0));
+		assert_ok!(Buidl::delegate_vote(RuntimeOrigin::signed(5), HACKATHON, 0, 4));
+		assert_eq!(Buidl::solution_voters(0, 0), vec![4, 5]);
+		assert_eq!(Buidl::solution_voters(0, 1), vec![6]);
+
+		assert_ok!(Buidl::vote(RuntimeOrigin::signed(6), HACKATHON, 0, 0));
+		assert_eq!(Buidl::solution_voters(0, 0), vec![4, 5, 6]);
+		assert!(Buidl::solution_voters(0, 1).is_empty());
 	});
 }
 
 #[test]
-fn correct_error_for_none_value() {
+fn delegation_cycles_are_rejected() {
 	new_test_ext().execute_with(|| {
-		// Ensure the expected error is thrown when no value is present.
+		challenge_with_two_solutions(10);
+		assert_noop!(
+			Buidl::delegate_vote(RuntimeOrigin::signed(4), HACKATHON, 0, 4),
+			Error::<Test>::DelegationCycle
+		);
+		assert_ok!(Buidl::delegate_vote(RuntimeOrigin::signed(4), HACKATHON, 0, 5));
+		assert_noop!(
+			Buidl::delegate_vote(RuntimeOrigin::signed(5), HACKATHON, 0, 4),
+			Error::<Test>::DelegationCycle
+		);
+		assert_noop!(
+			Buidl::delegate_vote(RuntimeOrigin::signed(6), HACKATHON, 0, 4),
+			Error::<Test>::DelegationCycle
+		);
 		assert_noop!(
-			TemplateModule::cause_error(RuntimeOrigin::signed(1)),
-			Error::<Test>::NoneValue
+			Buidl::delegate_vote(RuntimeOrigin::signed(4), HACKATHON, 0, 6),
+			Error::<Test>::JudgeHasDelegated
 		);
 	});
 }